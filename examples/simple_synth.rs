@@ -5,8 +5,8 @@ use auxide_dsp::nodes::filters::SvfMode;
 use auxide_dsp::nodes::oscillators::SawOsc;
 
 fn main() {
-    // Build a simple synth: SawOsc -> SVF Filter -> ADSR Envelope
-    let _graph = SynthBuilder::new()
+    // Build a simple synth: SawOsc -> SVF Filter -> ADSR Envelope -> output
+    let built = SynthBuilder::new()
         .add_oscillator(SawOsc { freq: 440.0 })
         .add_filter(SvfFilter {
             cutoff: 1000.0,
@@ -20,7 +20,13 @@ fn main() {
             release_ms: 300.0,
             curve: 1.0,
         })
-        .build_graph();
+        .add_output()
+        .build(512)
+        .expect("synth graph should compile");
 
-    println!("Synth graph built successfully");
+    println!(
+        "Synth graph built successfully: {} nodes, {} edges",
+        built.nodes.len(),
+        built.graph.edges.len()
+    );
 }
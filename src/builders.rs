@@ -1,9 +1,244 @@
-use auxide::graph::{Graph, NodeType};
-use auxide::plan::Plan;
+//! Builders that assemble and wire common graph layouts.
+//!
+//! Nodes are connected in series as they are added: output 0 of the current
+//! node feeds input 0 of the next. `split`/`parallel`/`merge` fan a signal out
+//! into branches and sum them back together, and `modulate` routes a
+//! modulator into a named modulation port of the current node.
+
+use auxide::graph::{Edge, Graph, GraphError, NodeId, NodeType, PortId, Rate};
+use auxide::node::NodeDef;
+use auxide::plan::{Plan, PlanError};
+
+use crate::describe::{Describe, NodeDescriptor, PortRole};
+use crate::nodes::oscillators::Constant;
+use crate::nodes::utility::RingMod;
+
+/// Errors reported when a builder finishes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BuildError {
+    /// An edge was rejected by the graph.
+    Graph(GraphError),
+    /// The wired graph failed to compile.
+    Plan(PlanError),
+    /// The node has no modulation port for the named parameter.
+    UnknownParam { node: NodeId, param: String },
+    /// A modulator or branch was added before any signal node.
+    NoSignal,
+    /// `merge` was called without an open `split`.
+    NothingToMerge,
+}
+
+impl From<GraphError> for BuildError {
+    fn from(e: GraphError) -> Self {
+        BuildError::Graph(e)
+    }
+}
+
+impl From<PlanError> for BuildError {
+    fn from(e: PlanError) -> Self {
+        BuildError::Plan(e)
+    }
+}
+
+/// A compiled graph together with the ids of the nodes the builder added.
+pub struct BuiltGraph {
+    pub graph: Graph,
+    pub plan: Plan,
+    /// Nodes added through the builder methods, in call order.
+    pub nodes: Vec<NodeId>,
+    /// The last node of the main chain.
+    pub last: Option<NodeId>,
+}
+
+/// Open `split`: the node every branch starts from and the branch ends.
+struct Fork {
+    source: Option<NodeId>,
+    tails: Vec<NodeId>,
+}
+
+/// Wiring state shared by both builders.
+struct Chain {
+    graph: Graph,
+    nodes: Vec<NodeId>,
+    descriptors: Vec<(NodeId, NodeDescriptor)>,
+    tail: Option<NodeId>,
+    forks: Vec<Fork>,
+    gate: Option<NodeId>,
+    error: Option<BuildError>,
+}
+
+impl Chain {
+    fn new() -> Self {
+        Self {
+            graph: Graph::new(),
+            nodes: Vec::new(),
+            descriptors: Vec::new(),
+            tail: None,
+            forks: Vec::new(),
+            gate: None,
+            error: None,
+        }
+    }
+
+    fn fail(&mut self, error: BuildError) {
+        if self.error.is_none() {
+            self.error = Some(error);
+        }
+    }
+
+    /// Add a node without wiring it.
+    fn insert<T: NodeDef + Describe + 'static>(&mut self, node: T) -> NodeId {
+        let descriptor = node.describe();
+        let id = self.graph.add_external_node(node);
+        self.descriptors.push((id, descriptor));
+        id
+    }
+
+    fn descriptor(&self, id: NodeId) -> Option<&NodeDescriptor> {
+        self.descriptors
+            .iter()
+            .find(|(node, _)| *node == id)
+            .map(|(_, d)| d)
+    }
+
+    fn has_inputs(&self, id: NodeId) -> bool {
+        self.graph.nodes[id.0]
+            .as_ref()
+            .is_some_and(|n| !n.inputs.is_empty())
+    }
+
+    fn connect(&mut self, from: NodeId, from_port: usize, to: NodeId, to_port: usize) {
+        let rate = self.graph.nodes[from.0]
+            .as_ref()
+            .and_then(|n| n.outputs.iter().find(|p| p.id == PortId(from_port)))
+            .map_or(Rate::Audio, |p| p.rate.clone());
+        let edge = Edge {
+            from_node: from,
+            from_port: PortId(from_port),
+            to_node: to,
+            to_port: PortId(to_port),
+            rate,
+        };
+        if let Err(e) = self.graph.add_edge(edge) {
+            self.fail(e.into());
+        }
+    }
+
+    /// Append a node after the current one.
+    fn series(&mut self, id: NodeId) {
+        if let Some(tail) = self.tail {
+            if self.has_inputs(id) {
+                self.connect(tail, 0, id, 0);
+            }
+        }
+        self.tail = Some(id);
+    }
+
+    /// Connect the gate source to a node whose first input is a gate.
+    fn wire_gate(&mut self, id: NodeId) {
+        let takes_gate = self
+            .descriptor(id)
+            .and_then(|d| d.inputs.first())
+            .is_some_and(|p| p.role == PortRole::Gate);
+        if !takes_gate {
+            return;
+        }
+        let gate = match self.gate {
+            Some(gate) => gate,
+            None => {
+                let gate = self.insert(Constant { value: 1.0 });
+                self.gate = Some(gate);
+                gate
+            }
+        };
+        self.connect(gate, 0, id, 0);
+    }
+
+    fn modulate<T: NodeDef + Describe + 'static>(&mut self, param: &str, source: T) {
+        let Some(target) = self.tail else {
+            self.fail(BuildError::NoSignal);
+            return;
+        };
+        let port = self.descriptor(target).and_then(|d| {
+            let param = d.param(param)?;
+            d.modulation_port(param.id)
+        });
+        let Some(port) = port else {
+            self.fail(BuildError::UnknownParam {
+                node: target,
+                param: param.to_string(),
+            });
+            return;
+        };
+        let id = self.insert(source);
+        self.nodes.push(id);
+        self.wire_gate(id);
+        self.connect(id, 0, target, port.0);
+    }
+
+    fn split(&mut self) {
+        self.forks.push(Fork {
+            source: self.tail,
+            tails: Vec::new(),
+        });
+    }
+
+    /// Rewind to the fork source before a branch is built.
+    fn begin_branch(&mut self) {
+        if self.forks.is_empty() {
+            self.split();
+        }
+        self.tail = self.forks.last().and_then(|f| f.source);
+    }
+
+    fn end_branch(&mut self) {
+        match (self.tail, self.forks.last_mut()) {
+            (Some(tail), Some(fork)) => fork.tails.push(tail),
+            _ => self.fail(BuildError::NoSignal),
+        }
+    }
+
+    /// Sum all branch ends with two-input mixers.
+    fn merge(&mut self) {
+        let Some(fork) = self.forks.pop() else {
+            self.fail(BuildError::NothingToMerge);
+            return;
+        };
+        let mut tails = fork.tails.into_iter();
+        let Some(mut sum) = tails.next() else {
+            self.fail(BuildError::NothingToMerge);
+            return;
+        };
+        for tail in tails {
+            let mix = self.graph.add_node(NodeType::Mix);
+            self.connect(sum, 0, mix, 0);
+            self.connect(tail, 0, mix, 1);
+            sum = mix;
+        }
+        self.tail = Some(sum);
+    }
+
+    fn finish(self, block_size: usize) -> Result<BuiltGraph, BuildError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        let plan = Plan::compile(&self.graph, block_size)?;
+        Ok(BuiltGraph {
+            graph: self.graph,
+            plan,
+            nodes: self.nodes,
+            last: self.tail,
+        })
+    }
+}
 
 /// SynthBuilder for building simple synth graphs
+///
+/// Oscillators start a new chain, filters are appended in series and
+/// envelopes are applied as amplitude. Envelopes are gated by the node passed
+/// to [`SynthBuilder::gate`], or by a constant held gate if none is given.
 pub struct SynthBuilder {
-    graph: Graph,
+    chain: Chain,
 }
 
 impl Default for SynthBuilder {
@@ -15,38 +250,108 @@ impl Default for SynthBuilder {
 impl SynthBuilder {
     pub fn new() -> Self {
         Self {
-            graph: Graph::new(),
+            chain: Chain::new(),
+        }
+    }
+
+    /// Set the gate source for envelopes added afterwards.
+    pub fn gate<T: NodeDef + Describe + 'static>(mut self, gate: T) -> Self {
+        let id = self.chain.insert(gate);
+        self.chain.nodes.push(id);
+        self.chain.gate = Some(id);
+        self
+    }
+
+    pub fn add_oscillator<T: NodeDef + Describe + 'static>(mut self, osc: T) -> Self {
+        let id = self.chain.insert(osc);
+        self.chain.nodes.push(id);
+        self.chain.tail = Some(id);
+        self
+    }
+
+    pub fn add_filter<T: NodeDef + Describe + 'static>(mut self, filter: T) -> Self {
+        let id = self.chain.insert(filter);
+        self.chain.nodes.push(id);
+        self.chain.series(id);
+        self
+    }
+
+    /// Multiply the current signal by an envelope.
+    pub fn add_envelope<T: NodeDef + Describe + 'static>(mut self, env: T) -> Self {
+        let id = self.chain.insert(env);
+        self.chain.nodes.push(id);
+        self.chain.wire_gate(id);
+        match self.chain.tail {
+            Some(tail) => {
+                let vca = self.chain.insert(RingMod { mix: 1.0 });
+                self.chain.connect(tail, 0, vca, 0);
+                self.chain.connect(id, 0, vca, 1);
+                self.chain.tail = Some(vca);
+            }
+            None => self.chain.tail = Some(id),
         }
+        self
     }
 
-    pub fn add_oscillator<T: auxide::node::NodeDef + 'static>(mut self, osc: T) -> Self {
-        self.graph.add_external_node(osc);
+    /// Route a modulator (envelope, LFO, ...) into the modulation port of
+    /// the named parameter on the current node.
+    pub fn modulate<T: NodeDef + Describe + 'static>(mut self, param: &str, source: T) -> Self {
+        self.chain.modulate(param, source);
         self
     }
 
-    pub fn add_filter<T: auxide::node::NodeDef + 'static>(mut self, filter: T) -> Self {
-        self.graph.add_external_node(filter);
+    /// Terminate the chain in an output sink.
+    pub fn add_output(mut self) -> Self {
+        let id = self.chain.graph.add_node(NodeType::OutputSink);
+        self.chain.nodes.push(id);
+        self.chain.series(id);
         self
     }
 
-    pub fn add_envelope<T: auxide::node::NodeDef + 'static>(mut self, env: T) -> Self {
-        self.graph.add_external_node(env);
+    /// Open a fork at the current node; branches start from here.
+    pub fn split(mut self) -> Self {
+        self.chain.split();
         self
     }
 
+    /// Build one branch of the open fork, opening one if needed.
+    pub fn parallel(mut self, branch: impl FnOnce(Self) -> Self) -> Self {
+        self.chain.begin_branch();
+        let mut builder = branch(self);
+        builder.chain.end_branch();
+        builder
+    }
+
+    /// Sum the branches of the open fork and continue from the sum.
+    pub fn merge(mut self) -> Self {
+        self.chain.merge();
+        self
+    }
+
+    /// Nodes added so far, in call order. Mixers, VCAs and the default gate
+    /// inserted by the builder are not listed.
+    pub fn node_ids(&self) -> &[NodeId] {
+        &self.chain.nodes
+    }
+
+    /// The current end of the chain.
+    pub fn last_node(&self) -> Option<NodeId> {
+        self.chain.tail
+    }
+
+    /// Return the graph; wiring errors are only reported by [`Self::build`].
     pub fn build_graph(self) -> Graph {
-        self.graph
+        self.chain.graph
     }
 
-    pub fn build(self, block_size: usize) -> Result<(Graph, Plan), auxide::plan::PlanError> {
-        let plan = Plan::compile(&self.graph, block_size)?;
-        Ok((self.graph, plan))
+    pub fn build(self, block_size: usize) -> Result<BuiltGraph, BuildError> {
+        self.chain.finish(block_size)
     }
 }
 
 /// EffectsChainBuilder for building effect chains
 pub struct EffectsChainBuilder {
-    graph: Graph,
+    chain: Chain,
 }
 
 impl Default for EffectsChainBuilder {
@@ -58,31 +363,77 @@ impl Default for EffectsChainBuilder {
 impl EffectsChainBuilder {
     pub fn new() -> Self {
         Self {
-            graph: Graph::new(),
+            chain: Chain::new(),
         }
     }
 
+    /// Start the chain at a pass-through input node.
     pub fn add_input(mut self) -> Self {
-        self.graph.add_node(NodeType::Dummy);
+        let id = self.chain.graph.add_node(NodeType::Dummy);
+        self.chain.nodes.push(id);
+        self.chain.tail = Some(id);
+        self
+    }
+
+    pub fn add_effect<T: NodeDef + Describe + 'static>(mut self, effect: T) -> Self {
+        let id = self.chain.insert(effect);
+        self.chain.nodes.push(id);
+        self.chain.series(id);
         self
     }
 
-    pub fn add_effect<T: auxide::node::NodeDef + 'static>(mut self, effect: T) -> Self {
-        self.graph.add_external_node(effect);
+    /// Route a modulator into the modulation port of the named parameter on
+    /// the current effect.
+    pub fn modulate<T: NodeDef + Describe + 'static>(mut self, param: &str, source: T) -> Self {
+        self.chain.modulate(param, source);
         self
     }
 
     pub fn add_output(mut self) -> Self {
-        self.graph.add_node(NodeType::OutputSink);
+        let id = self.chain.graph.add_node(NodeType::OutputSink);
+        self.chain.nodes.push(id);
+        self.chain.series(id);
         self
     }
 
+    /// Open a fork at the current node; branches start from here.
+    pub fn split(mut self) -> Self {
+        self.chain.split();
+        self
+    }
+
+    /// Build one branch of the open fork, opening one if needed. A branch
+    /// that adds no effects passes the dry signal.
+    pub fn parallel(mut self, branch: impl FnOnce(Self) -> Self) -> Self {
+        self.chain.begin_branch();
+        let mut builder = branch(self);
+        builder.chain.end_branch();
+        builder
+    }
+
+    /// Sum the branches of the open fork and continue from the sum.
+    pub fn merge(mut self) -> Self {
+        self.chain.merge();
+        self
+    }
+
+    /// Nodes added so far, in call order. Mixers inserted by `merge` are
+    /// not listed.
+    pub fn node_ids(&self) -> &[NodeId] {
+        &self.chain.nodes
+    }
+
+    /// The current end of the chain.
+    pub fn last_node(&self) -> Option<NodeId> {
+        self.chain.tail
+    }
+
+    /// Return the graph; wiring errors are only reported by [`Self::build`].
     pub fn build_graph(self) -> Graph {
-        self.graph
+        self.chain.graph
     }
 
-    pub fn build(self, block_size: usize) -> Result<(Graph, Plan), auxide::plan::PlanError> {
-        let plan = Plan::compile(&self.graph, block_size)?;
-        Ok((self.graph, plan))
+    pub fn build(self, block_size: usize) -> Result<BuiltGraph, BuildError> {
+        self.chain.finish(block_size)
     }
 }
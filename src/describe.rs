@@ -0,0 +1,107 @@
+#![forbid(unsafe_code)]
+
+//! Node introspection: names, roles and units of ports and parameters.
+//!
+//! Generic editors and preset systems can be written against [`Describe`]
+//! instead of hard-coding the port layout of each node.
+
+use auxide::graph::PortId;
+
+use crate::params::{ParamDesc, ParamUnit, Parameterized};
+
+/// What a port carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PortRole {
+    /// Audio signal.
+    Audio,
+    /// Sidechain key signal for dynamics processors.
+    Sidechain,
+    /// Per-sample offset added to the parameter with the given id.
+    Modulation { param: usize },
+    /// Gate signal; high above 0.5.
+    Gate,
+    /// Trigger signal; a rising edge above 0.5 fires an event.
+    Trigger,
+    /// Control-rate value such as an envelope, LFO or meter reading.
+    Control,
+}
+
+/// Descriptor of a single input or output port.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PortDesc {
+    pub id: PortId,
+    pub name: &'static str,
+    pub role: PortRole,
+    pub unit: ParamUnit,
+}
+
+impl PortDesc {
+    pub const fn new(id: usize, name: &'static str, role: PortRole, unit: ParamUnit) -> Self {
+        Self {
+            id: PortId(id),
+            name,
+            role,
+            unit,
+        }
+    }
+
+    pub const fn audio(id: usize, name: &'static str) -> Self {
+        Self::new(id, name, PortRole::Audio, ParamUnit::None)
+    }
+
+    pub const fn modulation(id: usize, name: &'static str, param: usize, unit: ParamUnit) -> Self {
+        Self::new(id, name, PortRole::Modulation { param }, unit)
+    }
+
+    pub const fn control(id: usize, name: &'static str, unit: ParamUnit) -> Self {
+        Self::new(id, name, PortRole::Control, unit)
+    }
+}
+
+/// Full description of a node.
+#[derive(Debug, Clone, Copy)]
+pub struct NodeDescriptor {
+    pub name: &'static str,
+    pub params: &'static [ParamDesc],
+    pub inputs: &'static [PortDesc],
+    pub outputs: &'static [PortDesc],
+}
+
+impl NodeDescriptor {
+    /// Input port that modulates the given parameter, if any.
+    pub fn modulation_port(&self, param: usize) -> Option<PortId> {
+        self.inputs
+            .iter()
+            .find(|p| p.role == PortRole::Modulation { param })
+            .map(|p| p.id)
+    }
+
+    /// Look up a parameter by name.
+    pub fn param(&self, name: &str) -> Option<&'static ParamDesc> {
+        self.params.iter().find(|p| p.name == name)
+    }
+}
+
+/// Nodes that can describe their ports and parameters.
+///
+/// Port descriptors are listed in `PortId` order and match the node's
+/// `input_ports`/`output_ports` one to one.
+pub trait Describe: Parameterized {
+    /// Display name of the node type.
+    fn name(&self) -> &'static str;
+
+    /// Descriptors of the input ports.
+    fn inputs(&self) -> &'static [PortDesc];
+
+    /// Descriptors of the output ports.
+    fn outputs(&self) -> &'static [PortDesc];
+
+    fn describe(&self) -> NodeDescriptor {
+        NodeDescriptor {
+            name: self.name(),
+            params: self.params(),
+            inputs: self.inputs(),
+            outputs: self.outputs(),
+        }
+    }
+}
@@ -0,0 +1,724 @@
+#![forbid(unsafe_code)]
+
+//! Filter coefficient design.
+//!
+//! [`BiquadCoefficients::design`] implements the formulas of Robert
+//! Bristow-Johnson's Audio EQ Cookbook. Coefficients are computed in `f64`
+//! and normalized so `a0 = 1`, matching the `b0..a2` fields of
+//! `BiquadFilter`.
+//!
+//! [`FilterDesign`] builds Butterworth, Chebyshev, Bessel and elliptic
+//! filters of any order from their analog prototypes: the pole/zero set at
+//! 1 rad/s is scaled or inverted to the prewarped cutoff, mapped through
+//! the bilinear transform, and paired into second-order sections.
+
+use std::f64::consts::{LN_2, PI};
+
+use num_complex::Complex64;
+
+/// Response of a cookbook biquad.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BiquadResponse {
+    Lowpass,
+    Highpass,
+    /// Bandpass with constant skirt gain; the peak gain equals Q.
+    BandpassSkirt,
+    /// Bandpass with a constant 0 dB peak.
+    BandpassPeak,
+    Notch,
+    Allpass,
+    /// Bell boosting or cutting by the gain at the centre frequency.
+    Peaking,
+    LowShelf,
+    HighShelf,
+}
+
+/// Width of a cookbook biquad's resonance, band or shelf transition.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Bandwidth {
+    /// Quality factor; 1/√2 is maximally flat for lowpass and highpass.
+    Q(f32),
+    /// Octaves between the -3 dB points of bandpass and notch, or between
+    /// the half-gain points of peaking.
+    Octaves(f32),
+    /// Shelf slope; 1 is the steepest that stays monotonic. Other responses
+    /// take the same damping as a shelf of their gain.
+    Slope(f32),
+}
+
+/// Normalized biquad coefficients, `a0 = 1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BiquadCoefficients {
+    pub b0: f32,
+    pub b1: f32,
+    pub b2: f32,
+    pub a1: f32,
+    pub a2: f32,
+}
+
+impl BiquadCoefficients {
+    /// Passes the input unchanged.
+    pub const IDENTITY: Self = Self {
+        b0: 1.0,
+        b1: 0.0,
+        b2: 0.0,
+        a1: 0.0,
+        a2: 0.0,
+    };
+
+    /// Cookbook design at `freq` Hz. `gain_db` only affects peaking and
+    /// shelving responses. The frequency is clamped inside `(0, Nyquist)` and
+    /// the bandwidth to a small positive floor, so modulated designs stay
+    /// stable.
+    pub fn design(
+        response: BiquadResponse,
+        freq: f32,
+        bandwidth: Bandwidth,
+        gain_db: f32,
+        sample_rate: f32,
+    ) -> Self {
+        let w0 = 2.0 * PI * (freq as f64 / sample_rate as f64).clamp(1e-6, 0.4999);
+        let (sin, cos) = w0.sin_cos();
+        let a = 10f64.powf(gain_db as f64 / 40.0);
+        let alpha = match bandwidth {
+            Bandwidth::Q(q) => sin / (2.0 * (q as f64).max(1e-4)),
+            Bandwidth::Octaves(bw) => sin * (LN_2 / 2.0 * (bw as f64).max(1e-4) * w0 / sin).sinh(),
+            Bandwidth::Slope(s) => {
+                let s = (s as f64).max(1e-4);
+                sin / 2.0 * ((a + 1.0 / a) * (1.0 / s - 1.0) + 2.0).max(1e-8).sqrt()
+            }
+        }
+        .max(1e-6);
+        let root = 2.0 * a.sqrt() * alpha;
+
+        let (b0, b1, b2, a0, a1, a2) = match response {
+            BiquadResponse::Lowpass => (
+                (1.0 - cos) / 2.0,
+                1.0 - cos,
+                (1.0 - cos) / 2.0,
+                1.0 + alpha,
+                -2.0 * cos,
+                1.0 - alpha,
+            ),
+            BiquadResponse::Highpass => (
+                (1.0 + cos) / 2.0,
+                -(1.0 + cos),
+                (1.0 + cos) / 2.0,
+                1.0 + alpha,
+                -2.0 * cos,
+                1.0 - alpha,
+            ),
+            BiquadResponse::BandpassSkirt => (
+                sin / 2.0,
+                0.0,
+                -sin / 2.0,
+                1.0 + alpha,
+                -2.0 * cos,
+                1.0 - alpha,
+            ),
+            BiquadResponse::BandpassPeak => {
+                (alpha, 0.0, -alpha, 1.0 + alpha, -2.0 * cos, 1.0 - alpha)
+            }
+            BiquadResponse::Notch => (1.0, -2.0 * cos, 1.0, 1.0 + alpha, -2.0 * cos, 1.0 - alpha),
+            BiquadResponse::Allpass => (
+                1.0 - alpha,
+                -2.0 * cos,
+                1.0 + alpha,
+                1.0 + alpha,
+                -2.0 * cos,
+                1.0 - alpha,
+            ),
+            BiquadResponse::Peaking => (
+                1.0 + alpha * a,
+                -2.0 * cos,
+                1.0 - alpha * a,
+                1.0 + alpha / a,
+                -2.0 * cos,
+                1.0 - alpha / a,
+            ),
+            BiquadResponse::LowShelf => (
+                a * ((a + 1.0) - (a - 1.0) * cos + root),
+                2.0 * a * ((a - 1.0) - (a + 1.0) * cos),
+                a * ((a + 1.0) - (a - 1.0) * cos - root),
+                (a + 1.0) + (a - 1.0) * cos + root,
+                -2.0 * ((a - 1.0) + (a + 1.0) * cos),
+                (a + 1.0) + (a - 1.0) * cos - root,
+            ),
+            BiquadResponse::HighShelf => (
+                a * ((a + 1.0) + (a - 1.0) * cos + root),
+                -2.0 * a * ((a - 1.0) + (a + 1.0) * cos),
+                a * ((a + 1.0) + (a - 1.0) * cos - root),
+                (a + 1.0) - (a - 1.0) * cos + root,
+                2.0 * ((a - 1.0) - (a + 1.0) * cos),
+                (a + 1.0) - (a - 1.0) * cos - root,
+            ),
+        };
+
+        Self {
+            b0: (b0 / a0) as f32,
+            b1: (b1 / a0) as f32,
+            b2: (b2 / a0) as f32,
+            a1: (a1 / a0) as f32,
+            a2: (a2 / a0) as f32,
+        }
+    }
+
+    /// Allpass with the same poles: the denominator reversed into the
+    /// numerator, or `a1 + z^-1` for a first-order section.
+    pub fn allpass(&self) -> Self {
+        if self.a2 == 0.0 {
+            Self {
+                b0: self.a1,
+                b1: 1.0,
+                b2: 0.0,
+                a1: self.a1,
+                a2: 0.0,
+            }
+        } else {
+            Self {
+                b0: self.a2,
+                b1: self.a1,
+                b2: 1.0,
+                a1: self.a1,
+                a2: self.a2,
+            }
+        }
+    }
+
+    /// Magnitude response at `freq` Hz.
+    pub fn magnitude(&self, freq: f32, sample_rate: f32) -> f32 {
+        let w = 2.0 * PI * freq as f64 / sample_rate as f64;
+        let (sin1, cos1) = w.sin_cos();
+        let (sin2, cos2) = (2.0 * w).sin_cos();
+        let (b0, b1, b2) = (self.b0 as f64, self.b1 as f64, self.b2 as f64);
+        let (a1, a2) = (self.a1 as f64, self.a2 as f64);
+        let num = (b0 + b1 * cos1 + b2 * cos2).hypot(b1 * sin1 + b2 * sin2);
+        let den = (1.0 + a1 * cos1 + a2 * cos2).hypot(a1 * sin1 + a2 * sin2);
+        (num / den) as f32
+    }
+}
+
+/// Analog prototype of a high-order design.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FilterFamily {
+    /// Maximally flat; -3 dB at the cutoff.
+    Butterworth,
+    /// Equiripple passband of `ripple_db`; the cutoff is the passband edge.
+    ChebyshevI { ripple_db: f32 },
+    /// Equiripple stopband at least `attenuation_db` down; the cutoff is
+    /// the stopband edge.
+    ChebyshevII { attenuation_db: f32 },
+    /// Maximally flat group delay; -3 dB at the cutoff.
+    Bessel,
+    /// Equiripple in both bands; the cutoff is the passband edge.
+    Elliptic { ripple_db: f32, attenuation_db: f32 },
+}
+
+/// Which side of the cutoff a high-order design passes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterBand {
+    Lowpass,
+    Highpass,
+}
+
+/// Zeros, poles and gain of a transfer function, in the s or z plane.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZeroPoleGain {
+    pub zeros: Vec<Complex64>,
+    pub poles: Vec<Complex64>,
+    pub gain: f64,
+}
+
+impl ZeroPoleGain {
+    /// Transfer function at `x`: `s = jω` for analog sets, `z = e^(jω)` for
+    /// digital ones.
+    pub fn evaluate(&self, x: Complex64) -> Complex64 {
+        let num: Complex64 = self.zeros.iter().map(|z| x - z).product();
+        let den: Complex64 = self.poles.iter().map(|p| x - p).product();
+        num / den * self.gain
+    }
+}
+
+/// High-order lowpass or highpass design from an analog prototype,
+/// bilinear-transformed with the cutoff prewarped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FilterDesign {
+    pub family: FilterFamily,
+    pub band: FilterBand,
+    /// Number of poles; 0 is treated as 1.
+    pub order: usize,
+    /// Cutoff in Hz, as defined by the family.
+    pub cutoff: f32,
+}
+
+impl FilterDesign {
+    pub fn new(family: FilterFamily, band: FilterBand, order: usize, cutoff: f32) -> Self {
+        Self {
+            family,
+            band,
+            order,
+            cutoff,
+        }
+    }
+
+    /// Normalized lowpass prototype with its cutoff at 1 rad/s.
+    pub fn prototype(&self) -> ZeroPoleGain {
+        let n = self.order.max(1);
+        match self.family {
+            FilterFamily::Butterworth => butterworth(n),
+            FilterFamily::ChebyshevI { ripple_db } => chebyshev1(n, ripple_db as f64),
+            FilterFamily::ChebyshevII { attenuation_db } => chebyshev2(n, attenuation_db as f64),
+            FilterFamily::Bessel => bessel(n),
+            FilterFamily::Elliptic {
+                ripple_db,
+                attenuation_db,
+            } => elliptic(n, ripple_db as f64, attenuation_db as f64),
+        }
+    }
+
+    /// Analog filter with its cutoff prewarped for `sample_rate`.
+    pub fn analog(&self, sample_rate: f32) -> ZeroPoleGain {
+        let fs = sample_rate as f64;
+        let fraction = (self.cutoff as f64 / fs).clamp(1e-6, 0.4999);
+        let warped = 2.0 * fs * (PI * fraction).tan();
+        let proto = self.prototype();
+        match self.band {
+            FilterBand::Lowpass => {
+                let degree = proto.poles.len() - proto.zeros.len();
+                ZeroPoleGain {
+                    zeros: proto.zeros.iter().map(|z| z * warped).collect(),
+                    poles: proto.poles.iter().map(|p| p * warped).collect(),
+                    gain: proto.gain * warped.powi(degree as i32),
+                }
+            }
+            FilterBand::Highpass => {
+                let degree = proto.poles.len() - proto.zeros.len();
+                let num: Complex64 = proto.zeros.iter().map(|z| -z).product();
+                let den: Complex64 = proto.poles.iter().map(|p| -p).product();
+                let mut zeros: Vec<Complex64> = proto.zeros.iter().map(|z| warped / z).collect();
+                zeros.extend(std::iter::repeat_n(Complex64::new(0.0, 0.0), degree));
+                ZeroPoleGain {
+                    zeros,
+                    poles: proto.poles.iter().map(|p| warped / p).collect(),
+                    gain: proto.gain * (num / den).re,
+                }
+            }
+        }
+    }
+
+    /// Digital filter at `sample_rate`.
+    pub fn digital(&self, sample_rate: f32) -> ZeroPoleGain {
+        let analog = self.analog(sample_rate);
+        let fs2 = 2.0 * sample_rate as f64;
+        let bilinear = |s: &Complex64| (fs2 + s) / (fs2 - s);
+        let degree = analog.poles.len() - analog.zeros.len();
+        let num: Complex64 = analog.zeros.iter().map(|z| fs2 - z).product();
+        let den: Complex64 = analog.poles.iter().map(|p| fs2 - p).product();
+        let mut zeros: Vec<Complex64> = analog.zeros.iter().map(bilinear).collect();
+        zeros.extend(std::iter::repeat_n(Complex64::new(-1.0, 0.0), degree));
+        ZeroPoleGain {
+            zeros,
+            poles: analog.poles.iter().map(bilinear).collect(),
+            gain: analog.gain * (num / den).re,
+        }
+    }
+
+    /// Cascade of second-order sections, ordered by pole radius so the
+    /// highest-Q section runs last; odd orders include one first-order
+    /// section (`b2 = a2 = 0`). Each section has unit gain at DC (lowpass)
+    /// or Nyquist (highpass), except the first, which carries the passband
+    /// gain of the whole cascade.
+    ///
+    /// Coefficients are rounded to `f32`; very low cutoffs at high orders
+    /// place poles close to `z = 1`, where that rounding moves the response
+    /// by a few hundredths of a dB.
+    pub fn sections(&self, sample_rate: f32) -> Vec<BiquadCoefficients> {
+        let digital = self.digital(sample_rate);
+        let mut poles = pairs(&digital.poles);
+        let mut zeros = pairs(&digital.zeros);
+        // Poles nearest the unit circle choose their zeros first
+        poles.sort_by(|a, b| b.radius().total_cmp(&a.radius()));
+
+        let reference = match self.band {
+            FilterBand::Lowpass => Complex64::new(1.0, 0.0),
+            FilterBand::Highpass => Complex64::new(-1.0, 0.0),
+        };
+        let mut sections = Vec::with_capacity(poles.len());
+        let mut gain = digital.gain;
+        for pole in poles {
+            // Prefer a zero group of the same size, then the closest
+            let key = |zero: &Pair| (zero.is_single() != pole.is_single(), pole.distance(zero));
+            let nearest = (0..zeros.len()).min_by(|&i, &j| {
+                let (a, b) = (key(&zeros[i]), key(&zeros[j]));
+                a.0.cmp(&b.0).then(a.1.total_cmp(&b.1))
+            });
+            let zero = match nearest {
+                Some(i) => zeros.swap_remove(i),
+                None => Pair::Single(Complex64::new(0.0, 0.0)),
+            };
+            let (b1, b2) = zero.polynomial();
+            let (a1, a2) = pole.polynomial();
+            let r = reference;
+            // Real at z = ±1
+            let response = ((r * r + r * b1 + b2) / (r * r + r * a1 + a2)).re;
+            gain *= response;
+            sections.push(BiquadCoefficients {
+                b0: (1.0 / response) as f32,
+                b1: (b1 / response) as f32,
+                b2: (b2 / response) as f32,
+                a1: a1 as f32,
+                a2: a2 as f32,
+            });
+        }
+        sections.reverse();
+        if let Some(first) = sections.first_mut() {
+            let g = gain as f32;
+            first.b0 *= g;
+            first.b1 *= g;
+            first.b2 *= g;
+        }
+        sections
+    }
+}
+
+/// A conjugate pair or real pair of roots, or a lone real root.
+#[derive(Debug, Clone, Copy)]
+enum Pair {
+    Conjugate(Complex64),
+    Real(f64, f64),
+    Single(Complex64),
+}
+
+impl Pair {
+    fn is_single(&self) -> bool {
+        matches!(self, Pair::Single(_))
+    }
+
+    fn radius(&self) -> f64 {
+        match self {
+            Pair::Conjugate(c) | Pair::Single(c) => c.norm(),
+            Pair::Real(a, b) => a.abs().max(b.abs()),
+        }
+    }
+
+    fn distance(&self, other: &Pair) -> f64 {
+        let point = |p: &Pair| match *p {
+            Pair::Conjugate(c) | Pair::Single(c) => c,
+            Pair::Real(a, b) => Complex64::new(if a.abs() > b.abs() { a } else { b }, 0.0),
+        };
+        (point(self) - point(other)).norm()
+    }
+
+    /// `(c1, c2)` of the monic `z^2 + c1 z + c2` (or `z + c1`) with these roots.
+    fn polynomial(&self) -> (f64, f64) {
+        match *self {
+            Pair::Conjugate(c) => (-2.0 * c.re, c.norm_sqr()),
+            Pair::Real(a, b) => (-(a + b), a * b),
+            Pair::Single(c) => (-c.re, 0.0),
+        }
+    }
+}
+
+/// Group conjugate-symmetric roots into pairs, real roots two at a time.
+fn pairs(roots: &[Complex64]) -> Vec<Pair> {
+    let tolerance = |c: &Complex64| 1e-9 * c.norm().max(1.0);
+    let mut out: Vec<Pair> = roots
+        .iter()
+        .filter(|c| c.im > tolerance(c))
+        .map(|&c| Pair::Conjugate(c))
+        .collect();
+    let mut reals: Vec<f64> = roots
+        .iter()
+        .filter(|c| c.im.abs() <= tolerance(c))
+        .map(|c| c.re)
+        .collect();
+    reals.sort_by(f64::total_cmp);
+    let mut chunks = reals.chunks_exact(2);
+    out.extend(chunks.by_ref().map(|c| Pair::Real(c[0], c[1])));
+    out.extend(
+        chunks
+            .remainder()
+            .iter()
+            .map(|&r| Pair::Single(Complex64::new(r, 0.0))),
+    );
+    out
+}
+
+/// Poles `-e^(jθ)` spread over the left half of the unit circle.
+fn unit_circle_poles(n: usize) -> impl Iterator<Item = Complex64> {
+    (0..n).map(move |i| {
+        let m = 2.0 * i as f64 + 1.0 - n as f64;
+        -Complex64::from_polar(1.0, PI * m / (2.0 * n as f64))
+    })
+}
+
+fn butterworth(n: usize) -> ZeroPoleGain {
+    ZeroPoleGain {
+        zeros: Vec::new(),
+        poles: unit_circle_poles(n).collect(),
+        gain: 1.0,
+    }
+}
+
+fn chebyshev1(n: usize, ripple_db: f64) -> ZeroPoleGain {
+    let eps = (10f64.powf(0.1 * ripple_db.max(1e-6)) - 1.0).sqrt();
+    let mu = (1.0 / eps).asinh() / n as f64;
+    let poles: Vec<Complex64> = unit_circle_poles(n)
+        .map(|p| Complex64::new(mu.sinh() * p.re, mu.cosh() * p.im))
+        .collect();
+    // Even orders start the passband at the bottom of the ripple
+    let dc = if n % 2 == 1 {
+        1.0
+    } else {
+        1.0 / (1.0 + eps * eps).sqrt()
+    };
+    let gain = dc * poles.iter().map(|p| -p).product::<Complex64>().re;
+    ZeroPoleGain {
+        zeros: Vec::new(),
+        poles,
+        gain,
+    }
+}
+
+fn chebyshev2(n: usize, attenuation_db: f64) -> ZeroPoleGain {
+    let eps = 1.0 / (10f64.powf(0.1 * attenuation_db.max(1e-6)) - 1.0).sqrt();
+    let mu = (1.0 / eps).asinh() / n as f64;
+    let zeros: Vec<Complex64> = (0..n)
+        .map(|i| 2.0 * i as f64 + 1.0 - n as f64)
+        .filter(|&m| m != 0.0)
+        .map(|m| Complex64::new(0.0, 1.0 / (PI * m / (2.0 * n as f64)).sin()))
+        .collect();
+    let poles: Vec<Complex64> = unit_circle_poles(n)
+        .map(|p| 1.0 / Complex64::new(mu.sinh() * p.re, mu.cosh() * p.im))
+        .collect();
+    let num: Complex64 = zeros.iter().map(|z| -z).product();
+    let den: Complex64 = poles.iter().map(|p| -p).product();
+    ZeroPoleGain {
+        zeros,
+        poles,
+        gain: (den / num).re,
+    }
+}
+
+/// Roots of the reverse Bessel polynomial by Aberth iteration, scaled to
+/// -3 dB at 1 rad/s.
+fn bessel(n: usize) -> ZeroPoleGain {
+    // a[k] is the coefficient of s^k; the polynomial is monic
+    let mut a = vec![1.0f64; n + 1];
+    for k in (1..=n).rev() {
+        a[k - 1] = a[k] * (2 * n - k + 1) as f64 * k as f64 / (2 * (n - k + 1)) as f64;
+    }
+    let eval = |s: Complex64| {
+        let mut p = Complex64::new(a[n], 0.0);
+        let mut dp = Complex64::new(0.0, 0.0);
+        for k in (0..n).rev() {
+            dp = dp * s + p;
+            p = p * s + a[k];
+        }
+        (p, dp)
+    };
+
+    let radius = a[0].powf(1.0 / n as f64);
+    let mut roots: Vec<Complex64> = (0..n)
+        .map(|i| Complex64::from_polar(radius, PI * (2.0 * i as f64 + 1.5) / n as f64))
+        .collect();
+    for _ in 0..500 {
+        let mut largest = 0.0f64;
+        for i in 0..n {
+            let (p, dp) = eval(roots[i]);
+            let ratio = p / dp;
+            let repulsion: Complex64 = (0..n)
+                .filter(|&j| j != i)
+                .map(|j| 1.0 / (roots[i] - roots[j]))
+                .sum();
+            let step = ratio / (1.0 - ratio * repulsion);
+            roots[i] -= step;
+            largest = largest.max(step.norm() / roots[i].norm());
+        }
+        if largest < 1e-15 {
+            break;
+        }
+    }
+    // Restore exact conjugate symmetry
+    for root in roots.iter_mut() {
+        if root.im.abs() < 1e-9 * root.norm() {
+            root.im = 0.0;
+        }
+    }
+    let upper: Vec<Complex64> = roots.iter().filter(|r| r.im > 0.0).copied().collect();
+    let mut poles: Vec<Complex64> = roots.iter().filter(|r| r.im == 0.0).copied().collect();
+    for p in upper {
+        poles.push(p);
+        poles.push(p.conj());
+    }
+
+    // Bisect for the -3 dB frequency of the delay-normalized prototype
+    let magnitude_sqr = |w: f64| {
+        poles
+            .iter()
+            .map(|p| p.norm_sqr() / (Complex64::new(0.0, w) - p).norm_sqr())
+            .product::<f64>()
+    };
+    let (mut lo, mut hi) = (0.0, 4.0 * n as f64);
+    for _ in 0..100 {
+        let mid = 0.5 * (lo + hi);
+        if magnitude_sqr(mid) > 0.5 {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    let poles: Vec<Complex64> = poles.iter().map(|p| p / lo).collect();
+    let gain = poles.iter().map(|p| -p).product::<Complex64>().re;
+    ZeroPoleGain {
+        zeros: Vec::new(),
+        poles,
+        gain,
+    }
+}
+
+/// Descending Landen moduli of `k`, used by the elliptic functions below.
+fn landen(k: f64) -> Vec<f64> {
+    let mut moduli = Vec::new();
+    let mut k = k;
+    while k > 1e-15 && moduli.len() < 32 {
+        k = (k / (1.0 + (1.0 - k * k).sqrt())).powi(2);
+        moduli.push(k);
+    }
+    moduli
+}
+
+/// Jacobi `cd(uK, k)` by descending Landen transformation.
+fn cde(u: Complex64, k: f64) -> Complex64 {
+    let mut w = (u * PI / 2.0).cos();
+    for v in landen(k).iter().rev() {
+        w = (1.0 + v) * w / (1.0 + v * w * w);
+    }
+    w
+}
+
+/// Jacobi `sn(uK, k)` by descending Landen transformation.
+fn sne(u: Complex64, k: f64) -> Complex64 {
+    let mut w = (u * PI / 2.0).sin();
+    for v in landen(k).iter().rev() {
+        w = (1.0 + v) * w / (1.0 + v * w * w);
+    }
+    w
+}
+
+/// Inverse of `sne`, by ascending Landen transformation.
+fn asne(w: Complex64, k: f64) -> Complex64 {
+    let moduli = landen(k);
+    let mut w = w;
+    let mut previous = k;
+    for &v in &moduli {
+        w = w / (1.0 + (1.0 - w * w * previous * previous).sqrt()) * 2.0 / (1.0 + v);
+        previous = v;
+    }
+    w.asin() * 2.0 / PI
+}
+
+/// Elliptic prototype after Orfanidis, "Lecture Notes on Elliptic Filter
+/// Design".
+fn elliptic(n: usize, ripple_db: f64, attenuation_db: f64) -> ZeroPoleGain {
+    let ripple_db = ripple_db.max(1e-6);
+    let attenuation_db = attenuation_db.max(ripple_db + 1e-3);
+    let ep = (10f64.powf(0.1 * ripple_db) - 1.0).sqrt();
+    let es = (10f64.powf(0.1 * attenuation_db) - 1.0).sqrt();
+    let k1 = ep / es;
+    let half = n / 2;
+    let u: Vec<f64> = (1..=half)
+        .map(|i| (2.0 * i as f64 - 1.0) / n as f64)
+        .collect();
+
+    // Selectivity from the degree equation
+    let k1p = (1.0 - k1 * k1).sqrt();
+    let kp = k1p.powi(n as i32)
+        * u.iter()
+            .map(|&ui| sne(Complex64::new(ui, 0.0), k1p).re.powi(4))
+            .product::<f64>();
+    let k = (1.0 - kp * kp).sqrt();
+
+    let j = Complex64::new(0.0, 1.0);
+    let v0 = (-j * asne(j / ep, k1) / n as f64).re;
+    let mut zeros = Vec::with_capacity(2 * half);
+    let mut poles = Vec::with_capacity(n);
+    for &ui in &u {
+        let zero = j / (cde(Complex64::new(ui, 0.0), k) * k);
+        let pole = j * cde(Complex64::new(ui, -v0), k);
+        zeros.extend([zero, zero.conj()]);
+        poles.extend([pole, pole.conj()]);
+    }
+    if n % 2 == 1 {
+        let real = j * sne(Complex64::new(0.0, v0), k);
+        poles.push(Complex64::new(real.re, 0.0));
+    }
+
+    let dc = if n % 2 == 1 {
+        1.0
+    } else {
+        1.0 / (1.0 + ep * ep).sqrt()
+    };
+    let num: Complex64 = zeros.iter().map(|z| -z).product();
+    let den: Complex64 = poles.iter().map(|p| -p).product();
+    ZeroPoleGain {
+        zeros,
+        poles,
+        gain: dc * (den / num).re,
+    }
+}
+
+/// Slope of a Linkwitz-Riley crossover: a Butterworth filter applied twice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrossoverOrder {
+    /// 12 dB/octave.
+    Lr2,
+    /// 24 dB/octave.
+    Lr4,
+    /// 48 dB/octave.
+    Lr8,
+}
+
+impl CrossoverOrder {
+    /// Order of the Butterworth filter that is squared.
+    pub const fn butterworth_order(self) -> usize {
+        match self {
+            CrossoverOrder::Lr2 => 1,
+            CrossoverOrder::Lr4 => 2,
+            CrossoverOrder::Lr8 => 4,
+        }
+    }
+}
+
+/// Linkwitz-Riley crossover at one frequency. Both halves are 6 dB down at
+/// the crossover and sum to `allpass`; the LR2 highpass is inverted so the
+/// sum stays in phase.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkwitzRiley {
+    pub lowpass: Vec<BiquadCoefficients>,
+    pub highpass: Vec<BiquadCoefficients>,
+    pub allpass: Vec<BiquadCoefficients>,
+}
+
+impl LinkwitzRiley {
+    pub fn design(order: CrossoverOrder, freq: f32, sample_rate: f32) -> Self {
+        let n = order.butterworth_order();
+        let butterworth = |band| {
+            FilterDesign::new(FilterFamily::Butterworth, band, n, freq).sections(sample_rate)
+        };
+        let lowpass = butterworth(FilterBand::Lowpass);
+        let allpass = lowpass.iter().map(BiquadCoefficients::allpass).collect();
+        let mut highpass = butterworth(FilterBand::Highpass).repeat(2);
+        if n % 2 == 1 {
+            let first = &mut highpass[0];
+            first.b0 = -first.b0;
+            first.b1 = -first.b1;
+            first.b2 = -first.b2;
+        }
+        Self {
+            lowpass: lowpass.repeat(2),
+            highpass,
+            allpass,
+        }
+    }
+}
@@ -21,6 +21,16 @@ pub fn ms_to_samples(ms: f32, sample_rate: f32) -> usize {
     ((ms * sample_rate) / 1000.0).floor() as usize
 }
 
+/// Xorshift64 step; returns a uniform value in [0, 1).
+///
+/// `rng` must be seeded non-zero.
+pub fn xorshift(rng: &mut u64) -> f32 {
+    *rng ^= *rng << 13;
+    *rng ^= *rng >> 7;
+    *rng ^= *rng << 17;
+    (*rng >> 40) as f32 / (1u64 << 24) as f32
+}
+
 /// PolyBLEP correction for band-limited waveforms.
 pub fn polyblep(phase: f32, phase_inc: f32) -> f32 {
     let t = phase / phase_inc;
@@ -55,3 +65,234 @@ pub fn compute_exponential_coefficient(time_ms: f32, sample_rate: f32) -> f32 {
     }
     (-1.0 / (time_ms / 1000.0 * sample_rate)).exp()
 }
+
+/// 4-point cubic Hermite interpolation from a circular buffer.
+pub fn cubic_interpolate(buffer: &[f32], read_pos: f32) -> f32 {
+    let len = buffer.len();
+    if len == 0 {
+        return 0.0;
+    }
+    let idx = read_pos.floor() as usize;
+    let frac = read_pos - read_pos.floor();
+    let xm1 = buffer[(idx + len - 1) % len];
+    let x0 = buffer[idx % len];
+    let x1 = buffer[(idx + 1) % len];
+    let x2 = buffer[(idx + 2) % len];
+    let c1 = 0.5 * (x1 - xm1);
+    let c2 = xm1 - 2.5 * x0 + 2.0 * x1 - 0.5 * x2;
+    let c3 = 0.5 * (x2 - xm1) + 1.5 * (x0 - x1);
+    ((c3 * frac + c2) * frac + c1) * frac + x0
+}
+
+/// Fractional-read kernel for delay lines and sample buffers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    /// 2-point linear.
+    Linear,
+    /// 4-point cubic Hermite, as `cubic_interpolate`.
+    Cubic,
+    /// 4-point, 3rd-order Lagrange.
+    Lagrange,
+    /// 6-point, 5th-order polynomial with Niemitalo's published "optimal"
+    /// coefficients for 2x oversampled signals. It keeps unity gain at DC
+    /// but does not pass through the samples, and it rolls off towards the
+    /// top of the oversampled band.
+    Optimal,
+    /// 8-point Blackman-windowed sinc.
+    Sinc,
+}
+
+impl Interpolation {
+    /// Number of samples the kernel reads.
+    pub const fn taps(self) -> usize {
+        match self {
+            Interpolation::Linear => 2,
+            Interpolation::Cubic | Interpolation::Lagrange => 4,
+            Interpolation::Optimal => 6,
+            Interpolation::Sinc => 8,
+        }
+    }
+
+    /// Kernel weights for `x[1 - taps/2] ..= x[taps/2]` at fraction `t`.
+    fn weights(self, t: f64, out: &mut [f64]) {
+        match self {
+            Interpolation::Linear => {
+                out[0] = 1.0 - t;
+                out[1] = t;
+            }
+            Interpolation::Cubic => {
+                let (t2, t3) = (t * t, t * t * t);
+                out[0] = -0.5 * t3 + t2 - 0.5 * t;
+                out[1] = 1.5 * t3 - 2.5 * t2 + 1.0;
+                out[2] = -1.5 * t3 + 2.0 * t2 + 0.5 * t;
+                out[3] = 0.5 * t3 - 0.5 * t2;
+            }
+            Interpolation::Lagrange => {
+                for (k, w) in out.iter_mut().enumerate() {
+                    let xk = k as f64 - 1.0;
+                    *w = (0..4)
+                        .filter(|&m| m != k)
+                        .map(|m| {
+                            let xm = m as f64 - 1.0;
+                            (t - xm) / (xk - xm)
+                        })
+                        .product();
+                }
+            }
+            Interpolation::Optimal => {
+                // Niemitalo, "Polynomial Interpolators for High-Quality
+                // Resampling of Oversampled Audio": optimal 2x, 6-point,
+                // 5th-order, z-form. Rows are the z^0, z^2, z^4 (even) and
+                // z^1, z^3, z^5 (odd) coefficients of each point pair.
+                const EVEN: [[f64; 3]; 3] = [
+                    [
+                        0.405_133_960_071_457_13,
+                        0.092_517_944_384_243_93,
+                        0.002_348_066_035_706_7,
+                    ],
+                    [
+                        -0.191_337_682_540_351_94,
+                        0.161_878_444_879_435_92,
+                        0.029_460_171_431_119_12,
+                    ],
+                    [
+                        0.038_457_987_295_881_49,
+                        -0.057_129_361_042_426_44,
+                        0.018_667_509_299_210_7,
+                    ],
+                ];
+                const ODD: [[f64; 3]; 3] = [
+                    [
+                        0.283_428_063_389_066_9,
+                        0.217_032_770_240_549,
+                        0.013_092_947_487_315_15,
+                    ],
+                    [
+                        -0.164_716_261_905_545_42,
+                        -0.001_545_472_035_424_99,
+                        0.033_992_714_448_519_09,
+                    ],
+                    [
+                        0.043_179_501_852_256_09,
+                        -0.018_028_142_559_264_17,
+                        0.001_521_700_215_582_04,
+                    ],
+                ];
+                let z = t - 0.5;
+                let (z2, z3, z4, z5) = (z * z, z * z * z, z * z * z * z, z * z * z * z * z);
+                // Point pairs (x[j+1], x[-j]) share even and odd weights
+                for j in 0..3 {
+                    let even = EVEN[0][j] + EVEN[1][j] * z2 + EVEN[2][j] * z4;
+                    let odd = ODD[0][j] * z + ODD[1][j] * z3 + ODD[2][j] * z5;
+                    out[3 + j] = even + odd;
+                    out[2 - j] = even - odd;
+                }
+            }
+            Interpolation::Sinc => {
+                let half = 4.0;
+                let mut sum = 0.0;
+                for (k, w) in out.iter_mut().enumerate() {
+                    let x = k as f64 - (half - 1.0) - t;
+                    let sinc = if x == 0.0 {
+                        1.0
+                    } else {
+                        (std::f64::consts::PI * x).sin() / (std::f64::consts::PI * x)
+                    };
+                    let phase = std::f64::consts::PI * (x / half + 1.0);
+                    *w = sinc * (0.42 - 0.5 * phase.cos() + 0.08 * (2.0 * phase).cos());
+                    sum += *w;
+                }
+                for w in out.iter_mut() {
+                    *w /= sum;
+                }
+            }
+        }
+    }
+}
+
+/// Fractional positions tabulated by an `Interpolator`.
+const INTERPOLATOR_PHASES: usize = 256;
+
+/// An `Interpolation` kernel with its weights precomputed, for reading
+/// circular buffers without per-sample polynomial or sinc evaluation.
+///
+/// Build it in `init_state`; reads do not allocate.
+#[derive(Debug, Clone)]
+pub struct Interpolator {
+    kind: Interpolation,
+    /// `INTERPOLATOR_PHASES + 1` rows of `kind.taps()` weights.
+    table: Vec<f32>,
+}
+
+impl Interpolator {
+    /// Tabulate the weights of `kind`.
+    pub fn new(kind: Interpolation) -> Self {
+        let taps = kind.taps();
+        let mut table = vec![0.0; (INTERPOLATOR_PHASES + 1) * taps];
+        let mut weights = [0.0f64; 8];
+        for (p, row) in table.chunks_exact_mut(taps).enumerate() {
+            kind.weights(p as f64 / INTERPOLATOR_PHASES as f64, &mut weights[..taps]);
+            for (out, w) in row.iter_mut().zip(&weights) {
+                *out = *w as f32;
+            }
+        }
+        Self { kind, table }
+    }
+
+    /// The kernel this interpolator was built for.
+    pub fn kind(&self) -> Interpolation {
+        self.kind
+    }
+
+    /// Read `buffer` circularly at `index + frac`, with `frac` in 0..1.
+    pub fn read_at(&self, buffer: &[f32], index: usize, frac: f32) -> f32 {
+        let len = buffer.len();
+        if len == 0 {
+            return 0.0;
+        }
+        let taps = self.kind.taps();
+        let row = frac.clamp(0.0, 1.0) * INTERPOLATOR_PHASES as f32;
+        let r0 = (row as usize).min(INTERPOLATOR_PHASES - 1);
+        let mix = row - r0 as f32;
+        let (a, b) = (
+            &self.table[r0 * taps..(r0 + 1) * taps],
+            &self.table[(r0 + 1) * taps..(r0 + 2) * taps],
+        );
+        // First tap sits taps/2 - 1 samples before `index`
+        let mut pos = (index % len + len * taps - (taps / 2 - 1)) % len;
+        let mut out = 0.0;
+        for (wa, wb) in a.iter().zip(b) {
+            out += buffer[pos] * (wa + (wb - wa) * mix);
+            pos += 1;
+            if pos == len {
+                pos = 0;
+            }
+        }
+        out
+    }
+
+    /// Read `buffer` circularly at a fractional index, like
+    /// `linear_interpolate`.
+    pub fn read(&self, buffer: &[f32], read_pos: f32) -> f32 {
+        let base = read_pos.floor();
+        self.read_at(buffer, base.max(0.0) as usize, read_pos - base)
+    }
+
+    /// Read a circular delay line `delay` samples behind `write_index`.
+    ///
+    /// The kernel reaches `taps() / 2` samples toward `write_index`, so
+    /// delays shorter than that read audio that has not been written yet.
+    pub fn read_delay(&self, buffer: &[f32], write_index: usize, delay: f32) -> f32 {
+        let len = buffer.len();
+        if len == 0 {
+            return 0.0;
+        }
+        let whole = delay.max(0.0).ceil();
+        let back = whole as usize % len;
+        self.read_at(
+            buffer,
+            (write_index + len - back) % len,
+            whole - delay.max(0.0),
+        )
+    }
+}
@@ -3,13 +3,23 @@
 #![forbid(unsafe_code)]
 
 pub mod builders;
+pub mod describe;
+pub mod filter_design;
 pub mod helpers;
 pub mod nodes;
+pub mod oversampling;
+pub mod params;
+pub mod wav;
 pub mod wavetables;
 pub mod windows;
 
 pub use builders::*;
+pub use describe::*;
+pub use filter_design::*;
 pub use helpers::*;
 pub use nodes::*;
+pub use oversampling::*;
+pub use params::*;
+pub use wav::*;
 pub use wavetables::*;
 pub use windows::*;
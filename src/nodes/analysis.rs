@@ -0,0 +1,832 @@
+//! Analysis nodes: level meters, frequency estimators and onset detection.
+//!
+//! All nodes pass no audio; their outputs are control signals held between
+//! analysis frames. FFT plans and frame buffers are created in `init_state`.
+
+use std::sync::Arc;
+
+use crate::describe::{Describe, PortDesc, PortRole};
+use crate::helpers::compute_exponential_coefficient;
+use crate::params::{ParamDesc, ParamUnit, Parameterized};
+use crate::windows::hann_window;
+use auxide::graph::{Port, PortId, Rate};
+use auxide::node::NodeDef;
+use num_complex::Complex;
+use realfft::{ComplexToReal, RealFftPlanner, RealToComplex};
+
+/// Sliding analysis frame shared by the FFT-based analysers.
+///
+/// Keeps the last `size` input samples and produces a windowed, zero-padded
+/// spectrum every `hop` samples.
+#[derive(Clone)]
+pub struct AnalysisFrame {
+    pub size: usize,
+    pub hop: usize,
+    pub history: Vec<f32>,
+    pub write_pos: usize,
+    pub since_frame: usize,
+    pub window: Vec<f32>,
+    pub frame: Vec<f32>,
+    pub spectrum: Vec<Complex<f32>>,
+    pub scratch: Vec<Complex<f32>>,
+    pub fft: Arc<dyn RealToComplex<f32>>,
+}
+
+impl AnalysisFrame {
+    fn new(
+        planner: &mut RealFftPlanner<f32>,
+        size: usize,
+        fft_size: usize,
+        window: Vec<f32>,
+    ) -> Self {
+        let fft = planner.plan_fft_forward(fft_size);
+        Self {
+            size,
+            hop: (size / 2).max(1),
+            history: vec![0.0; size],
+            write_pos: 0,
+            since_frame: 0,
+            window,
+            frame: vec![0.0; fft_size],
+            spectrum: fft.make_output_vec(),
+            scratch: fft.make_scratch_vec(),
+            fft,
+        }
+    }
+
+    /// Push one sample; returns true when a new spectrum is ready.
+    fn push(&mut self, sample: f32) -> bool {
+        self.history[self.write_pos] = sample;
+        self.write_pos = (self.write_pos + 1) % self.size;
+        self.since_frame += 1;
+        if self.since_frame < self.hop {
+            return false;
+        }
+        self.since_frame = 0;
+
+        // Unroll the ring oldest-first; the tail of `frame` stays zero padding
+        for i in 0..self.size {
+            let idx = (self.write_pos + i) % self.size;
+            self.frame[i] = self.history[idx] * self.window[i];
+        }
+        for x in self.frame[self.size..].iter_mut() {
+            *x = 0.0;
+        }
+        self.fft
+            .process_with_scratch(&mut self.frame, &mut self.spectrum, &mut self.scratch)
+            .is_ok()
+    }
+}
+
+/// State of an Rms meter
+#[derive(Debug, Clone)]
+pub struct RmsState {
+    pub buffer: Vec<f32>,
+    pub write_pos: usize,
+    pub sum_squares: f64,
+}
+
+/// Sliding-window RMS level detector
+#[derive(Debug, Clone)]
+pub struct Rms {
+    pub window_size: usize,
+}
+
+impl NodeDef for Rms {
+    type State = RmsState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        RmsState {
+            buffer: vec![0.0; self.window_size.max(1)],
+            write_pos: 0,
+            sum_squares: 0.0,
+        }
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let output = &mut outputs[0];
+        let len = state.buffer.len();
+
+        for i in 0..input.len() {
+            let oldest = state.buffer[state.write_pos] as f64;
+            let x = input[i] as f64;
+            state.sum_squares = (state.sum_squares - oldest * oldest + x * x).max(0.0);
+            state.buffer[state.write_pos] = input[i];
+            state.write_pos = (state.write_pos + 1) % len;
+            output[i] = (state.sum_squares / len as f64).sqrt() as f32;
+        }
+    }
+}
+
+impl Parameterized for Rms {
+    fn params(&self) -> &'static [ParamDesc] {
+        &[]
+    }
+
+    fn get_param(&self, _id: usize) -> Option<f32> {
+        None
+    }
+
+    fn set_param(&mut self, _id: usize, _value: f32) {}
+}
+
+impl Describe for Rms {
+    fn name(&self) -> &'static str {
+        "Rms"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::control(0, "rms", ParamUnit::Gain)];
+        PORTS
+    }
+}
+
+/// State of a Peak meter
+#[derive(Debug, Clone)]
+pub struct PeakState {
+    pub peak: f32,
+    pub hold_counter: f32,
+}
+
+/// Peak level detector with hold and exponential release
+#[derive(Debug, Clone)]
+pub struct Peak {
+    pub hold_ms: f32,
+    pub release_ms: f32,
+}
+
+impl NodeDef for Peak {
+    type State = PeakState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        PeakState {
+            peak: 0.0,
+            hold_counter: 0.0,
+        }
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let output = &mut outputs[0];
+        let hold_samples = self.hold_ms * 0.001 * sample_rate;
+        let release_coeff = compute_exponential_coefficient(self.release_ms, sample_rate);
+
+        for i in 0..input.len() {
+            let level = input[i].abs();
+            if level > state.peak {
+                state.peak = level;
+                state.hold_counter = hold_samples;
+            } else if state.hold_counter > 0.0 {
+                state.hold_counter -= 1.0;
+            } else {
+                state.peak *= release_coeff;
+            }
+            output[i] = state.peak;
+        }
+    }
+}
+
+impl Parameterized for Peak {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "hold_ms", 0.0, 5000.0, 50.0, ParamUnit::Milliseconds),
+            ParamDesc::new(
+                1,
+                "release_ms",
+                1.0,
+                10000.0,
+                300.0,
+                ParamUnit::Milliseconds,
+            ),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.hold_ms),
+            1 => Some(self.release_ms),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.hold_ms = value,
+            1 => self.release_ms = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for Peak {
+    fn name(&self) -> &'static str {
+        "Peak"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::control(0, "peak", ParamUnit::Gain)];
+        PORTS
+    }
+}
+
+/// State of a FrequencyAnalyzer
+#[derive(Debug, Clone)]
+pub struct FrequencyAnalyzerState {
+    pub counter: usize,
+    pub last_sample: f32,
+    pub zero_crossings: usize,
+    pub estimate: f32,
+}
+
+/// Zero-crossing frequency estimator; updates once per window
+#[derive(Debug, Clone)]
+pub struct FrequencyAnalyzer {
+    pub window_size: usize,
+}
+
+impl NodeDef for FrequencyAnalyzer {
+    type State = FrequencyAnalyzerState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        FrequencyAnalyzerState {
+            counter: 0,
+            last_sample: 0.0,
+            zero_crossings: 0,
+            estimate: 0.0,
+        }
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let output = &mut outputs[0];
+        let window = self.window_size.max(1);
+
+        for i in 0..input.len() {
+            if (state.last_sample < 0.0) != (input[i] < 0.0) {
+                state.zero_crossings += 1;
+            }
+            state.last_sample = input[i];
+            state.counter += 1;
+
+            if state.counter >= window {
+                state.estimate = state.zero_crossings as f32 * sample_rate / (2.0 * window as f32);
+                state.counter = 0;
+                state.zero_crossings = 0;
+            }
+            output[i] = state.estimate;
+        }
+    }
+}
+
+impl Parameterized for FrequencyAnalyzer {
+    fn params(&self) -> &'static [ParamDesc] {
+        &[]
+    }
+
+    fn get_param(&self, _id: usize) -> Option<f32> {
+        None
+    }
+
+    fn set_param(&mut self, _id: usize, _value: f32) {}
+}
+
+impl Describe for FrequencyAnalyzer {
+    fn name(&self) -> &'static str {
+        "FrequencyAnalyzer"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::control(0, "frequency", ParamUnit::Hertz)];
+        PORTS
+    }
+}
+
+/// State of a SpectralCentroid
+#[derive(Clone)]
+pub struct SpectralCentroidState {
+    pub frame: AnalysisFrame,
+    pub centroid: f32,
+}
+
+/// Spectral centroid ("brightness") in Hz, computed on Hann-windowed frames
+/// with 50% overlap
+#[derive(Debug, Clone)]
+pub struct SpectralCentroid {
+    pub fft_size: usize,
+}
+
+impl NodeDef for SpectralCentroid {
+    type State = SpectralCentroidState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        let size = self.fft_size.max(4);
+        let mut planner = RealFftPlanner::<f32>::new();
+        SpectralCentroidState {
+            frame: AnalysisFrame::new(&mut planner, size, size, hann_window(size)),
+            centroid: 0.0,
+        }
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let output = &mut outputs[0];
+
+        for i in 0..input.len() {
+            if state.frame.push(input[i]) {
+                let bin_hz = sample_rate / state.frame.frame.len() as f32;
+                let mut weighted = 0.0;
+                let mut total = 0.0;
+                for (k, bin) in state.frame.spectrum.iter().enumerate() {
+                    let mag = bin.norm();
+                    weighted += k as f32 * bin_hz * mag;
+                    total += mag;
+                }
+                state.centroid = if total > 1e-9 { weighted / total } else { 0.0 };
+            }
+            output[i] = state.centroid;
+        }
+    }
+}
+
+impl Parameterized for SpectralCentroid {
+    fn params(&self) -> &'static [ParamDesc] {
+        &[]
+    }
+
+    fn get_param(&self, _id: usize) -> Option<f32> {
+        None
+    }
+
+    fn set_param(&mut self, _id: usize, _value: f32) {}
+}
+
+impl Describe for SpectralCentroid {
+    fn name(&self) -> &'static str {
+        "SpectralCentroid"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::control(0, "centroid", ParamUnit::Hertz)];
+        PORTS
+    }
+}
+
+/// State of an OnsetDetector
+#[derive(Clone)]
+pub struct OnsetDetectorState {
+    pub frame: AnalysisFrame,
+    pub prev_mags: Vec<f32>,
+    pub strength: f32,
+    pub mean: f32,
+    pub armed: bool,
+}
+
+/// Spectral-flux onset detector
+///
+/// Output 0 is a one-sample trigger at each detected onset, output 1 the
+/// onset strength (positive spectral flux times `sensitivity`). An onset
+/// fires when the strength exceeds its running mean by `threshold`.
+#[derive(Debug, Clone)]
+pub struct OnsetDetector {
+    pub threshold: f32,
+    pub sensitivity: f32,
+    pub fft_size: usize,
+}
+
+impl NodeDef for OnsetDetector {
+    type State = OnsetDetectorState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            }, // onset trigger
+            Port {
+                id: PortId(1),
+                rate: Rate::Audio,
+            }, // strength
+        ];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        let size = self.fft_size.max(4);
+        let mut planner = RealFftPlanner::<f32>::new();
+        let frame = AnalysisFrame::new(&mut planner, size, size, hann_window(size));
+        OnsetDetectorState {
+            prev_mags: vec![0.0; frame.spectrum.len()],
+            frame,
+            strength: 0.0,
+            mean: 0.0,
+            armed: true,
+        }
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let norm = 2.0 / state.frame.frame.len() as f32;
+
+        for i in 0..input.len() {
+            let mut trigger = 0.0;
+            if state.frame.push(input[i]) {
+                let mut flux = 0.0;
+                for (bin, prev) in state.frame.spectrum.iter().zip(state.prev_mags.iter_mut()) {
+                    let mag = bin.norm() * norm;
+                    flux += (mag - *prev).max(0.0);
+                    *prev = mag;
+                }
+                state.strength = flux * self.sensitivity;
+
+                let novelty = state.strength - state.mean;
+                if novelty > self.threshold {
+                    if state.armed {
+                        trigger = 1.0;
+                        state.armed = false;
+                    }
+                } else {
+                    state.armed = true;
+                }
+                state.mean = 0.9 * state.mean + 0.1 * state.strength;
+            }
+            outputs[0][i] = trigger;
+            if outputs.len() > 1 {
+                outputs[1][i] = state.strength;
+            }
+        }
+    }
+}
+
+impl Parameterized for OnsetDetector {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "threshold", 0.0, 10.0, 0.1, ParamUnit::None),
+            ParamDesc::new(1, "sensitivity", 0.0, 100.0, 1.0, ParamUnit::None),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.threshold),
+            1 => Some(self.sensitivity),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.threshold = value,
+            1 => self.sensitivity = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for OnsetDetector {
+    fn name(&self) -> &'static str {
+        "OnsetDetector"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::new(0, "onset", PortRole::Trigger, ParamUnit::None),
+            PortDesc::control(1, "strength", ParamUnit::None),
+        ];
+        PORTS
+    }
+}
+
+/// State of an AutocorrPitchDetector
+#[derive(Clone)]
+pub struct AutocorrPitchDetectorState {
+    pub frame: AnalysisFrame,
+    pub inverse_fft: Arc<dyn ComplexToReal<f32>>,
+    pub inverse_scratch: Vec<Complex<f32>>,
+    pub correlation: Vec<f32>,
+    pub pitch: f32,
+}
+
+/// Autocorrelation pitch detector
+///
+/// The autocorrelation of each frame is computed through the power spectrum,
+/// so the cost per frame is O(n log n). Outputs 0 Hz for unvoiced frames.
+/// `window_size` should cover at least two periods of `min_freq`.
+#[derive(Debug, Clone)]
+pub struct AutocorrPitchDetector {
+    pub min_freq: f32,
+    pub max_freq: f32,
+    pub window_size: usize,
+}
+
+/// Normalized correlation below which a frame is treated as unvoiced.
+const VOICED_THRESHOLD: f32 = 0.3;
+
+impl NodeDef for AutocorrPitchDetector {
+    type State = AutocorrPitchDetectorState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        let size = self.window_size.max(4);
+        // Zero-pad to twice the window so the correlation is linear, not circular
+        let fft_size = 2 * size;
+        let mut planner = RealFftPlanner::<f32>::new();
+        let frame = AnalysisFrame::new(&mut planner, size, fft_size, vec![1.0; size]);
+        let inverse_fft = planner.plan_fft_inverse(fft_size);
+        AutocorrPitchDetectorState {
+            frame,
+            inverse_scratch: inverse_fft.make_scratch_vec(),
+            inverse_fft,
+            correlation: vec![0.0; fft_size],
+            pitch: 0.0,
+        }
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let output = &mut outputs[0];
+
+        for i in 0..input.len() {
+            if state.frame.push(input[i]) {
+                state.pitch = self.estimate(state, sample_rate);
+            }
+            output[i] = state.pitch;
+        }
+    }
+}
+
+impl AutocorrPitchDetector {
+    fn estimate(&self, state: &mut AutocorrPitchDetectorState, sample_rate: f32) -> f32 {
+        for bin in state.frame.spectrum.iter_mut() {
+            *bin = Complex::new(bin.norm_sqr(), 0.0);
+        }
+        if state
+            .inverse_fft
+            .process_with_scratch(
+                &mut state.frame.spectrum,
+                &mut state.correlation,
+                &mut state.inverse_scratch,
+            )
+            .is_err()
+        {
+            return 0.0;
+        }
+
+        let corr = &state.correlation;
+        let r0 = corr[0];
+        if r0 <= 1e-9 {
+            return 0.0;
+        }
+        let min_lag = (sample_rate / self.max_freq.max(1.0)).floor().max(2.0) as usize;
+        let max_lag =
+            ((sample_rate / self.min_freq.max(1.0)).ceil() as usize).min(state.frame.size - 2);
+        if min_lag >= max_lag {
+            return 0.0;
+        }
+
+        // Skip the main lobe around lag 0 before searching for the period peak
+        let mut start = 1;
+        while start < max_lag && corr[start] > 0.0 {
+            start += 1;
+        }
+        let start = start.max(min_lag);
+
+        let mut best_lag = 0;
+        let mut best = 0.0;
+        for (lag, &value) in corr.iter().enumerate().take(max_lag + 1).skip(start) {
+            if value > best {
+                best = value;
+                best_lag = lag;
+            }
+        }
+        if best_lag == 0 || best / r0 < VOICED_THRESHOLD {
+            return 0.0;
+        }
+
+        // Parabolic interpolation around the peak
+        let (a, b, c) = (corr[best_lag - 1], corr[best_lag], corr[best_lag + 1]);
+        let denom = a - 2.0 * b + c;
+        let shift = if denom.abs() > 1e-12 {
+            0.5 * (a - c) / denom
+        } else {
+            0.0
+        };
+        sample_rate / (best_lag as f32 + shift)
+    }
+}
+
+impl Parameterized for AutocorrPitchDetector {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "min_freq", 20.0, 2000.0, 50.0, ParamUnit::Hertz),
+            ParamDesc::new(1, "max_freq", 50.0, 5000.0, 1000.0, ParamUnit::Hertz),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.min_freq),
+            1 => Some(self.max_freq),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.min_freq = value,
+            1 => self.max_freq = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for AutocorrPitchDetector {
+    fn name(&self) -> &'static str {
+        "AutocorrPitchDetector"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::control(0, "pitch", ParamUnit::Hertz)];
+        PORTS
+    }
+}
@@ -0,0 +1,235 @@
+//! Word-length reduction for rendering to fixed-point output.
+//!
+//! The input is quantized to the target bit depth after adding dither, and
+//! the total error (quantization plus dither) is fed back through a
+//! noise-shaping filter so the noise transfer function is `1 - H(z)`.
+//! Random values come from `helpers::xorshift`, which the physical and
+//! granular voices also use (the noise oscillators keep their own LCG),
+//! seeded from the node so renders are repeatable.
+
+use crate::describe::{Describe, PortDesc};
+use crate::helpers::xorshift;
+use crate::params::{ParamDesc, ParamUnit, Parameterized};
+use auxide::graph::{Port, PortId, Rate};
+use auxide::node::NodeDef;
+
+/// Longest error-feedback filter.
+const MAX_ORDER: usize = 9;
+
+/// Seed used in place of zero, which would stall the generator.
+const DEFAULT_SEED: u64 = 0x5DEE_CE66_D1CE_4E5B;
+
+/// Probability density of the dither added before quantizing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DitherType {
+    /// Plain truncation to the nearest step; the error follows the signal.
+    None,
+    /// Rectangular, one step wide. Removes distortion but not noise
+    /// modulation.
+    Rpdf,
+    /// Triangular, two steps wide: the error's mean and variance are
+    /// independent of the signal.
+    #[default]
+    Tpdf,
+    /// Triangular from the difference of successive rectangular values, which
+    /// tilts the dither spectrum towards high frequencies.
+    HighPassTpdf,
+}
+
+/// Error-feedback filter applied to the quantization error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NoiseShaping {
+    /// White error.
+    #[default]
+    None,
+    /// `(1 - z^-1)`: 6 dB/octave rise towards Nyquist.
+    FirstOrder,
+    /// `(1 - z^-1)^2`: 12 dB/octave rise towards Nyquist.
+    SecondOrder,
+    /// Wannamaker's 9-tap F-weighted filter, following the ear's threshold
+    /// curve with its deepest cut around 3-4 kHz. Designed for 44.1 kHz.
+    FWeighted,
+    /// Lipshitz's 9-tap modified E-weighted filter; a gentler curve than
+    /// `FWeighted`. Designed for 44.1 kHz.
+    ModifiedEWeighted,
+    /// Lipshitz's 9-tap improved E-weighted filter, the most aggressive
+    /// curve. Designed for 44.1 kHz.
+    ImprovedEWeighted,
+}
+
+impl NoiseShaping {
+    /// Feedback taps `h1..hN` of `H(z)`; the noise transfer function is
+    /// `1 - sum(h_k z^-k)`.
+    pub fn coefficients(&self) -> &'static [f32] {
+        match self {
+            NoiseShaping::None => &[],
+            NoiseShaping::FirstOrder => &[1.0],
+            NoiseShaping::SecondOrder => &[2.0, -1.0],
+            NoiseShaping::FWeighted => &[
+                2.412, -3.370, 3.937, -4.174, 3.353, -2.205, 1.281, -0.569, 0.0847,
+            ],
+            NoiseShaping::ModifiedEWeighted => &[
+                1.662, -1.263, 0.4827, -0.2913, 0.1268, -0.1124, 0.03252, -0.01265, -0.03524,
+            ],
+            NoiseShaping::ImprovedEWeighted => &[
+                2.847, -4.685, 6.214, -7.184, 6.639, -5.032, 3.263, -1.632, 0.4191,
+            ],
+        }
+    }
+}
+
+/// State of a Dither
+#[derive(Debug, Clone)]
+pub struct DitherState {
+    pub rng: u64,
+    /// Previous uniform value, for `DitherType::HighPassTpdf`.
+    pub previous: f32,
+    /// Past total errors, most recent first.
+    pub error: [f32; MAX_ORDER],
+}
+
+/// Dither and noise-shaping output stage.
+///
+/// Quantizes to `bits` of signed fixed point, with full scale at ±1 and the
+/// output clamped to the representable range `-1..1 - 2^(1 - bits)`. Use
+/// one node per channel with different seeds so the channels' dither is
+/// uncorrelated.
+#[derive(Debug, Clone)]
+pub struct Dither {
+    /// Target word length, rounded to a whole number of bits.
+    pub bits: f32,
+    pub dither: DitherType,
+    pub shaping: NoiseShaping,
+    pub seed: u64,
+}
+
+impl Dither {
+    /// TPDF dither to `bits` without noise shaping.
+    pub fn new(bits: f32) -> Self {
+        Self {
+            bits,
+            dither: DitherType::Tpdf,
+            shaping: NoiseShaping::None,
+            seed: DEFAULT_SEED,
+        }
+    }
+}
+
+impl Default for Dither {
+    fn default() -> Self {
+        Self::new(16.0)
+    }
+}
+
+impl NodeDef for Dither {
+    type State = DitherState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }]; // input
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        let mut rng = if self.seed == 0 {
+            DEFAULT_SEED
+        } else {
+            self.seed
+        };
+        DitherState {
+            previous: xorshift(&mut rng),
+            rng,
+            error: [0.0; MAX_ORDER],
+        }
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let output = &mut outputs[0];
+        let bits = self.bits.round().clamp(1.0, 24.0) as i32;
+        let step = 2.0f32.powi(1 - bits);
+        let coefficients = self.shaping.coefficients();
+
+        for i in 0..input.len() {
+            let feedback: f32 = coefficients
+                .iter()
+                .zip(&state.error)
+                .map(|(h, e)| h * e)
+                .sum();
+            let target = input[i] - feedback;
+            let dither = match self.dither {
+                DitherType::None => 0.0,
+                DitherType::Rpdf => xorshift(&mut state.rng) - 0.5,
+                DitherType::Tpdf => xorshift(&mut state.rng) + xorshift(&mut state.rng) - 1.0,
+                DitherType::HighPassTpdf => {
+                    let current = xorshift(&mut state.rng);
+                    let d = current - state.previous;
+                    state.previous = current;
+                    d
+                }
+            };
+            let quantized = ((target / step + dither).round()) * step;
+            // The error is taken before clamping so a clipped sample cannot
+            // wind up the feedback filter
+            state.error.copy_within(0..MAX_ORDER - 1, 1);
+            state.error[0] = quantized - target;
+            output[i] = quantized.clamp(-1.0, 1.0 - step);
+        }
+    }
+}
+
+impl Parameterized for Dither {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[ParamDesc::new(0, "bits", 1.0, 24.0, 16.0, ParamUnit::Bits)];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.bits),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        if id == 0 {
+            self.bits = value;
+        }
+    }
+}
+
+impl Describe for Dither {
+    fn name(&self) -> &'static str {
+        "Dither"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
@@ -1,3 +1,5 @@
+use crate::describe::{Describe, PortDesc, PortRole};
+use crate::params::{ParamDesc, ParamUnit, Parameterized};
 use auxide::graph::{Port, PortId, Rate};
 use auxide::node::NodeDef;
 
@@ -85,6 +87,67 @@ impl NodeDef for Compressor {
     }
 }
 
+impl Parameterized for Compressor {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "threshold", 0.0, 1.0, 0.5, ParamUnit::Gain),
+            ParamDesc::new(1, "ratio", 1.0, 100.0, 4.0, ParamUnit::Ratio),
+            ParamDesc::new(2, "attack_ms", 0.01, 1000.0, 10.0, ParamUnit::Milliseconds),
+            ParamDesc::new(
+                3,
+                "release_ms",
+                0.01,
+                5000.0,
+                100.0,
+                ParamUnit::Milliseconds,
+            ),
+            ParamDesc::new(4, "makeup_gain", 0.0, 16.0, 1.0, ParamUnit::Gain),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.threshold),
+            1 => Some(self.ratio),
+            2 => Some(self.attack_ms),
+            3 => Some(self.release_ms),
+            4 => Some(self.makeup_gain),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.threshold = value,
+            1 => self.ratio = value,
+            2 => self.attack_ms = value,
+            3 => self.release_ms = value,
+            4 => self.makeup_gain = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for Compressor {
+    fn name(&self) -> &'static str {
+        "Compressor"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::new(1, "sidechain", PortRole::Sidechain, ParamUnit::None),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 /// State of a Limiter
 #[derive(Debug, Clone)]
 pub struct LimiterState {
@@ -163,6 +226,51 @@ impl NodeDef for Limiter {
     }
 }
 
+impl Parameterized for Limiter {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "threshold", 0.0, 1.0, 0.9, ParamUnit::Gain),
+            ParamDesc::new(1, "attack_ms", 0.01, 1000.0, 1.0, ParamUnit::Milliseconds),
+            ParamDesc::new(2, "release_ms", 0.01, 5000.0, 50.0, ParamUnit::Milliseconds),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.threshold),
+            1 => Some(self.attack_ms),
+            2 => Some(self.release_ms),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.threshold = value,
+            1 => self.attack_ms = value,
+            2 => self.release_ms = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for Limiter {
+    fn name(&self) -> &'static str {
+        "Limiter"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 /// State of a Noise Gate
 #[derive(Debug, Clone)]
 pub struct GateState {
@@ -242,6 +350,61 @@ impl NodeDef for NoiseGate {
     }
 }
 
+impl Parameterized for NoiseGate {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "threshold", 0.0, 1.0, 0.05, ParamUnit::Gain),
+            ParamDesc::new(1, "ratio", 1.0, 100.0, 10.0, ParamUnit::Ratio),
+            ParamDesc::new(2, "attack_ms", 0.01, 1000.0, 1.0, ParamUnit::Milliseconds),
+            ParamDesc::new(
+                3,
+                "release_ms",
+                0.01,
+                5000.0,
+                100.0,
+                ParamUnit::Milliseconds,
+            ),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.threshold),
+            1 => Some(self.ratio),
+            2 => Some(self.attack_ms),
+            3 => Some(self.release_ms),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.threshold = value,
+            1 => self.ratio = value,
+            2 => self.attack_ms = value,
+            3 => self.release_ms = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for NoiseGate {
+    fn name(&self) -> &'static str {
+        "NoiseGate"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 /// State of an Expander
 #[derive(Debug, Clone)]
 pub struct ExpanderState {
@@ -320,3 +483,58 @@ impl NodeDef for Expander {
         }
     }
 }
+
+impl Parameterized for Expander {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "threshold", 0.0, 1.0, 0.1, ParamUnit::Gain),
+            ParamDesc::new(1, "ratio", 1.0, 100.0, 2.0, ParamUnit::Ratio),
+            ParamDesc::new(2, "attack_ms", 0.01, 1000.0, 5.0, ParamUnit::Milliseconds),
+            ParamDesc::new(
+                3,
+                "release_ms",
+                0.01,
+                5000.0,
+                100.0,
+                ParamUnit::Milliseconds,
+            ),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.threshold),
+            1 => Some(self.ratio),
+            2 => Some(self.attack_ms),
+            3 => Some(self.release_ms),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.threshold = value,
+            1 => self.ratio = value,
+            2 => self.attack_ms = value,
+            3 => self.release_ms = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for Expander {
+    fn name(&self) -> &'static str {
+        "Expander"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
@@ -1,3 +1,5 @@
+use crate::describe::{Describe, PortDesc, PortRole};
+use crate::params::{ParamDesc, ParamUnit, Parameterized};
 use auxide::graph::{Port, PortId, Rate};
 use auxide::node::NodeDef;
 
@@ -75,78 +77,140 @@ impl NodeDef for AdsrEnvelope {
         let dt = 1.0 / sample_rate;
 
         for i in 0..gate.len() {
-            let gate_on = gate[i] > 0.5;
+            output[i] = self.tick(state, gate[i] > 0.5, dt);
+        }
+    }
+}
 
-            match state.phase {
-                AdsrPhase::Idle => {
-                    if gate_on {
-                        state.phase = AdsrPhase::Attack;
-                        state.time_accum = 0.0;
-                    }
-                    state.level = 0.0;
+impl AdsrEnvelope {
+    /// Advance the envelope by `dt` seconds and return its level.
+    pub fn tick(&self, state: &mut AdsrState, gate_on: bool, dt: f32) -> f32 {
+        match state.phase {
+            AdsrPhase::Idle => {
+                if gate_on {
+                    state.phase = AdsrPhase::Attack;
+                    state.time_accum = 0.0;
                 }
-                AdsrPhase::Attack => {
-                    state.time_accum += dt;
-                    let t = (state.time_accum / (self.attack_ms / 1000.0)).min(1.0);
-                    state.level = if self.curve > 0.0 {
-                        1.0 - (-t * self.curve).exp()
-                    } else {
-                        t
-                    };
-                    if state.time_accum >= self.attack_ms / 1000.0 {
-                        state.phase = AdsrPhase::Decay;
-                        state.time_accum = 0.0;
-                    }
-                    if !gate_on {
-                        state.phase = AdsrPhase::Release;
-                        state.time_accum = 0.0;
-                    }
+                state.level = 0.0;
+            }
+            AdsrPhase::Attack => {
+                state.time_accum += dt;
+                let t = (state.time_accum / (self.attack_ms / 1000.0)).min(1.0);
+                state.level = if self.curve > 0.0 {
+                    1.0 - (-t * self.curve).exp()
+                } else {
+                    t
+                };
+                if state.time_accum >= self.attack_ms / 1000.0 {
+                    state.phase = AdsrPhase::Decay;
+                    state.time_accum = 0.0;
                 }
-                AdsrPhase::Decay => {
-                    state.time_accum += dt;
-                    let t = (state.time_accum / (self.decay_ms / 1000.0)).min(1.0);
-                    let decay_factor = if self.curve > 0.0 {
-                        (-t * self.curve).exp()
-                    } else {
-                        1.0 - t
-                    };
-                    state.level = 1.0 + (self.sustain_level - 1.0) * decay_factor;
-                    if state.time_accum >= self.decay_ms / 1000.0 {
-                        state.phase = AdsrPhase::Sustain;
-                    }
-                    if !gate_on {
-                        state.phase = AdsrPhase::Release;
-                        state.time_accum = 0.0;
-                    }
+                if !gate_on {
+                    state.phase = AdsrPhase::Release;
+                    state.time_accum = 0.0;
                 }
-                AdsrPhase::Sustain => {
-                    state.level = self.sustain_level;
-                    if !gate_on {
-                        state.phase = AdsrPhase::Release;
-                        state.time_accum = 0.0;
-                    }
+            }
+            AdsrPhase::Decay => {
+                state.time_accum += dt;
+                let t = (state.time_accum / (self.decay_ms / 1000.0)).min(1.0);
+                let decay_factor = if self.curve > 0.0 {
+                    (-t * self.curve).exp()
+                } else {
+                    1.0 - t
+                };
+                state.level = 1.0 + (self.sustain_level - 1.0) * decay_factor;
+                if state.time_accum >= self.decay_ms / 1000.0 {
+                    state.phase = AdsrPhase::Sustain;
                 }
-                AdsrPhase::Release => {
-                    state.time_accum += dt;
-                    let t = (state.time_accum / (self.release_ms / 1000.0)).min(1.0);
-                    let release_factor = if self.curve > 0.0 {
-                        (-t * self.curve).exp()
-                    } else {
-                        1.0 - t
-                    };
-                    state.level = self.sustain_level * release_factor;
-                    if state.time_accum >= self.release_ms / 1000.0 {
-                        state.phase = AdsrPhase::Idle;
-                        state.level = 0.0;
-                    }
+                if !gate_on {
+                    state.phase = AdsrPhase::Release;
+                    state.time_accum = 0.0;
                 }
             }
+            AdsrPhase::Sustain => {
+                state.level = self.sustain_level;
+                if !gate_on {
+                    state.phase = AdsrPhase::Release;
+                    state.time_accum = 0.0;
+                }
+            }
+            AdsrPhase::Release => {
+                state.time_accum += dt;
+                let t = (state.time_accum / (self.release_ms / 1000.0)).min(1.0);
+                let release_factor = if self.curve > 0.0 {
+                    (-t * self.curve).exp()
+                } else {
+                    1.0 - t
+                };
+                state.level = self.sustain_level * release_factor;
+                if state.time_accum >= self.release_ms / 1000.0 {
+                    state.phase = AdsrPhase::Idle;
+                    state.level = 0.0;
+                }
+            }
+        }
+        state.level
+    }
+}
 
-            output[i] = state.level;
+impl Parameterized for AdsrEnvelope {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "attack_ms", 0.0, 10000.0, 10.0, ParamUnit::Milliseconds),
+            ParamDesc::new(1, "decay_ms", 0.0, 10000.0, 100.0, ParamUnit::Milliseconds),
+            ParamDesc::new(2, "sustain_level", 0.0, 1.0, 0.7, ParamUnit::Normalized),
+            ParamDesc::new(
+                3,
+                "release_ms",
+                0.0,
+                10000.0,
+                200.0,
+                ParamUnit::Milliseconds,
+            ),
+            ParamDesc::new(4, "curve", 0.0, 10.0, 0.0, ParamUnit::None),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.attack_ms),
+            1 => Some(self.decay_ms),
+            2 => Some(self.sustain_level),
+            3 => Some(self.release_ms),
+            4 => Some(self.curve),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.attack_ms = value,
+            1 => self.decay_ms = value,
+            2 => self.sustain_level = value,
+            3 => self.release_ms = value,
+            4 => self.curve = value,
+            _ => {}
         }
     }
 }
 
+impl Describe for AdsrEnvelope {
+    fn name(&self) -> &'static str {
+        "AdsrEnvelope"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::new(0, "gate", PortRole::Gate, ParamUnit::None)];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::control(0, "envelope", ParamUnit::Normalized)];
+        PORTS
+    }
+}
+
 /// State of an AR Envelope
 #[derive(Debug, Clone)]
 pub struct ArState {
@@ -264,6 +328,58 @@ impl NodeDef for ArEnvelope {
     }
 }
 
+impl Parameterized for ArEnvelope {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "attack_ms", 0.0, 10000.0, 10.0, ParamUnit::Milliseconds),
+            ParamDesc::new(
+                1,
+                "release_ms",
+                0.0,
+                10000.0,
+                200.0,
+                ParamUnit::Milliseconds,
+            ),
+            ParamDesc::new(2, "curve", 0.0, 10.0, 0.0, ParamUnit::None),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.attack_ms),
+            1 => Some(self.release_ms),
+            2 => Some(self.curve),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.attack_ms = value,
+            1 => self.release_ms = value,
+            2 => self.curve = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for ArEnvelope {
+    fn name(&self) -> &'static str {
+        "ArEnvelope"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::new(0, "gate", PortRole::Gate, ParamUnit::None)];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::control(0, "envelope", ParamUnit::Normalized)];
+        PORTS
+    }
+}
+
 /// State of an AD Envelope
 #[derive(Debug, Clone)]
 pub struct AdState {
@@ -381,3 +497,48 @@ impl NodeDef for AdEnvelope {
         }
     }
 }
+
+impl Parameterized for AdEnvelope {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "attack_ms", 0.0, 10000.0, 10.0, ParamUnit::Milliseconds),
+            ParamDesc::new(1, "decay_ms", 0.0, 10000.0, 200.0, ParamUnit::Milliseconds),
+            ParamDesc::new(2, "curve", 0.0, 10.0, 0.0, ParamUnit::None),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.attack_ms),
+            1 => Some(self.decay_ms),
+            2 => Some(self.curve),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.attack_ms = value,
+            1 => self.decay_ms = value,
+            2 => self.curve = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for AdEnvelope {
+    fn name(&self) -> &'static str {
+        "AdEnvelope"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::new(0, "gate", PortRole::Gate, ParamUnit::None)];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::control(0, "envelope", ParamUnit::Normalized)];
+        PORTS
+    }
+}
@@ -1,4 +1,9 @@
+use crate::describe::{Describe, PortDesc};
+use crate::filter_design::{
+    Bandwidth, BiquadCoefficients, BiquadResponse, CrossoverOrder, FilterDesign, LinkwitzRiley,
+};
 use crate::helpers::{compute_exponential_coefficient, freq_to_phase_increment};
+use crate::params::{ParamDesc, ParamUnit, Parameterized};
 use auxide::graph::{Port, PortId, Rate};
 use auxide::node::NodeDef;
 
@@ -129,6 +134,305 @@ impl NodeDef for SvfFilter {
     }
 }
 
+impl Parameterized for SvfFilter {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "cutoff", 20.0, 20000.0, 1000.0, ParamUnit::Hertz),
+            ParamDesc::new(1, "resonance", 0.0, 1.0, 0.5, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.cutoff),
+            1 => Some(self.resonance),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.cutoff = value,
+            1 => self.resonance = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for SvfFilter {
+    fn name(&self) -> &'static str {
+        "SvfFilter"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "cutoff_mod", 0, ParamUnit::Hertz),
+            PortDesc::modulation(2, "resonance_mod", 1, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// Smallest damping a `ZdfSvfFilter` runs at, a Q of 200.
+const MIN_SVF_DAMPING: f32 = 0.005;
+
+/// State of a ZdfSvfFilter: the two trapezoidal integrators.
+#[derive(Debug, Clone, Default)]
+pub struct ZdfSvfState {
+    pub ic1eq: f32,
+    pub ic2eq: f32,
+}
+
+/// Coefficients of the TPT core for one cutoff, resonance and gain.
+#[derive(Debug, Clone, Copy)]
+struct ZdfSvfCoefficients {
+    k: f32,
+    a1: f32,
+    a2: f32,
+    a3: f32,
+    /// Square root of the linear shelf and bell gain.
+    a: f32,
+}
+
+impl ZdfSvfCoefficients {
+    fn new(cutoff: f32, resonance: f32, gain: f32, sample_rate: f32) -> Self {
+        let fraction = (cutoff / sample_rate).clamp(1e-6, 0.49);
+        let g = (std::f32::consts::PI * fraction).tan();
+        let k = (2.0 - 2.0 * resonance.clamp(0.0, 1.0)).max(MIN_SVF_DAMPING);
+        let a1 = 1.0 / (1.0 + g * (g + k));
+        let a2 = g * a1;
+        Self {
+            k,
+            a1,
+            a2,
+            a3: g * a2,
+            a: 10.0f32.powf(gain.clamp(-48.0, 48.0) / 40.0),
+        }
+    }
+}
+
+/// Zero-delay-feedback state variable filter (Zavalishin/Simper TPT).
+///
+/// The integrators are trapezoidal and the feedback loop is solved per
+/// sample, so the response is the bilinear transform of the analog SVF
+/// prewarped at the cutoff. It stays stable with cutoff and resonance
+/// modulated at audio rate. All responses come out at once:
+/// lowpass, highpass, bandpass (0 dB peak), notch, peak, allpass, low
+/// shelf, high shelf and bell. The shelves and bell share the core's poles,
+/// with `gain` setting their boost or cut.
+#[derive(Debug, Clone)]
+pub struct ZdfSvfFilter {
+    pub cutoff: f32,
+    /// 0 is a Q of 0.5, 1 is nearly undamped.
+    pub resonance: f32,
+    /// Shelf and bell gain in dB.
+    pub gain: f32,
+}
+
+impl Default for ZdfSvfFilter {
+    fn default() -> Self {
+        Self {
+            cutoff: 1000.0,
+            resonance: 0.5,
+            gain: 0.0,
+        }
+    }
+}
+
+impl NodeDef for ZdfSvfFilter {
+    type State = ZdfSvfState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            }, // input
+            Port {
+                id: PortId(1),
+                rate: Rate::Audio,
+            }, // cutoff_mod
+            Port {
+                id: PortId(2),
+                rate: Rate::Audio,
+            }, // resonance_mod
+            Port {
+                id: PortId(3),
+                rate: Rate::Audio,
+            }, // gain_mod
+        ];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            }, // lowpass
+            Port {
+                id: PortId(1),
+                rate: Rate::Audio,
+            }, // highpass
+            Port {
+                id: PortId(2),
+                rate: Rate::Audio,
+            }, // bandpass
+            Port {
+                id: PortId(3),
+                rate: Rate::Audio,
+            }, // notch
+            Port {
+                id: PortId(4),
+                rate: Rate::Audio,
+            }, // peak
+            Port {
+                id: PortId(5),
+                rate: Rate::Audio,
+            }, // allpass
+            Port {
+                id: PortId(6),
+                rate: Rate::Audio,
+            }, // low_shelf
+            Port {
+                id: PortId(7),
+                rate: Rate::Audio,
+            }, // high_shelf
+            Port {
+                id: PortId(8),
+                rate: Rate::Audio,
+            }, // bell
+        ];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        ZdfSvfState::default()
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let cutoff_mod = if inputs.len() > 1 { inputs[1] } else { &[] };
+        let resonance_mod = if inputs.len() > 2 { inputs[2] } else { &[] };
+        let gain_mod = if inputs.len() > 3 { inputs[3] } else { &[] };
+        let modulation = |buf: &[f32], i: usize| if buf.is_empty() { 0.0 } else { buf[i] };
+        let coefficients_at = |i: usize| {
+            ZdfSvfCoefficients::new(
+                self.cutoff + modulation(cutoff_mod, i),
+                self.resonance + modulation(resonance_mod, i),
+                self.gain + modulation(gain_mod, i),
+                sample_rate,
+            )
+        };
+
+        let modulated = !(cutoff_mod.is_empty() && resonance_mod.is_empty() && gain_mod.is_empty());
+        let mut c = coefficients_at(0);
+
+        for i in 0..input.len() {
+            if modulated && i > 0 {
+                c = coefficients_at(i);
+            }
+            let v0 = input[i];
+            let v3 = v0 - state.ic2eq;
+            let v1 = c.a1 * state.ic1eq + c.a2 * v3;
+            let v2 = state.ic2eq + c.a2 * state.ic1eq + c.a3 * v3;
+            state.ic1eq = 2.0 * v1 - state.ic1eq;
+            state.ic2eq = 2.0 * v2 - state.ic2eq;
+
+            let low = v2;
+            let band = c.k * v1;
+            let high = v0 - band - low;
+            let gain = c.a * c.a;
+            outputs[0][i] = low;
+            outputs[1][i] = high;
+            outputs[2][i] = band;
+            outputs[3][i] = low + high;
+            outputs[4][i] = low - high;
+            outputs[5][i] = v0 - 2.0 * band;
+            outputs[6][i] = high + c.a * band + gain * low;
+            outputs[7][i] = gain * high + c.a * band + low;
+            outputs[8][i] = v0 + (gain - 1.0) * band;
+        }
+    }
+}
+
+impl Parameterized for ZdfSvfFilter {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "cutoff", 20.0, 20000.0, 1000.0, ParamUnit::Hertz),
+            ParamDesc::new(1, "resonance", 0.0, 1.0, 0.5, ParamUnit::Normalized),
+            ParamDesc::new(2, "gain", -24.0, 24.0, 0.0, ParamUnit::Decibels),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.cutoff),
+            1 => Some(self.resonance),
+            2 => Some(self.gain),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.cutoff = value,
+            1 => self.resonance = value,
+            2 => self.gain = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for ZdfSvfFilter {
+    fn name(&self) -> &'static str {
+        "ZdfSvfFilter"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "cutoff_mod", 0, ParamUnit::Hertz),
+            PortDesc::modulation(2, "resonance_mod", 1, ParamUnit::Normalized),
+            PortDesc::modulation(3, "gain_mod", 2, ParamUnit::Decibels),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "lowpass"),
+            PortDesc::audio(1, "highpass"),
+            PortDesc::audio(2, "bandpass"),
+            PortDesc::audio(3, "notch"),
+            PortDesc::audio(4, "peak"),
+            PortDesc::audio(5, "allpass"),
+            PortDesc::audio(6, "low_shelf"),
+            PortDesc::audio(7, "high_shelf"),
+            PortDesc::audio(8, "bell"),
+        ];
+        PORTS
+    }
+}
+
 /// State of a Ladder Filter
 #[derive(Debug, Clone)]
 pub struct LadderState {
@@ -220,56 +524,983 @@ impl NodeDef for LadderFilter {
             let t = (1.0 - p) * 1.386249;
             let _t2 = 12.0 + t * t;
 
-            let x = input[i] * self.drive;
+            let x = input[i] * self.drive;
+
+            let y4 = x - k * (state.z4 + state.z3 + state.z2 + state.z1);
+            let y3 = y4 * t + state.z4;
+            let y2 = y3 * t + state.z3;
+            let y1 = y2 * t + state.z2;
+            let y0 = y1 * t + state.z1;
+
+            state.z1 += y0 * t;
+            state.z2 += y1 * t;
+            state.z3 += y2 * t;
+            state.z4 += y3 * t;
+
+            output[i] = y4;
+        }
+    }
+}
+
+impl Parameterized for LadderFilter {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "cutoff", 20.0, 20000.0, 1000.0, ParamUnit::Hertz),
+            ParamDesc::new(1, "resonance", 0.0, 1.0, 0.5, ParamUnit::Normalized),
+            ParamDesc::new(2, "drive", 0.0, 10.0, 1.0, ParamUnit::Gain),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.cutoff),
+            1 => Some(self.resonance),
+            2 => Some(self.drive),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.cutoff = value,
+            1 => self.resonance = value,
+            2 => self.drive = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for LadderFilter {
+    fn name(&self) -> &'static str {
+        "LadderFilter"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "cutoff_mod", 0, ParamUnit::Hertz),
+            PortDesc::modulation(2, "resonance_mod", 1, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// Linearize-and-solve passes per sample in the zero-delay-feedback ladders.
+const LADDER_SOLVER_PASSES: usize = 2;
+
+/// Feedback at which the diode ladder starts to self-oscillate.
+const DIODE_LADDER_THRESHOLD: f32 = 17.0;
+
+/// Resonant frequency of the diode ladder relative to its stage cutoff.
+const DIODE_LADDER_PEAK_RATIO: f32 = std::f32::consts::SQRT_2;
+
+/// tanh(x) / x, the secant slope that stands in for tanh during a solve.
+#[inline]
+fn tanh_ratio(x: f32) -> f32 {
+    if x.abs() < 1e-4 {
+        1.0
+    } else {
+        x.tanh() / x
+    }
+}
+
+/// Solves `m * y = b` by Gaussian elimination with partial pivoting.
+fn solve4(mut m: [[f32; 4]; 4], mut b: [f32; 4]) -> [f32; 4] {
+    for col in 0..4 {
+        let pivot = (col..4)
+            .max_by(|&r, &s| m[r][col].abs().total_cmp(&m[s][col].abs()))
+            .unwrap_or(col);
+        m.swap(col, pivot);
+        b.swap(col, pivot);
+        let pivot_row = m[col];
+        for row in col + 1..4 {
+            let factor = m[row][col] / pivot_row[col];
+            for (value, &p) in m[row].iter_mut().zip(&pivot_row).skip(col) {
+                *value -= factor * p;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+    let mut y = [0.0; 4];
+    for row in (0..4).rev() {
+        let tail: f32 = (row + 1..4).map(|j| m[row][j] * y[j]).sum();
+        y[row] = (b[row] - tail) / m[row][row];
+    }
+    y
+}
+
+/// Trapezoidal integrator gain for a cutoff, kept below Nyquist.
+fn prewarped_gain(cutoff: f32, sample_rate: f32) -> f32 {
+    let fraction = (cutoff / sample_rate).clamp(1e-6, 0.49);
+    (std::f32::consts::PI * fraction).tan()
+}
+
+/// Which stage of a `ZdfLadderFilter` feeds the output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LadderSlope {
+    /// First stage, 6 dB/octave.
+    Db6,
+    /// Second stage, 12 dB/octave.
+    Db12,
+    /// Third stage, 18 dB/octave.
+    Db18,
+    /// Fourth stage, 24 dB/octave.
+    #[default]
+    Db24,
+}
+
+/// State of a zero-delay-feedback ladder: one trapezoidal integrator per stage.
+#[derive(Debug, Clone, Default)]
+pub struct ZdfLadderState {
+    pub stages: [f32; 4],
+}
+
+impl ZdfLadderState {
+    /// Advances the integrators to the stage outputs just solved for.
+    #[inline]
+    fn update(&mut self, y: &[f32; 4]) {
+        for (s, &y) in self.stages.iter_mut().zip(y) {
+            *s = 2.0 * y - *s;
+        }
+    }
+}
+
+/// Zero-delay-feedback Moog transistor ladder.
+///
+/// Each of the four one-pole stages runs its input and output through tanh,
+/// as does the input after the feedback is subtracted. Every sample the
+/// tanh terms are linearized around an estimate of the operating point and
+/// the implicit system is solved exactly, then re-linearized around that
+/// solution. The trapezoidal integrators are prewarped, so the resonant
+/// peak sits on the cutoff. Above a `resonance` of 1 the filter
+/// self-oscillates at the cutoff, settling a few percent flat as the
+/// stages saturate, by the same ratio at every pitch.
+/// `compensation` feeds the input forward into the feedback to restore the
+/// passband level the resonance takes away.
+#[derive(Debug, Clone)]
+pub struct ZdfLadderFilter {
+    pub cutoff: f32,
+    /// 1 is the self-oscillation threshold.
+    pub resonance: f32,
+    /// Input gain into the stage nonlinearities.
+    pub drive: f32,
+    /// 0 lets the passband drop with resonance, 1 holds it at unity.
+    pub compensation: f32,
+    pub slope: LadderSlope,
+}
+
+impl Default for ZdfLadderFilter {
+    fn default() -> Self {
+        Self {
+            cutoff: 1000.0,
+            resonance: 0.5,
+            drive: 1.0,
+            compensation: 0.0,
+            slope: LadderSlope::Db24,
+        }
+    }
+}
+
+impl NodeDef for ZdfLadderFilter {
+    type State = ZdfLadderState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            }, // input
+            Port {
+                id: PortId(1),
+                rate: Rate::Audio,
+            }, // cutoff_mod
+            Port {
+                id: PortId(2),
+                rate: Rate::Audio,
+            }, // resonance_mod
+        ];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        ZdfLadderState::default()
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let cutoff_mod = if inputs.len() > 1 { inputs[1] } else { &[] };
+        let resonance_mod = if inputs.len() > 2 { inputs[2] } else { &[] };
+        let output = &mut outputs[0];
+        let tap = self.slope as usize;
+        let compensation = self.compensation.clamp(0.0, 1.0);
+        let mut g = prewarped_gain(self.cutoff, sample_rate);
+
+        for i in 0..input.len() {
+            if !cutoff_mod.is_empty() {
+                g = prewarped_gain(self.cutoff + cutoff_mod[i], sample_rate);
+            }
+            let resonance = self.resonance
+                + if resonance_mod.is_empty() {
+                    0.0
+                } else {
+                    resonance_mod[i]
+                };
+            let k = 4.0 * resonance.max(0.0);
+            let x = input[i] * self.drive * (1.0 + k * compensation);
+            let s = state.stages;
+
+            let mut y = s;
+            for _ in 0..LADDER_SOLVER_PASSES {
+                let t0 = tanh_ratio(x - k * y[3]);
+                let t = y.map(tanh_ratio);
+                let m = [
+                    [1.0 + g * t[0], 0.0, 0.0, g * t0 * k],
+                    [-g * t[0], 1.0 + g * t[1], 0.0, 0.0],
+                    [0.0, -g * t[1], 1.0 + g * t[2], 0.0],
+                    [0.0, 0.0, -g * t[2], 1.0 + g * t[3]],
+                ];
+                y = solve4(m, [s[0] + g * t0 * x, s[1], s[2], s[3]]);
+            }
+            state.update(&y);
+            output[i] = y[tap];
+        }
+    }
+}
+
+impl Parameterized for ZdfLadderFilter {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "cutoff", 20.0, 20000.0, 1000.0, ParamUnit::Hertz),
+            ParamDesc::new(1, "resonance", 0.0, 1.2, 0.5, ParamUnit::Normalized),
+            ParamDesc::new(2, "drive", 0.0, 10.0, 1.0, ParamUnit::Gain),
+            ParamDesc::new(3, "compensation", 0.0, 1.0, 0.0, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.cutoff),
+            1 => Some(self.resonance),
+            2 => Some(self.drive),
+            3 => Some(self.compensation),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.cutoff = value,
+            1 => self.resonance = value,
+            2 => self.drive = value,
+            3 => self.compensation = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for ZdfLadderFilter {
+    fn name(&self) -> &'static str {
+        "ZdfLadderFilter"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "cutoff_mod", 0, ParamUnit::Hertz),
+            PortDesc::modulation(2, "resonance_mod", 1, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// Zero-delay-feedback diode ladder (TB-303 style).
+///
+/// Unlike the transistor ladder the stages load each other: each capacitor
+/// charges through a diode pair from the node above and discharges into
+/// the node below, with a half-size capacitor at the bottom. The tanh
+/// terms act on the voltage across each diode pair and are solved the same
+/// way as in `ZdfLadderFilter`. The coupling spreads the poles, so the
+/// stage cutoff is scaled to put the resonant peak on `cutoff`, and
+/// `resonance` is scaled so that 1 is the self-oscillation threshold. A
+/// saturated oscillation settles further flat than the transistor ladder's.
+#[derive(Debug, Clone)]
+pub struct DiodeLadderFilter {
+    pub cutoff: f32,
+    /// 1 is the self-oscillation threshold.
+    pub resonance: f32,
+    /// Input gain into the diode nonlinearities.
+    pub drive: f32,
+    /// 0 lets the passband drop with resonance, 1 holds it at unity.
+    pub compensation: f32,
+}
+
+impl Default for DiodeLadderFilter {
+    fn default() -> Self {
+        Self {
+            cutoff: 1000.0,
+            resonance: 0.5,
+            drive: 1.0,
+            compensation: 0.0,
+        }
+    }
+}
+
+impl NodeDef for DiodeLadderFilter {
+    type State = ZdfLadderState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            }, // input
+            Port {
+                id: PortId(1),
+                rate: Rate::Audio,
+            }, // cutoff_mod
+            Port {
+                id: PortId(2),
+                rate: Rate::Audio,
+            }, // resonance_mod
+        ];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        ZdfLadderState::default()
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let cutoff_mod = if inputs.len() > 1 { inputs[1] } else { &[] };
+        let resonance_mod = if inputs.len() > 2 { inputs[2] } else { &[] };
+        let output = &mut outputs[0];
+        let compensation = self.compensation.clamp(0.0, 1.0);
+        let gain_at = |cutoff| prewarped_gain(cutoff, sample_rate) / DIODE_LADDER_PEAK_RATIO;
+        let mut g = gain_at(self.cutoff);
+
+        for i in 0..input.len() {
+            if !cutoff_mod.is_empty() {
+                g = gain_at(self.cutoff + cutoff_mod[i]);
+            }
+            let resonance = self.resonance
+                + if resonance_mod.is_empty() {
+                    0.0
+                } else {
+                    resonance_mod[i]
+                };
+            let k = DIODE_LADDER_THRESHOLD * resonance.max(0.0);
+            let x = input[i] * self.drive * (1.0 + k * compensation);
+            let s = state.stages;
+
+            let mut y = s;
+            for _ in 0..LADDER_SOLVER_PASSES {
+                let t = [
+                    tanh_ratio(x - k * y[3] - y[0]),
+                    tanh_ratio(y[0] - y[1]),
+                    tanh_ratio(y[1] - y[2]),
+                    tanh_ratio(y[2] - y[3]),
+                ];
+                let m = [
+                    [1.0 + g * (t[0] + t[1]), -g * t[1], 0.0, g * t[0] * k],
+                    [-g * t[1], 1.0 + g * (t[1] + t[2]), -g * t[2], 0.0],
+                    [0.0, -g * t[2], 1.0 + g * (t[2] + t[3]), -g * t[3]],
+                    [0.0, 0.0, -2.0 * g * t[3], 1.0 + 2.0 * g * t[3]],
+                ];
+                y = solve4(m, [s[0] + g * t[0] * x, s[1], s[2], s[3]]);
+            }
+            state.update(&y);
+            output[i] = y[3];
+        }
+    }
+}
+
+impl Parameterized for DiodeLadderFilter {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "cutoff", 20.0, 20000.0, 1000.0, ParamUnit::Hertz),
+            ParamDesc::new(1, "resonance", 0.0, 1.2, 0.5, ParamUnit::Normalized),
+            ParamDesc::new(2, "drive", 0.0, 10.0, 1.0, ParamUnit::Gain),
+            ParamDesc::new(3, "compensation", 0.0, 1.0, 0.0, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.cutoff),
+            1 => Some(self.resonance),
+            2 => Some(self.drive),
+            3 => Some(self.compensation),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.cutoff = value,
+            1 => self.resonance = value,
+            2 => self.drive = value,
+            3 => self.compensation = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for DiodeLadderFilter {
+    fn name(&self) -> &'static str {
+        "DiodeLadderFilter"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "cutoff_mod", 0, ParamUnit::Hertz),
+            PortDesc::modulation(2, "resonance_mod", 1, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// State of a Comb Filter
+#[derive(Debug, Clone)]
+pub struct CombState {
+    pub buffer: Vec<f32>,
+    pub index: usize,
+}
+
+/// Comb Filter
+#[derive(Debug, Clone)]
+pub struct CombFilter {
+    pub delay_ms: f32,
+    pub feedback: f32,
+    pub damp: f32,
+}
+
+impl NodeDef for CombFilter {
+    type State = CombState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            },
+            Port {
+                id: PortId(1),
+                rate: Rate::Audio,
+            },
+            Port {
+                id: PortId(2),
+                rate: Rate::Audio,
+            },
+        ];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, sample_rate: f32, _block_size: usize) -> Self::State {
+        let delay_samples = (self.delay_ms * sample_rate / 1000.0) as usize;
+        CombState {
+            buffer: vec![0.0; delay_samples],
+            index: 0,
+        }
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let feedback_mod = if inputs.len() > 1 { inputs[1] } else { &[] };
+        let damp_mod = if inputs.len() > 2 { inputs[2] } else { &[] };
+        let output = &mut outputs[0];
+
+        let delay_samples = state.buffer.len();
+        let mut damp = self.damp;
+        let mut feedback = self.feedback;
+
+        for i in 0..input.len() {
+            if !feedback_mod.is_empty() {
+                feedback = self.feedback + feedback_mod[i];
+            }
+            if !damp_mod.is_empty() {
+                damp = self.damp + damp_mod[i];
+            }
+
+            let delayed = state.buffer[state.index];
+            let damped = delayed * (1.0 - damp);
+            let out = input[i] + damped * feedback;
+            output[i] = out;
+
+            state.buffer[state.index] = out;
+            state.index = (state.index + 1) % delay_samples;
+        }
+    }
+}
+
+impl Parameterized for CombFilter {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "feedback", 0.0, 0.99, 0.5, ParamUnit::Normalized),
+            ParamDesc::new(1, "damp", 0.0, 1.0, 0.2, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.feedback),
+            1 => Some(self.damp),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.feedback = value,
+            1 => self.damp = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for CombFilter {
+    fn name(&self) -> &'static str {
+        "CombFilter"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "feedback_mod", 0, ParamUnit::Normalized),
+            PortDesc::modulation(2, "damp_mod", 1, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// State of a Formant Filter
+#[derive(Debug, Clone)]
+pub struct FormantState {
+    pub x1: f32,
+    pub x2: f32,
+    pub y1: f32,
+    pub y2: f32,
+}
+
+/// Formant Filter (simple vowel formant)
+#[derive(Debug, Clone)]
+pub struct FormantFilter {
+    pub freq1: f32,
+    pub freq2: f32,
+    pub bw1: f32,
+    pub bw2: f32,
+    pub gain1: f32,
+    pub gain2: f32,
+}
+
+impl NodeDef for FormantFilter {
+    type State = FormantState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        FormantState {
+            x1: 0.0,
+            x2: 0.0,
+            y1: 0.0,
+            y2: 0.0,
+        }
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let output = &mut outputs[0];
+
+        for i in 0..input.len() {
+            let x = input[i];
+
+            // Simple formant: two bandpass filters in parallel
+            let c1 = compute_exponential_coefficient(self.freq1, self.bw1);
+            let c2 = compute_exponential_coefficient(self.freq2, self.bw2);
+
+            let y1 = x * self.gain1 + state.x1 * c1 - state.y1 * c1;
+            let y2 = x * self.gain2 + state.x2 * c2 - state.y2 * c2;
+
+            state.x1 = x;
+            state.x2 = x;
+            state.y1 = y1;
+            state.y2 = y2;
+
+            output[i] = y1 + y2;
+        }
+    }
+}
+
+impl Parameterized for FormantFilter {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "freq1", 20.0, 20000.0, 800.0, ParamUnit::Hertz),
+            ParamDesc::new(1, "freq2", 20.0, 20000.0, 1200.0, ParamUnit::Hertz),
+            ParamDesc::new(2, "bw1", 1.0, 5000.0, 80.0, ParamUnit::Hertz),
+            ParamDesc::new(3, "bw2", 1.0, 5000.0, 90.0, ParamUnit::Hertz),
+            ParamDesc::new(4, "gain1", 0.0, 4.0, 1.0, ParamUnit::Gain),
+            ParamDesc::new(5, "gain2", 0.0, 4.0, 1.0, ParamUnit::Gain),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.freq1),
+            1 => Some(self.freq2),
+            2 => Some(self.bw1),
+            3 => Some(self.bw2),
+            4 => Some(self.gain1),
+            5 => Some(self.gain2),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.freq1 = value,
+            1 => self.freq2 = value,
+            2 => self.bw1 = value,
+            3 => self.bw2 = value,
+            4 => self.gain1 = value,
+            5 => self.gain2 = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for FormantFilter {
+    fn name(&self) -> &'static str {
+        "FormantFilter"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// State of a BiquadFilter
+#[derive(Debug, Clone, Default)]
+pub struct BiquadFilterState {
+    pub x1: f32,
+    pub x2: f32,
+    pub y1: f32,
+    pub y2: f32,
+}
+
+impl BiquadFilterState {
+    /// Run one sample through `coefficients` in direct form I, which keeps
+    /// the state meaningful when the coefficients change between samples.
+    #[inline]
+    pub fn process(&mut self, coefficients: &BiquadCoefficients, x: f32) -> f32 {
+        let c = coefficients;
+        let y = c.b0 * x + c.b1 * self.x1 + c.b2 * self.x2 - c.a1 * self.y1 - c.a2 * self.y2;
+
+        self.x2 = self.x1;
+        self.x1 = x;
+        self.y2 = self.y1;
+        self.y1 = y;
+        y
+    }
+}
+
+/// Biquad Filter (second-order IIR)
+#[derive(Debug, Clone)]
+pub struct BiquadFilter {
+    pub b0: f32,
+    pub b1: f32,
+    pub b2: f32,
+    pub a1: f32,
+    pub a2: f32,
+}
+
+impl BiquadFilter {
+    /// Filter with fixed coefficients, typically from
+    /// `BiquadCoefficients::design`.
+    pub fn new(coefficients: BiquadCoefficients) -> Self {
+        Self {
+            b0: coefficients.b0,
+            b1: coefficients.b1,
+            b2: coefficients.b2,
+            a1: coefficients.a1,
+            a2: coefficients.a2,
+        }
+    }
+
+    pub fn coefficients(&self) -> BiquadCoefficients {
+        BiquadCoefficients {
+            b0: self.b0,
+            b1: self.b1,
+            b2: self.b2,
+            a1: self.a1,
+            a2: self.a2,
+        }
+    }
+}
+
+impl NodeDef for BiquadFilter {
+    type State = BiquadFilterState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        BiquadFilterState::default()
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let output = &mut outputs[0];
+        let coefficients = self.coefficients();
+
+        for i in 0..input.len() {
+            output[i] = state.process(&coefficients, input[i]);
+        }
+    }
+}
 
-            let y4 = x - k * (state.z4 + state.z3 + state.z2 + state.z1);
-            let y3 = y4 * t + state.z4;
-            let y2 = y3 * t + state.z3;
-            let y1 = y2 * t + state.z2;
-            let y0 = y1 * t + state.z1;
+impl Parameterized for BiquadFilter {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "b0", -4.0, 4.0, 1.0, ParamUnit::None),
+            ParamDesc::new(1, "b1", -4.0, 4.0, 0.0, ParamUnit::None),
+            ParamDesc::new(2, "b2", -4.0, 4.0, 0.0, ParamUnit::None),
+            ParamDesc::new(3, "a1", -2.0, 2.0, 0.0, ParamUnit::None),
+            ParamDesc::new(4, "a2", -1.0, 1.0, 0.0, ParamUnit::None),
+        ];
+        PARAMS
+    }
 
-            state.z1 += y0 * t;
-            state.z2 += y1 * t;
-            state.z3 += y2 * t;
-            state.z4 += y3 * t;
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.b0),
+            1 => Some(self.b1),
+            2 => Some(self.b2),
+            3 => Some(self.a1),
+            4 => Some(self.a2),
+            _ => None,
+        }
+    }
 
-            output[i] = y4;
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.b0 = value,
+            1 => self.b1 = value,
+            2 => self.b2 = value,
+            3 => self.a1 = value,
+            4 => self.a2 = value,
+            _ => {}
         }
     }
 }
 
-/// State of a Comb Filter
-#[derive(Debug, Clone)]
-pub struct CombState {
-    pub buffer: Vec<f32>,
-    pub index: usize,
+impl Describe for BiquadFilter {
+    fn name(&self) -> &'static str {
+        "BiquadFilter"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
 }
 
-/// Comb Filter
+/// When a `ParametricBiquad` recomputes its coefficients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoefficientUpdate {
+    /// Every sample while a modulation input is connected.
+    #[default]
+    PerSample,
+    /// Once per block, from the modulation at its first sample.
+    PerBlock,
+}
+
+/// Biquad designed from cutoff, Q and gain with the cookbook formulas,
+/// redesigned as its modulation inputs move.
 #[derive(Debug, Clone)]
-pub struct CombFilter {
-    pub delay_ms: f32,
-    pub feedback: f32,
-    pub damp: f32,
+pub struct ParametricBiquad {
+    pub response: BiquadResponse,
+    pub cutoff: f32,
+    pub q: f32,
+    /// Gain in dB for peaking and shelving responses.
+    pub gain: f32,
+    pub update: CoefficientUpdate,
 }
 
-impl NodeDef for CombFilter {
-    type State = CombState;
+impl ParametricBiquad {
+    pub fn new(response: BiquadResponse, cutoff: f32, q: f32) -> Self {
+        Self {
+            response,
+            cutoff,
+            q,
+            gain: 0.0,
+            update: CoefficientUpdate::PerSample,
+        }
+    }
+
+    fn design(&self, cutoff: f32, q: f32, gain: f32, sample_rate: f32) -> BiquadCoefficients {
+        BiquadCoefficients::design(self.response, cutoff, Bandwidth::Q(q), gain, sample_rate)
+    }
+}
+
+impl NodeDef for ParametricBiquad {
+    type State = BiquadFilterState;
 
     fn input_ports(&self) -> &'static [Port] {
         const PORTS: &[Port] = &[
             Port {
                 id: PortId(0),
                 rate: Rate::Audio,
-            },
+            }, // input
             Port {
                 id: PortId(1),
                 rate: Rate::Audio,
-            },
+            }, // cutoff_mod
             Port {
                 id: PortId(2),
                 rate: Rate::Audio,
-            },
+            }, // q_mod
+            Port {
+                id: PortId(3),
+                rate: Rate::Audio,
+            }, // gain_mod
         ];
         PORTS
     }
@@ -286,12 +1517,8 @@ impl NodeDef for CombFilter {
         1
     }
 
-    fn init_state(&self, sample_rate: f32, _block_size: usize) -> Self::State {
-        let delay_samples = (self.delay_ms * sample_rate / 1000.0) as usize;
-        CombState {
-            buffer: vec![0.0; delay_samples],
-            index: 0,
-        }
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        BiquadFilterState::default()
     }
 
     fn process_block(
@@ -299,58 +1526,131 @@ impl NodeDef for CombFilter {
         state: &mut Self::State,
         inputs: &[&[f32]],
         outputs: &mut [Vec<f32>],
-        _sample_rate: f32,
+        sample_rate: f32,
     ) {
         let input = &inputs[0];
-        let feedback_mod = if inputs.len() > 1 { inputs[1] } else { &[] };
-        let damp_mod = if inputs.len() > 2 { inputs[2] } else { &[] };
+        let cutoff_mod = if inputs.len() > 1 { inputs[1] } else { &[] };
+        let q_mod = if inputs.len() > 2 { inputs[2] } else { &[] };
+        let gain_mod = if inputs.len() > 3 { inputs[3] } else { &[] };
         let output = &mut outputs[0];
-
-        let delay_samples = state.buffer.len();
-        let mut damp = self.damp;
-        let mut feedback = self.feedback;
+        let modulation = |buf: &[f32], i: usize| if buf.is_empty() { 0.0 } else { buf[i] };
+        let design_at = |i: usize| {
+            self.design(
+                self.cutoff + modulation(cutoff_mod, i),
+                self.q + modulation(q_mod, i),
+                self.gain + modulation(gain_mod, i),
+                sample_rate,
+            )
+        };
+
+        let modulated = !(cutoff_mod.is_empty() && q_mod.is_empty() && gain_mod.is_empty());
+        let per_sample = modulated && self.update == CoefficientUpdate::PerSample;
+        let mut coefficients = if input.is_empty() {
+            BiquadCoefficients::IDENTITY
+        } else {
+            design_at(0)
+        };
 
         for i in 0..input.len() {
-            if !feedback_mod.is_empty() {
-                feedback = self.feedback + feedback_mod[i];
-            }
-            if !damp_mod.is_empty() {
-                damp = self.damp + damp_mod[i];
+            if per_sample && i > 0 {
+                coefficients = design_at(i);
             }
+            output[i] = state.process(&coefficients, input[i]);
+        }
+    }
+}
 
-            let delayed = state.buffer[state.index];
-            let damped = delayed * (1.0 - damp);
-            let out = input[i] + damped * feedback;
-            output[i] = out;
+impl Parameterized for ParametricBiquad {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "cutoff", 20.0, 20000.0, 1000.0, ParamUnit::Hertz),
+            ParamDesc::new(1, "q", 0.1, 20.0, 0.707, ParamUnit::None),
+            ParamDesc::new(2, "gain", -24.0, 24.0, 0.0, ParamUnit::Decibels),
+        ];
+        PARAMS
+    }
 
-            state.buffer[state.index] = out;
-            state.index = (state.index + 1) % delay_samples;
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.cutoff),
+            1 => Some(self.q),
+            2 => Some(self.gain),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.cutoff = value,
+            1 => self.q = value,
+            2 => self.gain = value,
+            _ => {}
         }
     }
 }
 
-/// State of a Formant Filter
+impl Describe for ParametricBiquad {
+    fn name(&self) -> &'static str {
+        "ParametricBiquad"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "cutoff_mod", 0, ParamUnit::Hertz),
+            PortDesc::modulation(2, "q_mod", 1, ParamUnit::None),
+            PortDesc::modulation(3, "gain_mod", 2, ParamUnit::Decibels),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// State of a CascadeFilter: a chain of biquads with their state, also used
+/// for the bands of a BandSplitter.
 #[derive(Debug, Clone)]
-pub struct FormantState {
-    pub x1: f32,
-    pub x2: f32,
-    pub y1: f32,
-    pub y2: f32,
+pub struct CascadeFilterState {
+    pub sections: Vec<BiquadCoefficients>,
+    pub stages: Vec<BiquadFilterState>,
 }
 
-/// Formant Filter (simple vowel formant)
+impl CascadeFilterState {
+    pub fn new(sections: Vec<BiquadCoefficients>) -> Self {
+        Self {
+            stages: vec![BiquadFilterState::default(); sections.len()],
+            sections,
+        }
+    }
+
+    #[inline]
+    pub fn process(&mut self, x: f32) -> f32 {
+        self.stages
+            .iter_mut()
+            .zip(&self.sections)
+            .fold(x, |y, (stage, section)| stage.process(section, y))
+    }
+}
+
+/// High-order Butterworth, Chebyshev, Bessel or elliptic filter run as a
+/// cascade of biquads. The sections are designed in `init_state`, so the
+/// design is fixed for the life of the state.
 #[derive(Debug, Clone)]
-pub struct FormantFilter {
-    pub freq1: f32,
-    pub freq2: f32,
-    pub bw1: f32,
-    pub bw2: f32,
-    pub gain1: f32,
-    pub gain2: f32,
+pub struct CascadeFilter {
+    pub design: FilterDesign,
 }
 
-impl NodeDef for FormantFilter {
-    type State = FormantState;
+impl CascadeFilter {
+    pub fn new(design: FilterDesign) -> Self {
+        Self { design }
+    }
+}
+
+impl NodeDef for CascadeFilter {
+    type State = CascadeFilterState;
 
     fn input_ports(&self) -> &'static [Port] {
         const PORTS: &[Port] = &[Port {
@@ -372,13 +1672,8 @@ impl NodeDef for FormantFilter {
         1
     }
 
-    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
-        FormantState {
-            x1: 0.0,
-            x2: 0.0,
-            y1: 0.0,
-            y2: 0.0,
-        }
+    fn init_state(&self, sample_rate: f32, _block_size: usize) -> Self::State {
+        CascadeFilterState::new(self.design.sections(sample_rate))
     }
 
     fn process_block(
@@ -392,46 +1687,79 @@ impl NodeDef for FormantFilter {
         let output = &mut outputs[0];
 
         for i in 0..input.len() {
-            let x = input[i];
+            output[i] = state.process(input[i]);
+        }
+    }
+}
 
-            // Simple formant: two bandpass filters in parallel
-            let c1 = compute_exponential_coefficient(self.freq1, self.bw1);
-            let c2 = compute_exponential_coefficient(self.freq2, self.bw2);
+impl Parameterized for CascadeFilter {
+    fn params(&self) -> &'static [ParamDesc] {
+        &[]
+    }
 
-            let y1 = x * self.gain1 + state.x1 * c1 - state.y1 * c1;
-            let y2 = x * self.gain2 + state.x2 * c2 - state.y2 * c2;
+    fn get_param(&self, _id: usize) -> Option<f32> {
+        None
+    }
 
-            state.x1 = x;
-            state.x2 = x;
-            state.y1 = y1;
-            state.y2 = y2;
+    fn set_param(&mut self, _id: usize, _value: f32) {}
+}
 
-            output[i] = y1 + y2;
-        }
+impl Describe for CascadeFilter {
+    fn name(&self) -> &'static str {
+        "CascadeFilter"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
     }
 }
 
-/// State of a BiquadFilter
+/// Most bands a `BandSplitter` produces.
+pub const MAX_BANDS: usize = 8;
+
+/// State of a BandSplitter
 #[derive(Debug, Clone)]
-pub struct BiquadFilterState {
-    pub x1: f32,
-    pub x2: f32,
-    pub y1: f32,
-    pub y2: f32,
+pub struct BandSplitterState {
+    /// Lowpass and highpass halves of each crossover, lowest first.
+    pub lowpass: Vec<CascadeFilterState>,
+    pub highpass: Vec<CascadeFilterState>,
+    /// For each band below the top, the allpasses of every crossover above
+    /// it.
+    pub compensation: Vec<Vec<CascadeFilterState>>,
 }
 
-/// Biquad Filter (second-order IIR)
+/// Phase-coherent multiband splitter built from Linkwitz-Riley crossovers.
+///
+/// Band 0 is below the lowest crossover frequency and the last band is above
+/// the highest. Each band passes through the allpasses of the crossovers
+/// above it, so all bands carry the same phase shift and sum to a flat
+/// magnitude response. Up to `MAX_BANDS - 1` frequencies are used, sorted
+/// ascending; the crossovers are designed in `init_state`.
 #[derive(Debug, Clone)]
-pub struct BiquadFilter {
-    pub b0: f32,
-    pub b1: f32,
-    pub b2: f32,
-    pub a1: f32,
-    pub a2: f32,
+pub struct BandSplitter {
+    pub frequencies: Vec<f32>,
+    pub order: CrossoverOrder,
 }
 
-impl NodeDef for BiquadFilter {
-    type State = BiquadFilterState;
+impl BandSplitter {
+    pub fn new(frequencies: Vec<f32>, order: CrossoverOrder) -> Self {
+        Self { frequencies, order }
+    }
+
+    /// Number of output bands.
+    pub fn bands(&self) -> usize {
+        self.frequencies.len().min(MAX_BANDS - 1) + 1
+    }
+}
+
+impl NodeDef for BandSplitter {
+    type State = BandSplitterState;
 
     fn input_ports(&self) -> &'static [Port] {
         const PORTS: &[Port] = &[Port {
@@ -442,23 +1770,72 @@ impl NodeDef for BiquadFilter {
     }
 
     fn output_ports(&self) -> &'static [Port] {
-        const PORTS: &[Port] = &[Port {
-            id: PortId(0),
-            rate: Rate::Audio,
-        }];
-        PORTS
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            },
+            Port {
+                id: PortId(1),
+                rate: Rate::Audio,
+            },
+            Port {
+                id: PortId(2),
+                rate: Rate::Audio,
+            },
+            Port {
+                id: PortId(3),
+                rate: Rate::Audio,
+            },
+            Port {
+                id: PortId(4),
+                rate: Rate::Audio,
+            },
+            Port {
+                id: PortId(5),
+                rate: Rate::Audio,
+            },
+            Port {
+                id: PortId(6),
+                rate: Rate::Audio,
+            },
+            Port {
+                id: PortId(7),
+                rate: Rate::Audio,
+            },
+        ];
+        &PORTS[..self.bands()]
     }
 
     fn required_inputs(&self) -> usize {
         1
     }
 
-    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
-        BiquadFilterState {
-            x1: 0.0,
-            x2: 0.0,
-            y1: 0.0,
-            y2: 0.0,
+    fn init_state(&self, sample_rate: f32, _block_size: usize) -> Self::State {
+        let mut frequencies = self.frequencies.clone();
+        frequencies.truncate(MAX_BANDS - 1);
+        frequencies.sort_by(f32::total_cmp);
+        let crossovers: Vec<LinkwitzRiley> = frequencies
+            .iter()
+            .map(|&f| LinkwitzRiley::design(self.order, f, sample_rate))
+            .collect();
+        BandSplitterState {
+            lowpass: crossovers
+                .iter()
+                .map(|c| CascadeFilterState::new(c.lowpass.clone()))
+                .collect(),
+            highpass: crossovers
+                .iter()
+                .map(|c| CascadeFilterState::new(c.highpass.clone()))
+                .collect(),
+            compensation: (0..crossovers.len())
+                .map(|band| {
+                    crossovers[band + 1..]
+                        .iter()
+                        .map(|c| CascadeFilterState::new(c.allpass.clone()))
+                        .collect()
+                })
+                .collect(),
         }
     }
 
@@ -470,21 +1847,56 @@ impl NodeDef for BiquadFilter {
         _sample_rate: f32,
     ) {
         let input = &inputs[0];
-        let output = &mut outputs[0];
+        let top = state.lowpass.len();
 
         for i in 0..input.len() {
-            let x = input[i];
-            let y = self.b0 * x + self.b1 * state.x1 + self.b2 * state.x2
-                - self.a1 * state.y1
-                - self.a2 * state.y2;
+            let mut rest = input[i];
+            for (band, output) in outputs.iter_mut().enumerate().take(top) {
+                let low = state.lowpass[band].process(rest);
+                rest = state.highpass[band].process(rest);
+                output[i] = state.compensation[band]
+                    .iter_mut()
+                    .fold(low, |y, allpass| allpass.process(y));
+            }
+            outputs[top][i] = rest;
+        }
+    }
+}
 
-            state.x2 = state.x1;
-            state.x1 = x;
-            state.y2 = state.y1;
-            state.y1 = y;
+impl Parameterized for BandSplitter {
+    fn params(&self) -> &'static [ParamDesc] {
+        &[]
+    }
 
-            output[i] = y;
-        }
+    fn get_param(&self, _id: usize) -> Option<f32> {
+        None
+    }
+
+    fn set_param(&mut self, _id: usize, _value: f32) {}
+}
+
+impl Describe for BandSplitter {
+    fn name(&self) -> &'static str {
+        "BandSplitter"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "band_0"),
+            PortDesc::audio(1, "band_1"),
+            PortDesc::audio(2, "band_2"),
+            PortDesc::audio(3, "band_3"),
+            PortDesc::audio(4, "band_4"),
+            PortDesc::audio(5, "band_5"),
+            PortDesc::audio(6, "band_6"),
+            PortDesc::audio(7, "band_7"),
+        ];
+        &PORTS[..self.bands()]
     }
 }
 
@@ -551,3 +1963,37 @@ impl NodeDef for AllpassFilter {
         }
     }
 }
+
+impl Parameterized for AllpassFilter {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] =
+            &[ParamDesc::new(0, "gain", -0.99, 0.99, 0.5, ParamUnit::None)];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        (id == 0).then_some(self.gain)
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        if id == 0 {
+            self.gain = value;
+        }
+    }
+}
+
+impl Describe for AllpassFilter {
+    fn name(&self) -> &'static str {
+        "AllpassFilter"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
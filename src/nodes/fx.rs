@@ -1,16 +1,122 @@
+use crate::describe::{Describe, PortDesc};
+use crate::helpers::{Interpolation, Interpolator};
+use crate::params::{ParamDesc, ParamUnit, Parameterized};
 use auxide::graph::{Port, PortId, Rate};
 use auxide::node::NodeDef;
 use num_complex::Complex;
 use realfft::RealFftPlanner;
 
+/// A delay-line node whose fractional reads can use any [`Interpolation`].
+///
+/// `init_state` reads linearly; [`with_interpolation`] picks another kernel.
+///
+/// [`with_interpolation`]: FractionalDelay::with_interpolation
+pub trait FractionalDelay: NodeDef {
+    /// State whose delay line is read with `interpolation`.
+    fn init_state_with(
+        &self,
+        interpolation: Interpolation,
+        sample_rate: f32,
+        block_size: usize,
+    ) -> Self::State;
+
+    /// Wraps the node so its delay line is read with `interpolation`.
+    fn with_interpolation(self, interpolation: Interpolation) -> Interpolated<Self>
+    where
+        Self: Sized,
+    {
+        Interpolated::new(self, interpolation)
+    }
+}
+
+/// Runs a [`FractionalDelay`] node with a chosen interpolation kernel.
+#[derive(Debug, Clone)]
+pub struct Interpolated<T> {
+    pub node: T,
+    pub interpolation: Interpolation,
+}
+
+impl<T: FractionalDelay> Interpolated<T> {
+    pub fn new(node: T, interpolation: Interpolation) -> Self {
+        Self {
+            node,
+            interpolation,
+        }
+    }
+}
+
+impl<T: FractionalDelay> NodeDef for Interpolated<T> {
+    type State = T::State;
+
+    fn input_ports(&self) -> &'static [Port] {
+        self.node.input_ports()
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        self.node.output_ports()
+    }
+
+    fn required_inputs(&self) -> usize {
+        self.node.required_inputs()
+    }
+
+    fn init_state(&self, sample_rate: f32, block_size: usize) -> Self::State {
+        self.node
+            .init_state_with(self.interpolation, sample_rate, block_size)
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        sample_rate: f32,
+    ) {
+        self.node.process_block(state, inputs, outputs, sample_rate)
+    }
+}
+
+impl<T: FractionalDelay + Parameterized> Parameterized for Interpolated<T> {
+    fn params(&self) -> &'static [ParamDesc] {
+        self.node.params()
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        self.node.get_param(id)
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        self.node.set_param(id, value)
+    }
+}
+
+impl<T: FractionalDelay + Describe> Describe for Interpolated<T> {
+    fn name(&self) -> &'static str {
+        self.node.name()
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        self.node.inputs()
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        self.node.outputs()
+    }
+}
+
 /// State of a Delay
 #[derive(Debug, Clone)]
 pub struct DelayState {
     pub buffer: Vec<f32>,
     pub index: usize,
+    pub delay_samples: f32,
+    pub interpolator: Interpolator,
 }
 
 /// Delay Effect
+///
+/// Fractional delay times are read linearly; see [`FractionalDelay`] for
+/// other kernels.
 #[derive(Debug, Clone)]
 pub struct Delay {
     pub delay_ms: f32,
@@ -51,12 +157,8 @@ impl NodeDef for Delay {
         1
     }
 
-    fn init_state(&self, sample_rate: f32, _block_size: usize) -> Self::State {
-        let delay_samples = (self.delay_ms * sample_rate / 1000.0) as usize;
-        DelayState {
-            buffer: vec![0.0; delay_samples],
-            index: 0,
-        }
+    fn init_state(&self, sample_rate: f32, block_size: usize) -> Self::State {
+        self.init_state_with(Interpolation::Linear, sample_rate, block_size)
     }
 
     fn process_block(
@@ -71,7 +173,7 @@ impl NodeDef for Delay {
         let mix_mod = if inputs.len() > 2 { inputs[2] } else { &[][..] };
         let output = &mut outputs[0];
 
-        let delay_samples = state.buffer.len();
+        let buffer_len = state.buffer.len();
 
         for i in 0..input.len() {
             let feedback = self.feedback
@@ -82,25 +184,96 @@ impl NodeDef for Delay {
                 };
             let mix = self.mix + if mix_mod.is_empty() { 0.0 } else { mix_mod[i] };
 
-            let delayed = state.buffer[state.index];
+            let delayed =
+                state
+                    .interpolator
+                    .read_delay(&state.buffer, state.index, state.delay_samples);
             let out = input[i] + delayed * feedback * mix + input[i] * (1.0 - mix);
             output[i] = out;
 
             state.buffer[state.index] = input[i] + delayed * feedback;
-            state.index = (state.index + 1) % delay_samples;
+            state.index = (state.index + 1) % buffer_len;
+        }
+    }
+}
+
+impl FractionalDelay for Delay {
+    fn init_state_with(
+        &self,
+        interpolation: Interpolation,
+        sample_rate: f32,
+        _block_size: usize,
+    ) -> Self::State {
+        let taps = interpolation.taps();
+        let delay_samples = (self.delay_ms * sample_rate / 1000.0).max((taps / 2) as f32);
+        DelayState {
+            buffer: vec![0.0; delay_samples.ceil() as usize + taps],
+            index: 0,
+            delay_samples,
+            interpolator: Interpolator::new(interpolation),
+        }
+    }
+}
+
+impl Parameterized for Delay {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "feedback", 0.0, 0.99, 0.5, ParamUnit::Normalized),
+            ParamDesc::new(1, "mix", 0.0, 1.0, 0.5, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.feedback),
+            1 => Some(self.mix),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.feedback = value,
+            1 => self.mix = value,
+            _ => {}
         }
     }
 }
 
+impl Describe for Delay {
+    fn name(&self) -> &'static str {
+        "Delay"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "feedback_mod", 0, ParamUnit::Normalized),
+            PortDesc::modulation(2, "mix_mod", 1, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 /// State of a Chorus
 #[derive(Debug, Clone)]
 pub struct ChorusState {
     pub buffer: Vec<f32>,
     pub index: usize,
     pub lfo_phase: f32,
+    pub interpolator: Interpolator,
 }
 
 /// Chorus Effect
+///
+/// The swept delay is read linearly; see [`FractionalDelay`] for other
+/// kernels.
 #[derive(Debug, Clone)]
 pub struct Chorus {
     pub delay_ms: f32,
@@ -142,13 +315,8 @@ impl NodeDef for Chorus {
         1
     }
 
-    fn init_state(&self, sample_rate: f32, _block_size: usize) -> Self::State {
-        let max_delay = (self.delay_ms + self.depth_ms) * sample_rate / 1000.0;
-        ChorusState {
-            buffer: vec![0.0; max_delay as usize + 1],
-            index: 0,
-            lfo_phase: 0.0,
-        }
+    fn init_state(&self, sample_rate: f32, block_size: usize) -> Self::State {
+        self.init_state_with(Interpolation::Linear, sample_rate, block_size)
     }
 
     fn process_block(
@@ -163,8 +331,9 @@ impl NodeDef for Chorus {
         let mix_mod = if inputs.len() > 2 { inputs[2] } else { &[] };
         let output = &mut outputs[0];
 
-        let base_delay_samples = (self.delay_ms * sample_rate / 1000.0) as usize;
+        let base_delay_samples = self.delay_ms * sample_rate / 1000.0;
         let depth_samples = self.depth_ms * sample_rate / 1000.0;
+        let min_delay = (state.interpolator.kind().taps() / 2) as f32;
 
         for i in 0..input.len() {
             let rate = self.rate
@@ -179,14 +348,10 @@ impl NodeDef for Chorus {
             state.lfo_phase = (state.lfo_phase + lfo_inc).fract();
             let lfo = (state.lfo_phase * std::f32::consts::TAU).sin() * 0.5 + 0.5; // 0 to 1
 
-            let delay_samples = base_delay_samples as f32 + lfo * depth_samples;
-            let delay_int = delay_samples as usize;
-            let frac = delay_samples.fract();
-
-            let idx1 = (state.index + state.buffer.len() - delay_int) % state.buffer.len();
-            let idx2 = (idx1 + state.buffer.len() - 1) % state.buffer.len();
-
-            let delayed = state.buffer[idx1] * (1.0 - frac) + state.buffer[idx2] * frac;
+            let delay_samples = (base_delay_samples + lfo * depth_samples).max(min_delay);
+            let delayed = state
+                .interpolator
+                .read_delay(&state.buffer, state.index, delay_samples);
 
             let out = input[i] * (1.0 - mix) + delayed * mix;
             output[i] = out;
@@ -197,15 +362,82 @@ impl NodeDef for Chorus {
     }
 }
 
+impl FractionalDelay for Chorus {
+    fn init_state_with(
+        &self,
+        interpolation: Interpolation,
+        sample_rate: f32,
+        _block_size: usize,
+    ) -> Self::State {
+        let max_delay = (self.delay_ms + self.depth_ms) * sample_rate / 1000.0;
+        ChorusState {
+            buffer: vec![0.0; max_delay as usize + interpolation.taps() + 1],
+            index: 0,
+            lfo_phase: 0.0,
+            interpolator: Interpolator::new(interpolation),
+        }
+    }
+}
+
+impl Parameterized for Chorus {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "rate", 0.0, 20.0, 1.0, ParamUnit::Hertz),
+            ParamDesc::new(1, "mix", 0.0, 1.0, 0.5, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.rate),
+            1 => Some(self.mix),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.rate = value,
+            1 => self.mix = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for Chorus {
+    fn name(&self) -> &'static str {
+        "Chorus"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "rate_mod", 0, ParamUnit::Hertz),
+            PortDesc::modulation(2, "mix_mod", 1, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 /// State of a Flanger
 #[derive(Debug, Clone)]
 pub struct FlangerState {
     pub buffer: Vec<f32>,
     pub index: usize,
     pub lfo_phase: f32,
+    pub interpolator: Interpolator,
 }
 
 /// Flanger Effect
+///
+/// The swept delay is read linearly; see [`FractionalDelay`] for other
+/// kernels.
 #[derive(Debug, Clone)]
 pub struct Flanger {
     pub delay_ms: f32,
@@ -252,13 +484,8 @@ impl NodeDef for Flanger {
         1
     }
 
-    fn init_state(&self, sample_rate: f32, _block_size: usize) -> Self::State {
-        let max_delay = (self.delay_ms + self.depth_ms) * sample_rate / 1000.0;
-        FlangerState {
-            buffer: vec![0.0; max_delay as usize + 1],
-            index: 0,
-            lfo_phase: 0.0,
-        }
+    fn init_state(&self, sample_rate: f32, block_size: usize) -> Self::State {
+        self.init_state_with(Interpolation::Linear, sample_rate, block_size)
     }
 
     fn process_block(
@@ -274,8 +501,9 @@ impl NodeDef for Flanger {
         let mix_mod = if inputs.len() > 3 { inputs[3] } else { &[] };
         let output = &mut outputs[0];
 
-        let base_delay_samples = (self.delay_ms * sample_rate / 1000.0) as usize;
+        let base_delay_samples = self.delay_ms * sample_rate / 1000.0;
         let depth_samples = self.depth_ms * sample_rate / 1000.0;
+        let min_delay = (state.interpolator.kind().taps() / 2) as f32;
 
         for i in 0..input.len() {
             let rate = self.rate
@@ -296,14 +524,10 @@ impl NodeDef for Flanger {
             state.lfo_phase = (state.lfo_phase + lfo_inc).fract();
             let lfo = (state.lfo_phase * std::f32::consts::TAU).sin() * 0.5 + 0.5; // 0 to 1
 
-            let delay_samples = base_delay_samples as f32 + lfo * depth_samples;
-            let delay_int = delay_samples as usize;
-            let frac = delay_samples.fract();
-
-            let idx1 = (state.index + state.buffer.len() - delay_int) % state.buffer.len();
-            let idx2 = (idx1 + state.buffer.len() - 1) % state.buffer.len();
-
-            let delayed = state.buffer[idx1] * (1.0 - frac) + state.buffer[idx2] * frac;
+            let delay_samples = (base_delay_samples + lfo * depth_samples).max(min_delay);
+            let delayed = state
+                .interpolator
+                .read_delay(&state.buffer, state.index, delay_samples);
 
             let out = input[i] + delayed * mix;
             output[i] = out;
@@ -314,6 +538,73 @@ impl NodeDef for Flanger {
     }
 }
 
+impl FractionalDelay for Flanger {
+    fn init_state_with(
+        &self,
+        interpolation: Interpolation,
+        sample_rate: f32,
+        _block_size: usize,
+    ) -> Self::State {
+        let max_delay = (self.delay_ms + self.depth_ms) * sample_rate / 1000.0;
+        FlangerState {
+            buffer: vec![0.0; max_delay as usize + interpolation.taps() + 1],
+            index: 0,
+            lfo_phase: 0.0,
+            interpolator: Interpolator::new(interpolation),
+        }
+    }
+}
+
+impl Parameterized for Flanger {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "rate", 0.0, 20.0, 0.5, ParamUnit::Hertz),
+            ParamDesc::new(1, "feedback", -0.99, 0.99, 0.5, ParamUnit::Normalized),
+            ParamDesc::new(2, "mix", 0.0, 1.0, 0.5, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.rate),
+            1 => Some(self.feedback),
+            2 => Some(self.mix),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.rate = value,
+            1 => self.feedback = value,
+            2 => self.mix = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for Flanger {
+    fn name(&self) -> &'static str {
+        "Flanger"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "rate_mod", 0, ParamUnit::Hertz),
+            PortDesc::modulation(2, "feedback_mod", 1, ParamUnit::Normalized),
+            PortDesc::modulation(3, "mix_mod", 2, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 /// State of a Phaser
 #[derive(Debug, Clone)]
 pub struct PhaserState {
@@ -415,6 +706,58 @@ impl NodeDef for Phaser {
     }
 }
 
+impl Parameterized for Phaser {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "rate", 0.0, 20.0, 0.5, ParamUnit::Hertz),
+            ParamDesc::new(1, "depth", 0.0, 1.0, 0.5, ParamUnit::Normalized),
+            ParamDesc::new(2, "feedback", -0.99, 0.99, 0.3, ParamUnit::Normalized),
+            ParamDesc::new(3, "mix", 0.0, 1.0, 0.5, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.rate),
+            1 => Some(self.depth),
+            2 => Some(self.feedback),
+            3 => Some(self.mix),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.rate = value,
+            1 => self.depth = value,
+            2 => self.feedback = value,
+            3 => self.mix = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for Phaser {
+    fn name(&self) -> &'static str {
+        "Phaser"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "rate_mod", 0, ParamUnit::Hertz),
+            PortDesc::modulation(2, "mix_mod", 3, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 /// State of a Simple Reverb
 #[derive(Debug, Clone)]
 pub struct SimpleReverbState {
@@ -518,14 +861,63 @@ impl NodeDef for SimpleReverb {
     }
 }
 
+impl Parameterized for SimpleReverb {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "decay", 0.0, 0.99, 0.5, ParamUnit::Normalized),
+            ParamDesc::new(1, "mix", 0.0, 1.0, 0.3, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.decay),
+            1 => Some(self.mix),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.decay = value,
+            1 => self.mix = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for SimpleReverb {
+    fn name(&self) -> &'static str {
+        "SimpleReverb"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "mix_mod", 1, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 /// State of a MultitapDelay
 #[derive(Debug, Clone)]
 pub struct MultitapDelayState {
     pub buffer: Vec<f32>,
     pub index: usize,
+    pub interpolator: Interpolator,
 }
 
 /// Multitap Delay Effect
+///
+/// Fractional tap times are read linearly; see [`FractionalDelay`] for
+/// other kernels. The longest tap feeds back into the line.
 #[derive(Debug, Clone)]
 pub struct MultitapDelay {
     pub taps: Vec<(f32, f32)>, // (delay_ms, gain)
@@ -566,17 +958,8 @@ impl NodeDef for MultitapDelay {
         1
     }
 
-    fn init_state(&self, sample_rate: f32, _block_size: usize) -> Self::State {
-        let max_delay_samples = self
-            .taps
-            .iter()
-            .map(|(ms, _)| (ms * sample_rate / 1000.0) as usize)
-            .max()
-            .unwrap_or(1);
-        MultitapDelayState {
-            buffer: vec![0.0; max_delay_samples],
-            index: 0,
-        }
+    fn init_state(&self, sample_rate: f32, block_size: usize) -> Self::State {
+        self.init_state_with(Interpolation::Linear, sample_rate, block_size)
     }
 
     fn process_block(
@@ -590,6 +973,13 @@ impl NodeDef for MultitapDelay {
         let feedback_mod = if inputs.len() > 1 { inputs[1] } else { &[] };
         let mix_mod = if inputs.len() > 2 { inputs[2] } else { &[] };
         let output = &mut outputs[0];
+        let min_delay = (state.interpolator.kind().taps() / 2) as f32;
+        let delay_of = |ms: f32| (ms * sample_rate / 1000.0).max(min_delay);
+        let longest = self
+            .taps
+            .iter()
+            .map(|(ms, _)| delay_of(*ms))
+            .fold(min_delay, f32::max);
 
         for i in 0..input.len() {
             let feedback = self.feedback
@@ -600,16 +990,21 @@ impl NodeDef for MultitapDelay {
                 };
             let mix = self.mix + if mix_mod.is_empty() { 0.0 } else { mix_mod[i] };
 
-            // Write input + feedback
+            // Write input + feedback from the longest tap
             let buf_len = state.buffer.len();
-            state.buffer[state.index] = input[i] + state.buffer[state.index] * feedback;
+            let recirculated = state
+                .interpolator
+                .read_delay(&state.buffer, state.index, longest);
+            state.buffer[state.index] = input[i] + recirculated * feedback;
 
             // Sum taps
             let mut tap_sum = 0.0;
             for (delay_ms, gain) in &self.taps {
-                let delay_samples = (delay_ms * sample_rate / 1000.0) as usize;
-                let read_idx = (state.index + buf_len - delay_samples) % buf_len;
-                tap_sum += state.buffer[read_idx] * gain;
+                tap_sum +=
+                    state
+                        .interpolator
+                        .read_delay(&state.buffer, state.index, delay_of(*delay_ms))
+                        * gain;
             }
 
             output[i] = input[i] * (1.0 - mix) + tap_sum * mix;
@@ -619,6 +1014,73 @@ impl NodeDef for MultitapDelay {
     }
 }
 
+impl FractionalDelay for MultitapDelay {
+    fn init_state_with(
+        &self,
+        interpolation: Interpolation,
+        sample_rate: f32,
+        _block_size: usize,
+    ) -> Self::State {
+        let max_delay_samples = self
+            .taps
+            .iter()
+            .map(|(ms, _)| (ms * sample_rate / 1000.0).ceil() as usize)
+            .max()
+            .unwrap_or(1);
+        MultitapDelayState {
+            buffer: vec![0.0; max_delay_samples + interpolation.taps() + 1],
+            index: 0,
+            interpolator: Interpolator::new(interpolation),
+        }
+    }
+}
+
+impl Parameterized for MultitapDelay {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "feedback", 0.0, 0.99, 0.3, ParamUnit::Normalized),
+            ParamDesc::new(1, "mix", 0.0, 1.0, 0.5, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.feedback),
+            1 => Some(self.mix),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.feedback = value,
+            1 => self.mix = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for MultitapDelay {
+    fn name(&self) -> &'static str {
+        "MultitapDelay"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "feedback_mod", 0, ParamUnit::Normalized),
+            PortDesc::modulation(2, "mix_mod", 1, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 /// State of a ConvolutionReverb
 #[derive(Clone)]
 pub struct ConvolutionReverbState {
@@ -788,6 +1250,49 @@ impl NodeDef for ConvolutionReverb {
     }
 }
 
+impl Parameterized for ConvolutionReverb {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[ParamDesc::new(
+            0,
+            "mix",
+            0.0,
+            1.0,
+            0.3,
+            ParamUnit::Normalized,
+        )];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        (id == 0).then_some(self.mix)
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        if id == 0 {
+            self.mix = value;
+        }
+    }
+}
+
+impl Describe for ConvolutionReverb {
+    fn name(&self) -> &'static str {
+        "ConvolutionReverb"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "mix_mod", 0, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 /// State of a Tremolo
 #[derive(Debug, Clone)]
 pub struct TremoloState {
@@ -876,3 +1381,49 @@ impl NodeDef for Tremolo {
         }
     }
 }
+
+impl Parameterized for Tremolo {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "rate", 0.0, 20.0, 5.0, ParamUnit::Hertz),
+            ParamDesc::new(1, "depth", 0.0, 1.0, 0.5, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.rate),
+            1 => Some(self.depth),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.rate = value,
+            1 => self.depth = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for Tremolo {
+    fn name(&self) -> &'static str {
+        "Tremolo"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "rate_mod", 0, ParamUnit::Hertz),
+            PortDesc::modulation(2, "depth_mod", 1, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
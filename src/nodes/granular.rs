@@ -0,0 +1,351 @@
+//! Granular synthesis from a sample buffer or a live-recorded input.
+
+use std::sync::Arc;
+
+use crate::describe::{Describe, PortDesc};
+use crate::helpers::{linear_interpolate, ms_to_samples, xorshift};
+use crate::params::{ParamDesc, ParamUnit, Parameterized};
+use crate::windows::{blackman_window, hann_window};
+use auxide::graph::{Port, PortId, Rate};
+use auxide::node::NodeDef;
+
+/// Size of the precomputed grain window table.
+const WINDOW_SIZE: usize = 1024;
+
+/// Where grains read their audio from.
+#[derive(Debug, Clone)]
+pub enum GrainSource {
+    /// A preloaded mono sample buffer; `position` 0..1 spans the buffer.
+    Buffer(Arc<Vec<f32>>),
+    /// A circular buffer recording the input; `position` 0..1 reaches from
+    /// the newest audio back to `length_ms` ago.
+    Live { length_ms: f32 },
+}
+
+/// Grain envelope shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GrainWindow {
+    Hann,
+    Blackman,
+}
+
+/// One playing grain.
+#[derive(Debug, Clone, Default)]
+pub struct Grain {
+    pub active: bool,
+    /// Read position in source samples.
+    pub position: f64,
+    pub rate: f64,
+    pub age: usize,
+    pub length: usize,
+    pub left: f32,
+    pub right: f32,
+}
+
+/// State of a Granulator
+#[derive(Debug, Clone)]
+pub struct GranulatorState {
+    /// Live recording; empty for a buffer source.
+    pub buffer: Vec<f32>,
+    pub write: usize,
+    pub window: Vec<f32>,
+    pub grains: Vec<Grain>,
+    /// Accumulates toward the next grain onset.
+    pub spawn_phase: f32,
+    pub rng: u64,
+}
+
+/// Granular synthesizer with stereo output.
+///
+/// Grains of `grain_ms` are spawned `density` times per second at
+/// `position`, offset randomly by up to `jitter` (a fraction of the source),
+/// transposed by `pitch` semitones and panned randomly within `spread`.
+/// At most `max_grains` play at once; the pool is allocated in
+/// `init_state`. The output is scaled by the inverse square root of the
+/// expected overlap to keep the level steady as density changes.
+#[derive(Debug, Clone)]
+pub struct Granulator {
+    pub source: GrainSource,
+    pub window: GrainWindow,
+    pub grain_ms: f32,
+    pub density: f32,
+    pub position: f32,
+    pub jitter: f32,
+    pub pitch: f32,
+    pub spread: f32,
+    pub max_grains: usize,
+}
+
+impl Granulator {
+    /// Granulator with 50 ms Hann grains at 20 grains per second.
+    pub fn new(source: GrainSource) -> Self {
+        Self {
+            source,
+            window: GrainWindow::Hann,
+            grain_ms: 50.0,
+            density: 20.0,
+            position: 0.0,
+            jitter: 0.0,
+            pitch: 0.0,
+            spread: 0.0,
+            max_grains: 64,
+        }
+    }
+}
+
+fn mod_input<'a>(inputs: &[&'a [f32]], index: usize) -> &'a [f32] {
+    if inputs.len() > index {
+        inputs[index]
+    } else {
+        &[]
+    }
+}
+
+/// Linearly interpolated read; positions outside the buffer are silent.
+fn read_linear(buffer: &[f32], position: f64) -> f32 {
+    if position < 0.0 {
+        return 0.0;
+    }
+    let idx = position as usize;
+    if idx + 1 >= buffer.len() {
+        return if idx + 1 == buffer.len() {
+            buffer[idx]
+        } else {
+            0.0
+        };
+    }
+    let frac = (position - idx as f64) as f32;
+    buffer[idx] + (buffer[idx + 1] - buffer[idx]) * frac
+}
+
+impl NodeDef for Granulator {
+    type State = GranulatorState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            }, // input
+            Port {
+                id: PortId(1),
+                rate: Rate::Audio,
+            }, // density_mod
+            Port {
+                id: PortId(2),
+                rate: Rate::Audio,
+            }, // position_mod
+            Port {
+                id: PortId(3),
+                rate: Rate::Audio,
+            }, // pitch_mod
+        ];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            }, // left
+            Port {
+                id: PortId(1),
+                rate: Rate::Audio,
+            }, // right
+        ];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        0
+    }
+
+    fn init_state(&self, sample_rate: f32, _block_size: usize) -> Self::State {
+        let buffer = match self.source {
+            GrainSource::Buffer(_) => Vec::new(),
+            GrainSource::Live { length_ms } => {
+                vec![0.0; ms_to_samples(length_ms, sample_rate).max(2)]
+            }
+        };
+        let window = match self.window {
+            GrainWindow::Hann => hann_window(WINDOW_SIZE),
+            GrainWindow::Blackman => blackman_window(WINDOW_SIZE),
+        };
+        GranulatorState {
+            buffer,
+            write: 0,
+            window,
+            grains: vec![Grain::default(); self.max_grains.max(1)],
+            spawn_phase: 1.0,
+            rng: 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        sample_rate: f32,
+    ) {
+        let input = mod_input(inputs, 0);
+        let density_mod = mod_input(inputs, 1);
+        let position_mod = mod_input(inputs, 2);
+        let pitch_mod = mod_input(inputs, 3);
+        let len = outputs[0].len();
+
+        let grain_len = ms_to_samples(self.grain_ms, sample_rate).max(2);
+
+        for i in 0..len {
+            if !state.buffer.is_empty() {
+                state.buffer[state.write] = if input.is_empty() { 0.0 } else { input[i] };
+                state.write = (state.write + 1) % state.buffer.len();
+            }
+            let source: &[f32] = match &self.source {
+                GrainSource::Buffer(buffer) => buffer,
+                GrainSource::Live { .. } => &state.buffer,
+            };
+
+            let density = (self.density
+                + if density_mod.is_empty() {
+                    0.0
+                } else {
+                    density_mod[i]
+                })
+            .max(0.0);
+            state.spawn_phase += density / sample_rate;
+            if state.spawn_phase >= 1.0 && !source.is_empty() {
+                state.spawn_phase -= state.spawn_phase.floor();
+                if let Some(grain) = state.grains.iter_mut().find(|g| !g.active) {
+                    let pitch = self.pitch
+                        + if pitch_mod.is_empty() {
+                            0.0
+                        } else {
+                            pitch_mod[i]
+                        };
+                    let rate = (pitch / 12.0).exp2() as f64;
+                    let jitter =
+                        (xorshift(&mut state.rng) * 2.0 - 1.0) * self.jitter.clamp(0.0, 1.0);
+                    let position = (self.position
+                        + if position_mod.is_empty() {
+                            0.0
+                        } else {
+                            position_mod[i]
+                        }
+                        + jitter)
+                        .clamp(0.0, 1.0);
+                    let source_len = source.len() as f64;
+                    let start = match self.source {
+                        GrainSource::Buffer(_) => position as f64 * (source_len - 1.0),
+                        GrainSource::Live { .. } => {
+                            // Stay behind the write head for the whole grain
+                            let ahead = grain_len as f64 * (rate - 1.0).max(0.0) + 1.0;
+                            let delay = (position as f64 * source_len)
+                                .max(ahead)
+                                .min(source_len - 1.0);
+                            (state.write as f64 - delay).rem_euclid(source_len)
+                        }
+                    };
+                    let pan = (xorshift(&mut state.rng) * 2.0 - 1.0) * self.spread.clamp(0.0, 1.0);
+                    let angle = (pan + 1.0) * std::f32::consts::FRAC_PI_4;
+                    *grain = Grain {
+                        active: true,
+                        position: start,
+                        rate,
+                        age: 0,
+                        length: grain_len,
+                        left: angle.cos(),
+                        right: angle.sin(),
+                    };
+                }
+            }
+
+            let mut left = 0.0;
+            let mut right = 0.0;
+            let live = matches!(self.source, GrainSource::Live { .. });
+            for grain in state.grains.iter_mut().filter(|g| g.active) {
+                let progress = grain.age as f32 / grain.length as f32;
+                let env = linear_interpolate(&state.window, progress * WINDOW_SIZE as f32);
+                let position = if live {
+                    grain.position.rem_euclid(source.len() as f64)
+                } else {
+                    grain.position
+                };
+                let sample = read_linear(source, position) * env;
+                left += sample * grain.left;
+                right += sample * grain.right;
+                grain.position += grain.rate;
+                grain.age += 1;
+                if grain.age >= grain.length {
+                    grain.active = false;
+                }
+            }
+            let overlap = (density * grain_len as f32 / sample_rate).max(1.0);
+            let norm = 1.0 / overlap.sqrt();
+            outputs[0][i] = left * norm;
+            if outputs.len() > 1 {
+                outputs[1][i] = right * norm;
+            }
+        }
+    }
+}
+
+impl Parameterized for Granulator {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "grain_ms", 1.0, 1000.0, 50.0, ParamUnit::Milliseconds),
+            ParamDesc::new(1, "density", 0.0, 500.0, 20.0, ParamUnit::Hertz),
+            ParamDesc::new(2, "position", 0.0, 1.0, 0.0, ParamUnit::Normalized),
+            ParamDesc::new(3, "jitter", 0.0, 1.0, 0.0, ParamUnit::Normalized),
+            ParamDesc::new(4, "pitch", -24.0, 24.0, 0.0, ParamUnit::Semitones),
+            ParamDesc::new(5, "spread", 0.0, 1.0, 0.0, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.grain_ms),
+            1 => Some(self.density),
+            2 => Some(self.position),
+            3 => Some(self.jitter),
+            4 => Some(self.pitch),
+            5 => Some(self.spread),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.grain_ms = value,
+            1 => self.density = value,
+            2 => self.position = value,
+            3 => self.jitter = value,
+            4 => self.pitch = value,
+            5 => self.spread = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for Granulator {
+    fn name(&self) -> &'static str {
+        "Granulator"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "density_mod", 1, ParamUnit::Hertz),
+            PortDesc::modulation(2, "position_mod", 2, ParamUnit::Normalized),
+            PortDesc::modulation(3, "pitch_mod", 4, ParamUnit::Semitones),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "left"), PortDesc::audio(1, "right")];
+        PORTS
+    }
+}
@@ -1,4 +1,6 @@
+use crate::describe::{Describe, PortDesc};
 use crate::helpers::freq_to_phase_increment;
+use crate::params::{ParamDesc, ParamUnit, Parameterized};
 use auxide::graph::{Port, PortId, Rate};
 use auxide::node::NodeDef;
 
@@ -105,3 +107,48 @@ impl NodeDef for Lfo {
         }
     }
 }
+
+impl Parameterized for Lfo {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "frequency", 0.0, 100.0, 1.0, ParamUnit::Hertz),
+            ParamDesc::new(1, "amplitude", 0.0, 20000.0, 1.0, ParamUnit::None),
+            ParamDesc::new(2, "offset", -20000.0, 20000.0, 0.0, ParamUnit::None),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.frequency),
+            1 => Some(self.amplitude),
+            2 => Some(self.offset),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.frequency = value,
+            1 => self.amplitude = value,
+            2 => self.offset = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for Lfo {
+    fn name(&self) -> &'static str {
+        "Lfo"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::modulation(0, "freq_mod", 0, ParamUnit::Hertz)];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::control(0, "out", ParamUnit::None)];
+        PORTS
+    }
+}
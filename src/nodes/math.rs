@@ -0,0 +1,1189 @@
+//! Math nodes: arithmetic and function nodes for modular patching.
+//!
+//! `Add`, `Sub`, `Multiply` and `Div` take between 2 and
+//! [`MAX_MATH_INPUTS`] operands. Only the first operand is required; an
+//! unconnected operand is skipped. All nodes are stateless.
+
+use crate::describe::{Describe, PortDesc};
+use crate::params::{ParamDesc, ParamUnit, Parameterized};
+use auxide::graph::{Port, PortId, Rate};
+use auxide::node::NodeDef;
+
+/// Maximum number of operands of the N-ary math nodes.
+pub const MAX_MATH_INPUTS: usize = 8;
+
+/// Smallest divisor magnitude used by `Div`.
+const MIN_DIVISOR: f32 = 1.0e-6;
+
+/// Smallest argument used by `Ln`.
+const MIN_LN_ARG: f32 = 1.0e-10;
+
+const OPERAND_PORTS: &[Port] = &[
+    Port {
+        id: PortId(0),
+        rate: Rate::Audio,
+    },
+    Port {
+        id: PortId(1),
+        rate: Rate::Audio,
+    },
+    Port {
+        id: PortId(2),
+        rate: Rate::Audio,
+    },
+    Port {
+        id: PortId(3),
+        rate: Rate::Audio,
+    },
+    Port {
+        id: PortId(4),
+        rate: Rate::Audio,
+    },
+    Port {
+        id: PortId(5),
+        rate: Rate::Audio,
+    },
+    Port {
+        id: PortId(6),
+        rate: Rate::Audio,
+    },
+    Port {
+        id: PortId(7),
+        rate: Rate::Audio,
+    },
+];
+
+const OPERAND_DESCS: &[PortDesc] = &[
+    PortDesc::audio(0, "in0"),
+    PortDesc::audio(1, "in1"),
+    PortDesc::audio(2, "in2"),
+    PortDesc::audio(3, "in3"),
+    PortDesc::audio(4, "in4"),
+    PortDesc::audio(5, "in5"),
+    PortDesc::audio(6, "in6"),
+    PortDesc::audio(7, "in7"),
+];
+
+/// State of a math node (stateless)
+#[derive(Debug, Clone)]
+pub struct MathState;
+
+fn operand_count(inputs: usize) -> usize {
+    inputs.clamp(2, MAX_MATH_INPUTS)
+}
+
+/// Fold all connected operands into output 0 with `op`, starting from the
+/// first operand mapped through `start`.
+fn fold(
+    inputs: &[&[f32]],
+    outputs: &mut [Vec<f32>],
+    start: impl Fn(f32) -> f32,
+    op: impl Fn(f32, f32) -> f32,
+) {
+    let output = &mut outputs[0];
+    let first = &inputs[0];
+    for (out, &x) in output.iter_mut().zip(first.iter()) {
+        *out = start(x);
+    }
+    for input in inputs.iter().skip(1).filter(|x| !x.is_empty()) {
+        for i in 0..first.len() {
+            output[i] = op(output[i], input[i]);
+        }
+    }
+}
+
+/// Replace NaN and infinities with silence.
+fn finite_or_zero(x: f32) -> f32 {
+    if x.is_finite() {
+        x
+    } else {
+        0.0
+    }
+}
+
+/// Sum of all operands
+#[derive(Debug, Clone)]
+pub struct Add {
+    /// Number of operand ports, clamped to 2..=MAX_MATH_INPUTS
+    pub inputs: usize,
+}
+
+impl NodeDef for Add {
+    type State = MathState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        &OPERAND_PORTS[..operand_count(self.inputs)]
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        MathState
+    }
+
+    fn process_block(
+        &self,
+        _state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        fold(inputs, outputs, |x| x, |acc, x| acc + x);
+    }
+}
+
+impl Parameterized for Add {
+    fn params(&self) -> &'static [ParamDesc] {
+        &[]
+    }
+
+    fn get_param(&self, _id: usize) -> Option<f32> {
+        None
+    }
+
+    fn set_param(&mut self, _id: usize, _value: f32) {}
+}
+
+impl Describe for Add {
+    fn name(&self) -> &'static str {
+        "Add"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        &OPERAND_DESCS[..operand_count(self.inputs)]
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// First operand minus the others
+#[derive(Debug, Clone)]
+pub struct Sub {
+    /// Number of operand ports, clamped to 2..=MAX_MATH_INPUTS
+    pub inputs: usize,
+}
+
+impl NodeDef for Sub {
+    type State = MathState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        &OPERAND_PORTS[..operand_count(self.inputs)]
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        MathState
+    }
+
+    fn process_block(
+        &self,
+        _state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        fold(inputs, outputs, |x| x, |acc, x| acc - x);
+    }
+}
+
+impl Parameterized for Sub {
+    fn params(&self) -> &'static [ParamDesc] {
+        &[]
+    }
+
+    fn get_param(&self, _id: usize) -> Option<f32> {
+        None
+    }
+
+    fn set_param(&mut self, _id: usize, _value: f32) {}
+}
+
+impl Describe for Sub {
+    fn name(&self) -> &'static str {
+        "Sub"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        &OPERAND_DESCS[..operand_count(self.inputs)]
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// Product of all operands
+#[derive(Debug, Clone)]
+pub struct Multiply {
+    /// Number of operand ports, clamped to 2..=MAX_MATH_INPUTS
+    pub inputs: usize,
+}
+
+impl NodeDef for Multiply {
+    type State = MathState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        &OPERAND_PORTS[..operand_count(self.inputs)]
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        MathState
+    }
+
+    fn process_block(
+        &self,
+        _state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        fold(inputs, outputs, |x| x, |acc, x| acc * x);
+    }
+}
+
+impl Parameterized for Multiply {
+    fn params(&self) -> &'static [ParamDesc] {
+        &[]
+    }
+
+    fn get_param(&self, _id: usize) -> Option<f32> {
+        None
+    }
+
+    fn set_param(&mut self, _id: usize, _value: f32) {}
+}
+
+impl Describe for Multiply {
+    fn name(&self) -> &'static str {
+        "Multiply"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        &OPERAND_DESCS[..operand_count(self.inputs)]
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// First operand divided by the others
+///
+/// Divisors closer to zero than 1e-6 are pushed away from zero, and any
+/// non-finite result (NaN or infinite operands) outputs 0.
+#[derive(Debug, Clone)]
+pub struct Div {
+    /// Number of operand ports, clamped to 2..=MAX_MATH_INPUTS
+    pub inputs: usize,
+}
+
+impl NodeDef for Div {
+    type State = MathState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        &OPERAND_PORTS[..operand_count(self.inputs)]
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        MathState
+    }
+
+    fn process_block(
+        &self,
+        _state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        // A lone operand is passed through, so it is sanitized as well
+        fold(inputs, outputs, finite_or_zero, |acc, x| {
+            let divisor = if x.abs() < MIN_DIVISOR {
+                MIN_DIVISOR.copysign(x)
+            } else {
+                x
+            };
+            finite_or_zero(acc / divisor)
+        });
+    }
+}
+
+impl Parameterized for Div {
+    fn params(&self) -> &'static [ParamDesc] {
+        &[]
+    }
+
+    fn get_param(&self, _id: usize) -> Option<f32> {
+        None
+    }
+
+    fn set_param(&mut self, _id: usize, _value: f32) {}
+}
+
+impl Describe for Div {
+    fn name(&self) -> &'static str {
+        "Div"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        &OPERAND_DESCS[..operand_count(self.inputs)]
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// Multiply by a constant factor
+#[derive(Debug, Clone)]
+pub struct Scale {
+    pub factor: f32,
+}
+
+impl NodeDef for Scale {
+    type State = MathState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            }, // input
+            Port {
+                id: PortId(1),
+                rate: Rate::Audio,
+            }, // factor_mod
+        ];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        MathState
+    }
+
+    fn process_block(
+        &self,
+        _state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let factor_mod = if inputs.len() > 1 { inputs[1] } else { &[] };
+        let output = &mut outputs[0];
+
+        for i in 0..input.len() {
+            let factor = self.factor
+                + if factor_mod.is_empty() {
+                    0.0
+                } else {
+                    factor_mod[i]
+                };
+            output[i] = input[i] * factor;
+        }
+    }
+}
+
+impl Parameterized for Scale {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[ParamDesc::new(
+            0,
+            "factor",
+            -100.0,
+            100.0,
+            1.0,
+            ParamUnit::Gain,
+        )];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        (id == 0).then_some(self.factor)
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        if id == 0 {
+            self.factor = value;
+        }
+    }
+}
+
+impl Describe for Scale {
+    fn name(&self) -> &'static str {
+        "Scale"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "factor_mod", 0, ParamUnit::Gain),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// Add a constant offset
+#[derive(Debug, Clone)]
+pub struct Offset {
+    pub amount: f32,
+}
+
+impl NodeDef for Offset {
+    type State = MathState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            }, // input
+            Port {
+                id: PortId(1),
+                rate: Rate::Audio,
+            }, // amount_mod
+        ];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        MathState
+    }
+
+    fn process_block(
+        &self,
+        _state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let amount_mod = if inputs.len() > 1 { inputs[1] } else { &[] };
+        let output = &mut outputs[0];
+
+        for i in 0..input.len() {
+            let amount = self.amount
+                + if amount_mod.is_empty() {
+                    0.0
+                } else {
+                    amount_mod[i]
+                };
+            output[i] = input[i] + amount;
+        }
+    }
+}
+
+impl Parameterized for Offset {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[ParamDesc::new(
+            0,
+            "amount",
+            -100.0,
+            100.0,
+            0.0,
+            ParamUnit::None,
+        )];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        (id == 0).then_some(self.amount)
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        if id == 0 {
+            self.amount = value;
+        }
+    }
+}
+
+impl Describe for Offset {
+    fn name(&self) -> &'static str {
+        "Offset"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "amount_mod", 0, ParamUnit::None),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// Absolute value
+#[derive(Debug, Clone)]
+pub struct Abs;
+
+impl NodeDef for Abs {
+    type State = MathState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        MathState
+    }
+
+    fn process_block(
+        &self,
+        _state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let output = &mut outputs[0];
+
+        for i in 0..input.len() {
+            output[i] = input[i].abs();
+        }
+    }
+}
+
+impl Parameterized for Abs {
+    fn params(&self) -> &'static [ParamDesc] {
+        &[]
+    }
+
+    fn get_param(&self, _id: usize) -> Option<f32> {
+        None
+    }
+
+    fn set_param(&mut self, _id: usize, _value: f32) {}
+}
+
+impl Describe for Abs {
+    fn name(&self) -> &'static str {
+        "Abs"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// Natural exponential; overflow outputs 0
+#[derive(Debug, Clone)]
+pub struct Exp;
+
+impl NodeDef for Exp {
+    type State = MathState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        MathState
+    }
+
+    fn process_block(
+        &self,
+        _state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let output = &mut outputs[0];
+
+        for i in 0..input.len() {
+            output[i] = finite_or_zero(input[i].exp());
+        }
+    }
+}
+
+impl Parameterized for Exp {
+    fn params(&self) -> &'static [ParamDesc] {
+        &[]
+    }
+
+    fn get_param(&self, _id: usize) -> Option<f32> {
+        None
+    }
+
+    fn set_param(&mut self, _id: usize, _value: f32) {}
+}
+
+impl Describe for Exp {
+    fn name(&self) -> &'static str {
+        "Exp"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// Natural logarithm
+///
+/// Arguments are clamped to at least 1e-10, so zero, negative and NaN
+/// inputs output ln(1e-10) instead of -inf or NaN; an infinite input
+/// outputs 0, as for the other guarded nodes.
+#[derive(Debug, Clone)]
+pub struct Ln;
+
+impl NodeDef for Ln {
+    type State = MathState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        MathState
+    }
+
+    fn process_block(
+        &self,
+        _state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let output = &mut outputs[0];
+
+        for i in 0..input.len() {
+            output[i] = finite_or_zero(input[i].max(MIN_LN_ARG).ln());
+        }
+    }
+}
+
+impl Parameterized for Ln {
+    fn params(&self) -> &'static [ParamDesc] {
+        &[]
+    }
+
+    fn get_param(&self, _id: usize) -> Option<f32> {
+        None
+    }
+
+    fn set_param(&mut self, _id: usize, _value: f32) {}
+}
+
+impl Describe for Ln {
+    fn name(&self) -> &'static str {
+        "Ln"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// Raise the input to a power; undefined results output 0
+#[derive(Debug, Clone)]
+pub struct Pow {
+    pub exponent: f32,
+}
+
+impl NodeDef for Pow {
+    type State = MathState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            }, // input
+            Port {
+                id: PortId(1),
+                rate: Rate::Audio,
+            }, // exponent_mod
+        ];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        MathState
+    }
+
+    fn process_block(
+        &self,
+        _state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let exponent_mod = if inputs.len() > 1 { inputs[1] } else { &[] };
+        let output = &mut outputs[0];
+
+        for i in 0..input.len() {
+            let exponent = self.exponent
+                + if exponent_mod.is_empty() {
+                    0.0
+                } else {
+                    exponent_mod[i]
+                };
+            output[i] = finite_or_zero(input[i].powf(exponent));
+        }
+    }
+}
+
+impl Parameterized for Pow {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[ParamDesc::new(
+            0,
+            "exponent",
+            -8.0,
+            8.0,
+            1.0,
+            ParamUnit::None,
+        )];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        (id == 0).then_some(self.exponent)
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        if id == 0 {
+            self.exponent = value;
+        }
+    }
+}
+
+impl Describe for Pow {
+    fn name(&self) -> &'static str {
+        "Pow"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "exponent_mod", 0, ParamUnit::None),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// Sine of the input, which is in cycles (1.0 = one period)
+#[derive(Debug, Clone)]
+pub struct Sin;
+
+impl NodeDef for Sin {
+    type State = MathState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        MathState
+    }
+
+    fn process_block(
+        &self,
+        _state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let output = &mut outputs[0];
+
+        for i in 0..input.len() {
+            output[i] = (input[i] * std::f32::consts::TAU).sin();
+        }
+    }
+}
+
+impl Parameterized for Sin {
+    fn params(&self) -> &'static [ParamDesc] {
+        &[]
+    }
+
+    fn get_param(&self, _id: usize) -> Option<f32> {
+        None
+    }
+
+    fn set_param(&mut self, _id: usize, _value: f32) {}
+}
+
+impl Describe for Sin {
+    fn name(&self) -> &'static str {
+        "Sin"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// Cosine of the input, which is in cycles (1.0 = one period)
+#[derive(Debug, Clone)]
+pub struct Cos;
+
+impl NodeDef for Cos {
+    type State = MathState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        MathState
+    }
+
+    fn process_block(
+        &self,
+        _state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let output = &mut outputs[0];
+
+        for i in 0..input.len() {
+            output[i] = (input[i] * std::f32::consts::TAU).cos();
+        }
+    }
+}
+
+impl Parameterized for Cos {
+    fn params(&self) -> &'static [ParamDesc] {
+        &[]
+    }
+
+    fn get_param(&self, _id: usize) -> Option<f32> {
+        None
+    }
+
+    fn set_param(&mut self, _id: usize, _value: f32) {}
+}
+
+impl Describe for Cos {
+    fn name(&self) -> &'static str {
+        "Cos"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// Clamp the input to [min, max]
+#[derive(Debug, Clone)]
+pub struct Clamp {
+    pub min: f32,
+    pub max: f32,
+}
+
+impl NodeDef for Clamp {
+    type State = MathState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            }, // input
+            Port {
+                id: PortId(1),
+                rate: Rate::Audio,
+            }, // min_mod
+            Port {
+                id: PortId(2),
+                rate: Rate::Audio,
+            }, // max_mod
+        ];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        MathState
+    }
+
+    fn process_block(
+        &self,
+        _state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let min_mod = if inputs.len() > 1 { inputs[1] } else { &[] };
+        let max_mod = if inputs.len() > 2 { inputs[2] } else { &[] };
+        let output = &mut outputs[0];
+
+        for i in 0..input.len() {
+            let min = self.min + if min_mod.is_empty() { 0.0 } else { min_mod[i] };
+            let max = self.max + if max_mod.is_empty() { 0.0 } else { max_mod[i] };
+            output[i] = input[i].max(min).min(max);
+        }
+    }
+}
+
+impl Parameterized for Clamp {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "min", -100.0, 100.0, -1.0, ParamUnit::None),
+            ParamDesc::new(1, "max", -100.0, 100.0, 1.0, ParamUnit::None),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.min),
+            1 => Some(self.max),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.min = value,
+            1 => self.max = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for Clamp {
+    fn name(&self) -> &'static str {
+        "Clamp"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "min_mod", 0, ParamUnit::None),
+            PortDesc::modulation(2, "max_mod", 1, ParamUnit::None),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
@@ -26,3 +26,24 @@ pub use pitch::*;
 
 pub mod utility;
 pub use utility::*;
+
+pub mod analysis;
+pub use analysis::*;
+
+pub mod math;
+pub use math::*;
+
+pub mod physical;
+pub use physical::*;
+
+pub mod granular;
+pub use granular::*;
+
+pub mod sampler;
+pub use sampler::*;
+
+pub mod tape;
+pub use tape::*;
+
+pub mod dither;
+pub use dither::*;
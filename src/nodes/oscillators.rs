@@ -5,9 +5,75 @@ use std::sync::Arc;
 use auxide::graph::Port;
 use auxide::node::NodeDef;
 
-use crate::helpers::{freq_to_phase_increment, polyblep};
+use crate::describe::{Describe, PortDesc, PortRole};
+use crate::nodes::envelopes::{AdsrEnvelope, AdsrState};
+use crate::params::{ParamDesc, ParamUnit, Parameterized};
+use crate::wavetables::{MipmappedWavetable, WavetableBank};
 
 const PORTS_NONE: &[Port] = &[];
+const PORTS_OSC_IN: &[Port] = &[
+    Port {
+        id: auxide::graph::PortId(0),
+        rate: auxide::graph::Rate::Audio,
+    }, // freq_mod
+    Port {
+        id: auxide::graph::PortId(1),
+        rate: auxide::graph::Rate::Audio,
+    }, // voct
+    Port {
+        id: auxide::graph::PortId(2),
+        rate: auxide::graph::Rate::Audio,
+    }, // fm
+    Port {
+        id: auxide::graph::PortId(3),
+        rate: auxide::graph::Rate::Audio,
+    }, // pm
+    Port {
+        id: auxide::graph::PortId(4),
+        rate: auxide::graph::Rate::Audio,
+    }, // sync
+];
+const PORTS_PULSE_IN: &[Port] = &[
+    Port {
+        id: auxide::graph::PortId(0),
+        rate: auxide::graph::Rate::Audio,
+    }, // freq_mod
+    Port {
+        id: auxide::graph::PortId(1),
+        rate: auxide::graph::Rate::Audio,
+    }, // voct
+    Port {
+        id: auxide::graph::PortId(2),
+        rate: auxide::graph::Rate::Audio,
+    }, // fm
+    Port {
+        id: auxide::graph::PortId(3),
+        rate: auxide::graph::Rate::Audio,
+    }, // pm
+    Port {
+        id: auxide::graph::PortId(4),
+        rate: auxide::graph::Rate::Audio,
+    }, // sync
+    Port {
+        id: auxide::graph::PortId(5),
+        rate: auxide::graph::Rate::Audio,
+    }, // pulse_width_mod
+];
+const DESCS_OSC_IN: &[PortDesc] = &[
+    PortDesc::modulation(0, "freq_mod", 0, ParamUnit::Hertz),
+    PortDesc::control(1, "voct", ParamUnit::Octaves),
+    PortDesc::control(2, "fm", ParamUnit::None),
+    PortDesc::control(3, "pm", ParamUnit::None),
+    PortDesc::new(4, "sync", PortRole::Trigger, ParamUnit::None),
+];
+const DESCS_PULSE_IN: &[PortDesc] = &[
+    PortDesc::modulation(0, "freq_mod", 0, ParamUnit::Hertz),
+    PortDesc::control(1, "voct", ParamUnit::Octaves),
+    PortDesc::control(2, "fm", ParamUnit::None),
+    PortDesc::control(3, "pm", ParamUnit::None),
+    PortDesc::new(4, "sync", PortRole::Trigger, ParamUnit::None),
+    PortDesc::modulation(5, "pulse_width_mod", 1, ParamUnit::Normalized),
+];
 const PORTS_MONO_OUT: &[Port] = &[Port {
     id: auxide::graph::PortId(0),
     rate: auxide::graph::Rate::Audio,
@@ -35,12 +101,26 @@ pub struct PulseOsc {
     pub pulse_width: f32,
 }
 
+/// Single-cycle wavetable oscillator, band-limited through per-octave
+/// mipmaps built from `table` in `init_state`.
 #[derive(Clone)]
 pub struct WavetableOsc {
     pub freq: f32,
     pub table: Arc<Vec<f32>>,
 }
 
+/// Multi-frame wavetable oscillator that morphs between frames.
+///
+/// `position` (0..1) scans the bank's frames, `phase` offsets the read
+/// phase in cycles; its modulation input gives phase modulation.
+#[derive(Clone)]
+pub struct WavetableMorphOsc {
+    pub freq: f32,
+    pub position: f32,
+    pub phase: f32,
+    pub bank: Arc<WavetableBank>,
+}
+
 #[derive(Clone)]
 pub struct SuperSaw {
     pub freq: f32,
@@ -57,12 +137,28 @@ pub struct PinkNoise;
 #[derive(Clone)]
 pub struct BrownNoise;
 
+/// Phase accumulator of the core oscillators.
+///
+/// Every discontinuity of the waveform, including hard-sync resets, is
+/// corrected with a two-sample PolyBLEP placed at its sub-sample position.
+/// Sync resets land one sample after the trigger edge so both sides of the
+/// step can be corrected; phase modulation also lags by one sample so the
+/// read phase movement is known in advance.
+#[derive(Default)]
 pub struct OscState {
     phase: f32,
+    blep_next: f32,
+    last_pm: f32,
+    last_sync: f32,
+}
+
+pub struct WavetableState {
+    osc: OscState,
+    mipmap: MipmappedWavetable,
 }
 
 pub struct MultiPhaseState {
-    phases: Vec<f32>,
+    voices: Vec<OscState>,
 }
 
 pub struct NoiseState {
@@ -71,6 +167,173 @@ pub struct NoiseState {
     brown: f32,
 }
 
+/// Modulation inputs shared by the core oscillators.
+///
+/// Instantaneous frequency is `(freq + freq_mod) * 2^voct * (1 + fm)`, so
+/// `fm` is linear through-zero FM relative to the carrier; negative
+/// frequencies run the phase backwards. `pm` offsets the phase in cycles and
+/// a rising edge on `sync` across 0.5 restarts the cycle.
+struct OscInputs<'a> {
+    freq_mod: &'a [f32],
+    voct: &'a [f32],
+    fm: &'a [f32],
+    pm: &'a [f32],
+    sync: &'a [f32],
+}
+
+fn input<'a>(inputs: &[&'a [f32]], index: usize) -> &'a [f32] {
+    if inputs.len() > index {
+        inputs[index]
+    } else {
+        &[]
+    }
+}
+
+impl<'a> OscInputs<'a> {
+    fn new(inputs: &[&'a [f32]]) -> Self {
+        Self {
+            freq_mod: input(inputs, 0),
+            voct: input(inputs, 1),
+            fm: input(inputs, 2),
+            pm: input(inputs, 3),
+            sync: input(inputs, 4),
+        }
+    }
+
+    fn freq(&self, base: f32, i: usize) -> f32 {
+        let mut freq = base
+            + if self.freq_mod.is_empty() {
+                0.0
+            } else {
+                self.freq_mod[i]
+            };
+        if !self.voct.is_empty() {
+            freq *= self.voct[i].exp2();
+        }
+        if !self.fm.is_empty() {
+            freq *= 1.0 + self.fm[i];
+        }
+        freq
+    }
+
+    fn pm(&self, i: usize) -> f32 {
+        if self.pm.is_empty() {
+            0.0
+        } else {
+            self.pm[i]
+        }
+    }
+
+    fn sync(&self, i: usize) -> f32 {
+        if self.sync.is_empty() {
+            0.0
+        } else {
+            self.sync[i]
+        }
+    }
+}
+
+impl OscState {
+    /// Render one sample of `shape` and advance by `inc` cycles.
+    ///
+    /// `steps` lists the waveform's jumps as `(phase, height)` with the
+    /// height seen when the phase moves forward through that point.
+    fn next(
+        &mut self,
+        shape: impl Fn(f32) -> f32,
+        steps: &[(f32, f32)],
+        inc: f32,
+        pm: f32,
+        sync: f32,
+    ) -> f32 {
+        let mut out = shape(self.phase) + self.blep_next;
+        self.blep_next = 0.0;
+        let delta = inc + (pm - self.last_pm);
+        self.last_pm = pm;
+
+        let rising = self.last_sync < 0.5 && sync >= 0.5;
+        let edge = (0.5 - self.last_sync) / (sync - self.last_sync);
+        self.last_sync = sync;
+        if !rising {
+            self.scan(steps, self.phase, delta, 0.0, 1.0, &mut out);
+            self.phase = (self.phase + delta).rem_euclid(1.0);
+            return out;
+        }
+
+        let at = edge.clamp(0.0, 1.0);
+        self.scan(steps, self.phase, delta, 0.0, at, &mut out);
+        let before = (self.phase + delta * at).rem_euclid(1.0);
+        let after = pm.rem_euclid(1.0);
+        self.step(at, shape(after) - shape(before), &mut out);
+        // The restarted cycle already includes the current phase offset
+        self.scan(steps, after, inc, at, 1.0 - at, &mut out);
+        self.phase = (after + inc * (1.0 - at)).rem_euclid(1.0);
+        out
+    }
+
+    /// BLEP every waveform jump crossed moving `delta * span` from `from`,
+    /// starting `start` into the current sample interval.
+    fn scan(
+        &mut self,
+        steps: &[(f32, f32)],
+        from: f32,
+        delta: f32,
+        start: f32,
+        span: f32,
+        out: &mut f32,
+    ) {
+        let travel = delta * span;
+        for &(at, height) in steps {
+            if travel > 0.0 {
+                let dist = (at - from).rem_euclid(1.0);
+                if dist > 0.0 && dist <= travel {
+                    self.step(start + dist / delta, height, out);
+                }
+            } else if travel < 0.0 {
+                // Moving backwards leaves `at` immediately when sitting on it
+                let dist = (from - at).rem_euclid(1.0);
+                if dist < -travel {
+                    self.step(start - dist / delta, -height, out);
+                }
+            }
+        }
+    }
+
+    /// Two-sample PolyBLEP for a jump of `height` at fraction `at` (0..1)
+    /// between the current and the next sample.
+    fn step(&mut self, at: f32, height: f32, out: &mut f32) {
+        let at = at.clamp(0.0, 1.0);
+        *out += 0.5 * height * (1.0 - at) * (1.0 - at);
+        self.blep_next -= 0.5 * height * at * at;
+    }
+}
+
+fn saw_shape(phase: f32) -> f32 {
+    2.0 * phase - 1.0
+}
+
+const SAW_STEPS: &[(f32, f32)] = &[(0.0, -2.0)];
+
+/// Band-limited pulse shared by `SquareOsc` and `PulseOsc`.
+fn render_pulse(
+    state: &mut OscState,
+    freq: f32,
+    pulse_width: f32,
+    inputs: &[&[f32]],
+    out: &mut [f32],
+    sample_rate: f32,
+) {
+    let pw_mod = input(inputs, 5);
+    let inputs = OscInputs::new(inputs);
+    for (i, sample) in out.iter_mut().enumerate() {
+        let pw = (pulse_width + if pw_mod.is_empty() { 0.0 } else { pw_mod[i] }).clamp(0.01, 0.99);
+        let shape = |phase: f32| if phase < pw { 1.0 } else { -1.0 };
+        let steps = [(0.0, 2.0), (pw, -2.0)];
+        let inc = inputs.freq(freq, i) / sample_rate;
+        *sample = state.next(shape, &steps, inc, inputs.pm(i), inputs.sync(i));
+    }
+}
+
 impl SawOsc {
     pub fn new(freq: f32) -> Self {
         Self { freq }
@@ -81,7 +344,7 @@ impl NodeDef for SawOsc {
     type State = OscState;
 
     fn input_ports(&self) -> &'static [Port] {
-        PORTS_NONE
+        PORTS_OSC_IN
     }
 
     fn output_ports(&self) -> &'static [Port] {
@@ -93,38 +356,71 @@ impl NodeDef for SawOsc {
     }
 
     fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
-        OscState { phase: 0.0 }
+        OscState::default()
     }
 
     fn process_block(
         &self,
         state: &mut Self::State,
-        _inputs: &[&[f32]],
+        inputs: &[&[f32]],
         outputs: &mut [Vec<f32>],
         sample_rate: f32,
     ) {
         let Some(out) = outputs.get_mut(0) else {
             return;
         };
-        let inc = freq_to_phase_increment(self.freq, sample_rate) / (2.0 * std::f32::consts::PI);
-        for sample in out.iter_mut() {
-            let phase = state.phase;
-            *sample = 2.0 * phase - 1.0;
-            // PolyBLEP to reduce aliasing
-            *sample -= polyblep(phase, inc);
-            state.phase += inc;
-            if state.phase >= 1.0 {
-                state.phase -= 1.0;
-            }
+        let inputs = OscInputs::new(inputs);
+        for (i, sample) in out.iter_mut().enumerate() {
+            let inc = inputs.freq(self.freq, i) / sample_rate;
+            *sample = state.next(saw_shape, SAW_STEPS, inc, inputs.pm(i), inputs.sync(i));
+        }
+    }
+}
+
+impl Parameterized for SawOsc {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[ParamDesc::new(
+            0,
+            "freq",
+            0.0,
+            20000.0,
+            440.0,
+            ParamUnit::Hertz,
+        )];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        (id == 0).then_some(self.freq)
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        if id == 0 {
+            self.freq = value;
         }
     }
 }
 
+impl Describe for SawOsc {
+    fn name(&self) -> &'static str {
+        "SawOsc"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        DESCS_OSC_IN
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 impl NodeDef for SquareOsc {
     type State = OscState;
 
     fn input_ports(&self) -> &'static [Port] {
-        PORTS_NONE
+        PORTS_PULSE_IN
     }
 
     fn output_ports(&self) -> &'static [Port] {
@@ -136,43 +432,69 @@ impl NodeDef for SquareOsc {
     }
 
     fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
-        OscState { phase: 0.0 }
+        OscState::default()
     }
 
     fn process_block(
         &self,
         state: &mut Self::State,
-        _inputs: &[&[f32]],
+        inputs: &[&[f32]],
         outputs: &mut [Vec<f32>],
         sample_rate: f32,
     ) {
         let Some(out) = outputs.get_mut(0) else {
             return;
         };
-        let pw = self.pulse_width.clamp(0.01, 0.99);
-        let inc = freq_to_phase_increment(self.freq, sample_rate) / (2.0 * std::f32::consts::PI);
-        for sample in out.iter_mut() {
-            let phase = state.phase;
-            let base = if phase < pw { 1.0 } else { -1.0 };
-            // Apply polyblep at both edges
-            let mut val = base;
-            val += polyblep(phase, inc);
-            let phase_pw = (phase - pw + 1.0) % 1.0;
-            val -= polyblep(phase_pw, inc);
-            *sample = val;
-            state.phase += inc;
-            if state.phase >= 1.0 {
-                state.phase -= 1.0;
-            }
+        render_pulse(state, self.freq, self.pulse_width, inputs, out, sample_rate);
+    }
+}
+
+impl Parameterized for SquareOsc {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "freq", 0.0, 20000.0, 440.0, ParamUnit::Hertz),
+            ParamDesc::new(1, "pulse_width", 0.01, 0.99, 0.5, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.freq),
+            1 => Some(self.pulse_width),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.freq = value,
+            1 => self.pulse_width = value,
+            _ => {}
         }
     }
 }
 
+impl Describe for SquareOsc {
+    fn name(&self) -> &'static str {
+        "SquareOsc"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        DESCS_PULSE_IN
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 impl NodeDef for TriangleOsc {
     type State = OscState;
 
     fn input_ports(&self) -> &'static [Port] {
-        PORTS_NONE
+        PORTS_OSC_IN
     }
 
     fn output_ports(&self) -> &'static [Port] {
@@ -184,38 +506,76 @@ impl NodeDef for TriangleOsc {
     }
 
     fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
-        OscState { phase: 0.0 }
+        OscState::default()
     }
 
     fn process_block(
         &self,
         state: &mut Self::State,
-        _inputs: &[&[f32]],
+        inputs: &[&[f32]],
         outputs: &mut [Vec<f32>],
         sample_rate: f32,
     ) {
         let Some(out) = outputs.get_mut(0) else {
             return;
         };
-        let inc = freq_to_phase_increment(self.freq, sample_rate) / (2.0 * std::f32::consts::PI);
-        for sample in out.iter_mut() {
-            let phase = state.phase;
+        let inputs = OscInputs::new(inputs);
+        let shape = |phase: f32| {
             let saw = 2.0 * phase - 1.0;
-            *sample = (2.0 / std::f32::consts::PI)
-                * (saw.abs() * std::f32::consts::PI / 2.0 - std::f32::consts::PI / 4.0).sin();
-            state.phase += inc;
-            if state.phase >= 1.0 {
-                state.phase -= 1.0;
-            }
+            (2.0 / std::f32::consts::PI)
+                * (saw.abs() * std::f32::consts::PI / 2.0 - std::f32::consts::PI / 4.0).sin()
+        };
+        for (i, sample) in out.iter_mut().enumerate() {
+            let inc = inputs.freq(self.freq, i) / sample_rate;
+            *sample = state.next(shape, &[], inc, inputs.pm(i), inputs.sync(i));
         }
     }
 }
 
+impl Parameterized for TriangleOsc {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[ParamDesc::new(
+            0,
+            "freq",
+            0.0,
+            20000.0,
+            440.0,
+            ParamUnit::Hertz,
+        )];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        (id == 0).then_some(self.freq)
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        if id == 0 {
+            self.freq = value;
+        }
+    }
+}
+
+impl Describe for TriangleOsc {
+    fn name(&self) -> &'static str {
+        "TriangleOsc"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        DESCS_OSC_IN
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 impl NodeDef for PulseOsc {
     type State = OscState;
 
     fn input_ports(&self) -> &'static [Port] {
-        PORTS_NONE
+        PORTS_PULSE_IN
     }
 
     fn output_ports(&self) -> &'static [Port] {
@@ -227,37 +587,69 @@ impl NodeDef for PulseOsc {
     }
 
     fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
-        OscState { phase: 0.0 }
+        OscState::default()
     }
 
     fn process_block(
         &self,
         state: &mut Self::State,
-        _inputs: &[&[f32]],
+        inputs: &[&[f32]],
         outputs: &mut [Vec<f32>],
         sample_rate: f32,
     ) {
         let Some(out) = outputs.get_mut(0) else {
             return;
         };
-        let pw = self.pulse_width.clamp(0.01, 0.99);
-        let inc = freq_to_phase_increment(self.freq, sample_rate) / (2.0 * std::f32::consts::PI);
-        for sample in out.iter_mut() {
-            let phase = state.phase;
-            *sample = if phase < pw { 1.0 } else { -1.0 };
-            state.phase += inc;
-            if state.phase >= 1.0 {
-                state.phase -= 1.0;
-            }
+        render_pulse(state, self.freq, self.pulse_width, inputs, out, sample_rate);
+    }
+}
+
+impl Parameterized for PulseOsc {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "freq", 0.0, 20000.0, 440.0, ParamUnit::Hertz),
+            ParamDesc::new(1, "pulse_width", 0.01, 0.99, 0.5, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.freq),
+            1 => Some(self.pulse_width),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.freq = value,
+            1 => self.pulse_width = value,
+            _ => {}
         }
     }
 }
 
+impl Describe for PulseOsc {
+    fn name(&self) -> &'static str {
+        "PulseOsc"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        DESCS_PULSE_IN
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 impl NodeDef for WavetableOsc {
-    type State = OscState;
+    type State = WavetableState;
 
     fn input_ports(&self) -> &'static [Port] {
-        PORTS_NONE
+        PORTS_OSC_IN
     }
 
     fn output_ports(&self) -> &'static [Port] {
@@ -269,42 +661,206 @@ impl NodeDef for WavetableOsc {
     }
 
     fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
-        OscState { phase: 0.0 }
+        WavetableState {
+            osc: OscState::default(),
+            mipmap: MipmappedWavetable::new(&self.table),
+        }
     }
 
     fn process_block(
         &self,
         state: &mut Self::State,
-        _inputs: &[&[f32]],
+        inputs: &[&[f32]],
         outputs: &mut [Vec<f32>],
         sample_rate: f32,
     ) {
         let Some(out) = outputs.get_mut(0) else {
             return;
         };
-        let table = &*self.table;
-        if table.is_empty() {
+        if self.table.is_empty() {
             out.fill(0.0);
             return;
         }
-        let inc = freq_to_phase_increment(self.freq, sample_rate) / (2.0 * std::f32::consts::PI);
-        let len = table.len() as f32;
-        for sample in out.iter_mut() {
-            let idx = (state.phase * len) as usize % table.len();
-            *sample = table[idx];
-            state.phase += inc;
-            if state.phase >= 1.0 {
-                state.phase -= 1.0;
-            }
+        let inputs = OscInputs::new(inputs);
+        let mipmap = &state.mipmap;
+        for (i, sample) in out.iter_mut().enumerate() {
+            let freq = inputs.freq(self.freq, i);
+            let shape = |phase: f32| mipmap.sample(phase, freq.abs(), sample_rate);
+            *sample = state
+                .osc
+                .next(shape, &[], freq / sample_rate, inputs.pm(i), inputs.sync(i));
+        }
+    }
+}
+
+impl Parameterized for WavetableOsc {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[ParamDesc::new(
+            0,
+            "freq",
+            0.0,
+            20000.0,
+            440.0,
+            ParamUnit::Hertz,
+        )];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        (id == 0).then_some(self.freq)
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        if id == 0 {
+            self.freq = value;
+        }
+    }
+}
+
+impl Describe for WavetableOsc {
+    fn name(&self) -> &'static str {
+        "WavetableOsc"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        DESCS_OSC_IN
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+impl NodeDef for WavetableMorphOsc {
+    type State = OscState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: auxide::graph::PortId(0),
+                rate: auxide::graph::Rate::Audio,
+            }, // freq_mod
+            Port {
+                id: auxide::graph::PortId(1),
+                rate: auxide::graph::Rate::Audio,
+            }, // position_mod
+            Port {
+                id: auxide::graph::PortId(2),
+                rate: auxide::graph::Rate::Audio,
+            }, // phase_mod
+        ];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        PORTS_MONO_OUT
+    }
+
+    fn required_inputs(&self) -> usize {
+        0
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        OscState::default()
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        sample_rate: f32,
+    ) {
+        let Some(out) = outputs.get_mut(0) else {
+            return;
+        };
+        let freq_mod = if !inputs.is_empty() { inputs[0] } else { &[] };
+        let position_mod = if inputs.len() > 1 { inputs[1] } else { &[] };
+        let phase_mod = if inputs.len() > 2 { inputs[2] } else { &[] };
+
+        for (i, sample) in out.iter_mut().enumerate() {
+            let freq = self.freq
+                + if freq_mod.is_empty() {
+                    0.0
+                } else {
+                    freq_mod[i]
+                };
+            let position = self.position
+                + if position_mod.is_empty() {
+                    0.0
+                } else {
+                    position_mod[i]
+                };
+            let offset = self.phase
+                + if phase_mod.is_empty() {
+                    0.0
+                } else {
+                    phase_mod[i]
+                };
+            let read_phase = (state.phase + offset).rem_euclid(1.0);
+            *sample = self.bank.sample(position, read_phase, freq, sample_rate);
+
+            state.phase += freq / sample_rate;
+            state.phase -= state.phase.floor();
+        }
+    }
+}
+
+impl Parameterized for WavetableMorphOsc {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "freq", 0.0, 20000.0, 440.0, ParamUnit::Hertz),
+            ParamDesc::new(1, "position", 0.0, 1.0, 0.0, ParamUnit::Normalized),
+            ParamDesc::new(2, "phase", 0.0, 1.0, 0.0, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.freq),
+            1 => Some(self.position),
+            2 => Some(self.phase),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.freq = value,
+            1 => self.position = value,
+            2 => self.phase = value,
+            _ => {}
         }
     }
 }
 
+impl Describe for WavetableMorphOsc {
+    fn name(&self) -> &'static str {
+        "WavetableMorphOsc"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::modulation(0, "freq_mod", 0, ParamUnit::Hertz),
+            PortDesc::modulation(1, "position_mod", 1, ParamUnit::Normalized),
+            PortDesc::modulation(2, "phase_mod", 2, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 impl NodeDef for SuperSaw {
     type State = MultiPhaseState;
 
     fn input_ports(&self) -> &'static [Port] {
-        PORTS_NONE
+        PORTS_OSC_IN
     }
 
     fn output_ports(&self) -> &'static [Port] {
@@ -318,41 +874,79 @@ impl NodeDef for SuperSaw {
     fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
         let voices = self.voices.max(1);
         MultiPhaseState {
-            phases: vec![0.0; voices],
+            voices: (0..voices).map(|_| OscState::default()).collect(),
         }
     }
 
     fn process_block(
         &self,
         state: &mut Self::State,
-        _inputs: &[&[f32]],
+        inputs: &[&[f32]],
         outputs: &mut [Vec<f32>],
         sample_rate: f32,
     ) {
         let Some(out) = outputs.get_mut(0) else {
             return;
         };
-        let voices = state.phases.len().max(1);
-        let base_inc =
-            freq_to_phase_increment(self.freq, sample_rate) / (2.0 * std::f32::consts::PI);
+        let inputs = OscInputs::new(inputs);
+        let voices = state.voices.len().max(1);
         let detune = self.detune.max(0.0);
-        for sample in out.iter_mut() {
+        for (i, sample) in out.iter_mut().enumerate() {
+            let base_inc = inputs.freq(self.freq, i) / sample_rate;
+            let (pm, sync) = (inputs.pm(i), inputs.sync(i));
             let mut acc = 0.0;
-            for (i, phase) in state.phases.iter_mut().enumerate() {
+            // A sync trigger restarts every voice together
+            for (v, voice) in state.voices.iter_mut().enumerate() {
                 let detune_factor =
-                    1.0 + detune * ((i as f32) - (voices as f32 - 1.0) / 2.0) / (voices as f32);
-                let inc = base_inc * detune_factor;
-                acc += 2.0 * *phase - 1.0;
-                *phase += inc;
-                if *phase >= 1.0 {
-                    *phase -= 1.0;
-                }
+                    1.0 + detune * ((v as f32) - (voices as f32 - 1.0) / 2.0) / (voices as f32);
+                acc += voice.next(saw_shape, SAW_STEPS, base_inc * detune_factor, pm, sync);
             }
             *sample = acc / voices as f32;
         }
     }
 }
 
+impl Parameterized for SuperSaw {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "freq", 0.0, 20000.0, 440.0, ParamUnit::Hertz),
+            ParamDesc::new(1, "detune", 0.0, 1.0, 0.1, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.freq),
+            1 => Some(self.detune),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.freq = value,
+            1 => self.detune = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for SuperSaw {
+    fn name(&self) -> &'static str {
+        "SuperSaw"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        DESCS_OSC_IN
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 impl NodeDef for WhiteNoise {
     type State = NoiseState;
 
@@ -395,6 +989,33 @@ impl NodeDef for WhiteNoise {
     }
 }
 
+impl Parameterized for WhiteNoise {
+    fn params(&self) -> &'static [ParamDesc] {
+        &[]
+    }
+
+    fn get_param(&self, _id: usize) -> Option<f32> {
+        None
+    }
+
+    fn set_param(&mut self, _id: usize, _value: f32) {}
+}
+
+impl Describe for WhiteNoise {
+    fn name(&self) -> &'static str {
+        "WhiteNoise"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        &[]
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 impl NodeDef for PinkNoise {
     type State = NoiseState;
 
@@ -444,6 +1065,33 @@ impl NodeDef for PinkNoise {
     }
 }
 
+impl Parameterized for PinkNoise {
+    fn params(&self) -> &'static [ParamDesc] {
+        &[]
+    }
+
+    fn get_param(&self, _id: usize) -> Option<f32> {
+        None
+    }
+
+    fn set_param(&mut self, _id: usize, _value: f32) {}
+}
+
+impl Describe for PinkNoise {
+    fn name(&self) -> &'static str {
+        "PinkNoise"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        &[]
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 impl NodeDef for BrownNoise {
     type State = NoiseState;
 
@@ -487,6 +1135,33 @@ impl NodeDef for BrownNoise {
     }
 }
 
+impl Parameterized for BrownNoise {
+    fn params(&self) -> &'static [ParamDesc] {
+        &[]
+    }
+
+    fn get_param(&self, _id: usize) -> Option<f32> {
+        None
+    }
+
+    fn set_param(&mut self, _id: usize, _value: f32) {}
+}
+
+impl Describe for BrownNoise {
+    fn name(&self) -> &'static str {
+        "BrownNoise"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        &[]
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 /// State of a Constant
 #[derive(Debug, Clone)]
 pub struct ConstantState;
@@ -529,3 +1204,639 @@ impl NodeDef for Constant {
         }
     }
 }
+
+impl Parameterized for Constant {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[ParamDesc::new(
+            0,
+            "value",
+            f32::MIN,
+            f32::MAX,
+            0.0,
+            ParamUnit::None,
+        )];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        (id == 0).then_some(self.value)
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        if id == 0 {
+            self.value = value;
+        }
+    }
+}
+
+impl Describe for Constant {
+    fn name(&self) -> &'static str {
+        "Constant"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        &[]
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::control(0, "out", ParamUnit::None)];
+        PORTS
+    }
+}
+
+/// Maximum number of operators in an [`FmVoice`].
+pub const MAX_FM_OPERATORS: usize = 6;
+
+/// Operator routing of an [`FmVoice`].
+///
+/// Operators are numbered from 0 and modulators must have a higher index
+/// than the operator they modulate; other routes are dropped by [`new`].
+///
+/// [`new`]: FmAlgorithm::new
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FmAlgorithm {
+    /// Bit `j` of `modulators[i]` routes operator `j` into operator `i`.
+    pub modulators: [u8; MAX_FM_OPERATORS],
+    /// Bit `i` sends operator `i` to the output.
+    pub carriers: u8,
+}
+
+impl FmAlgorithm {
+    /// The eight 4-operator algorithms of the DX21/TX81Z family, with
+    /// operator 1 at index 0.
+    pub const FOUR_OP: [FmAlgorithm; 8] = [
+        // 4 -> 3 -> 2 -> 1
+        FmAlgorithm::new([0b0010, 0b0100, 0b1000, 0, 0, 0], 0b0001),
+        // (3 + 4) -> 2 -> 1
+        FmAlgorithm::new([0b0010, 0b1100, 0, 0, 0, 0], 0b0001),
+        // (3 -> 2) + 4 -> 1
+        FmAlgorithm::new([0b1010, 0b0100, 0, 0, 0, 0], 0b0001),
+        // (4 -> 3) + 2 -> 1
+        FmAlgorithm::new([0b0110, 0, 0b1000, 0, 0, 0], 0b0001),
+        // 2 -> 1, 4 -> 3
+        FmAlgorithm::new([0b0010, 0, 0b1000, 0, 0, 0], 0b0101),
+        // 4 -> 1, 2, 3
+        FmAlgorithm::new([0b1000, 0b1000, 0b1000, 0, 0, 0], 0b0111),
+        // 4 -> 3, 1, 2
+        FmAlgorithm::new([0, 0, 0b1000, 0, 0, 0], 0b0111),
+        // all carriers
+        FmAlgorithm::new([0; MAX_FM_OPERATORS], 0b1111),
+    ];
+
+    /// The 32 6-operator algorithms of the DX7, with operator 1 at index 0.
+    ///
+    /// The DX7's feedback loop is not part of the routing: set `feedback` on
+    /// the operator listed in [`SIX_OP_FEEDBACK`]. Algorithms 4 and 6 loop
+    /// through two or three operators on the DX7; here that loop becomes
+    /// self-feedback on operator 6.
+    ///
+    /// [`SIX_OP_FEEDBACK`]: FmAlgorithm::SIX_OP_FEEDBACK
+    pub const SIX_OP: [FmAlgorithm; 32] = [
+        // 1: 2 -> 1, 6 -> 5 -> 4 -> 3
+        FmAlgorithm::new([0b000010, 0, 0b001000, 0b010000, 0b100000, 0], 0b000101),
+        // 2: 2 -> 1, 6 -> 5 -> 4 -> 3
+        FmAlgorithm::new([0b000010, 0, 0b001000, 0b010000, 0b100000, 0], 0b000101),
+        // 3: 3 -> 2 -> 1, 6 -> 5 -> 4
+        FmAlgorithm::new([0b000010, 0b000100, 0, 0b010000, 0b100000, 0], 0b001001),
+        // 4: 3 -> 2 -> 1, 6 -> 5 -> 4
+        FmAlgorithm::new([0b000010, 0b000100, 0, 0b010000, 0b100000, 0], 0b001001),
+        // 5: 2 -> 1, 4 -> 3, 6 -> 5
+        FmAlgorithm::new([0b000010, 0, 0b001000, 0, 0b100000, 0], 0b010101),
+        // 6: 2 -> 1, 4 -> 3, 6 -> 5
+        FmAlgorithm::new([0b000010, 0, 0b001000, 0, 0b100000, 0], 0b010101),
+        // 7: 2 -> 1, (4 + (6 -> 5)) -> 3
+        FmAlgorithm::new([0b000010, 0, 0b011000, 0, 0b100000, 0], 0b000101),
+        // 8: 2 -> 1, (4 + (6 -> 5)) -> 3
+        FmAlgorithm::new([0b000010, 0, 0b011000, 0, 0b100000, 0], 0b000101),
+        // 9: 2 -> 1, (4 + (6 -> 5)) -> 3
+        FmAlgorithm::new([0b000010, 0, 0b011000, 0, 0b100000, 0], 0b000101),
+        // 10: 3 -> 2 -> 1, (5 + 6) -> 4
+        FmAlgorithm::new([0b000010, 0b000100, 0, 0b110000, 0, 0], 0b001001),
+        // 11: 3 -> 2 -> 1, (5 + 6) -> 4
+        FmAlgorithm::new([0b000010, 0b000100, 0, 0b110000, 0, 0], 0b001001),
+        // 12: 2 -> 1, (4 + 5 + 6) -> 3
+        FmAlgorithm::new([0b000010, 0, 0b111000, 0, 0, 0], 0b000101),
+        // 13: 2 -> 1, (4 + 5 + 6) -> 3
+        FmAlgorithm::new([0b000010, 0, 0b111000, 0, 0, 0], 0b000101),
+        // 14: 2 -> 1, (5 + 6) -> 4 -> 3
+        FmAlgorithm::new([0b000010, 0, 0b001000, 0b110000, 0, 0], 0b000101),
+        // 15: 2 -> 1, (5 + 6) -> 4 -> 3
+        FmAlgorithm::new([0b000010, 0, 0b001000, 0b110000, 0, 0], 0b000101),
+        // 16: (2 + (4 -> 3) + (6 -> 5)) -> 1
+        FmAlgorithm::new([0b010110, 0, 0b001000, 0, 0b100000, 0], 0b000001),
+        // 17: (2 + (4 -> 3) + (6 -> 5)) -> 1
+        FmAlgorithm::new([0b010110, 0, 0b001000, 0, 0b100000, 0], 0b000001),
+        // 18: (2 + 3 + (6 -> 5 -> 4)) -> 1
+        FmAlgorithm::new([0b001110, 0, 0, 0b010000, 0b100000, 0], 0b000001),
+        // 19: 3 -> 2 -> 1, 6 -> 4, 6 -> 5
+        FmAlgorithm::new([0b000010, 0b000100, 0, 0b100000, 0b100000, 0], 0b011001),
+        // 20: 3 -> 1, 3 -> 2, (5 + 6) -> 4
+        FmAlgorithm::new([0b000100, 0b000100, 0, 0b110000, 0, 0], 0b001011),
+        // 21: 3 -> 1, 3 -> 2, 6 -> 4, 6 -> 5
+        FmAlgorithm::new([0b000100, 0b000100, 0, 0b100000, 0b100000, 0], 0b011011),
+        // 22: 2 -> 1, 6 -> 3, 6 -> 4, 6 -> 5
+        FmAlgorithm::new([0b000010, 0, 0b100000, 0b100000, 0b100000, 0], 0b011101),
+        // 23: 1, 3 -> 2, 6 -> 4, 6 -> 5
+        FmAlgorithm::new([0, 0b000100, 0, 0b100000, 0b100000, 0], 0b011011),
+        // 24: 1, 2, 6 -> 3, 6 -> 4, 6 -> 5
+        FmAlgorithm::new([0, 0, 0b100000, 0b100000, 0b100000, 0], 0b011111),
+        // 25: 1, 2, 3, 6 -> 4, 6 -> 5
+        FmAlgorithm::new([0, 0, 0, 0b100000, 0b100000, 0], 0b011111),
+        // 26: 1, 3 -> 2, (5 + 6) -> 4
+        FmAlgorithm::new([0, 0b000100, 0, 0b110000, 0, 0], 0b001011),
+        // 27: 1, 3 -> 2, (5 + 6) -> 4
+        FmAlgorithm::new([0, 0b000100, 0, 0b110000, 0, 0], 0b001011),
+        // 28: 2 -> 1, 5 -> 4 -> 3, 6
+        FmAlgorithm::new([0b000010, 0, 0b001000, 0b010000, 0, 0], 0b100101),
+        // 29: 1, 2, 4 -> 3, 6 -> 5
+        FmAlgorithm::new([0, 0, 0b001000, 0, 0b100000, 0], 0b010111),
+        // 30: 1, 2, 5 -> 4 -> 3, 6
+        FmAlgorithm::new([0, 0, 0b001000, 0b010000, 0, 0], 0b100111),
+        // 31: 1, 2, 3, 4, 6 -> 5
+        FmAlgorithm::new([0, 0, 0, 0, 0b100000, 0], 0b011111),
+        // 32: all carriers
+        FmAlgorithm::new([0, 0, 0, 0, 0, 0], 0b111111),
+    ];
+
+    /// Index of the operator the DX7 feeds back in each of [`SIX_OP`].
+    ///
+    /// [`SIX_OP`]: FmAlgorithm::SIX_OP
+    pub const SIX_OP_FEEDBACK: [usize; 32] = [
+        5, 1, 5, 5, 5, 5, 5, 3, 1, 2, 5, 1, 5, 5, 1, 5, 1, 2, 5, 2, 2, 5, 5, 5, 5, 5, 2, 4, 5, 4,
+        5, 5,
+    ];
+
+    pub const fn new(modulators: [u8; MAX_FM_OPERATORS], carriers: u8) -> Self {
+        let mut modulators = modulators;
+        let mut i = 0;
+        while i < MAX_FM_OPERATORS {
+            modulators[i] &= !((2u8 << i) - 1);
+            i += 1;
+        }
+        Self {
+            modulators,
+            carriers,
+        }
+    }
+
+    /// Classic 4-operator algorithm by its 1-based number (clamped to 1..=8).
+    pub fn four_op(number: usize) -> Self {
+        Self::FOUR_OP[number.clamp(1, 8) - 1]
+    }
+
+    /// Classic 6-operator algorithm by its 1-based DX7 number (clamped to 1..=32).
+    pub fn six_op(number: usize) -> Self {
+        Self::SIX_OP[number.clamp(1, 32) - 1]
+    }
+
+    /// Every operator modulates the next lower one; operator 0 is the carrier.
+    pub fn stack(operators: usize) -> Self {
+        let mut modulators = [0; MAX_FM_OPERATORS];
+        for (i, m) in modulators
+            .iter_mut()
+            .enumerate()
+            .take(operators.min(MAX_FM_OPERATORS).saturating_sub(1))
+        {
+            *m = 1 << (i + 1);
+        }
+        Self::new(modulators, 0b1)
+    }
+}
+
+/// One sine operator of an [`FmVoice`].
+#[derive(Debug, Clone)]
+pub struct FmOperator {
+    /// Frequency as a multiple of the voice frequency.
+    pub ratio: f32,
+    /// Fixed frequency in Hz, used instead of `ratio` when set.
+    pub fixed_freq: Option<f32>,
+    /// Output level; as a modulator this is the modulation index in radians.
+    pub level: f32,
+    /// Self-modulation amount in radians.
+    pub feedback: f32,
+    pub envelope: AdsrEnvelope,
+}
+
+impl FmOperator {
+    /// Operator at `ratio` with a full-sustain envelope and no feedback.
+    pub fn new(ratio: f32, level: f32) -> Self {
+        Self {
+            ratio,
+            fixed_freq: None,
+            level,
+            feedback: 0.0,
+            envelope: AdsrEnvelope {
+                attack_ms: 1.0,
+                decay_ms: 100.0,
+                sustain_level: 1.0,
+                release_ms: 100.0,
+                curve: 0.0,
+            },
+        }
+    }
+}
+
+/// Phase-modulation synthesis voice with [`MAX_FM_OPERATORS`] sine
+/// operators routed by an [`FmAlgorithm`].
+///
+/// Operators the algorithm leaves unrouted are silent, so 4-operator
+/// algorithms only use the first four. Each operator runs its own ADSR from
+/// the `gate` input; with no gate connected the voice is held on. Carrier
+/// outputs are averaged.
+#[derive(Debug, Clone)]
+pub struct FmVoice {
+    pub freq: f32,
+    pub algorithm: FmAlgorithm,
+    pub operators: [FmOperator; MAX_FM_OPERATORS],
+}
+
+impl FmVoice {
+    /// Voice with every operator at ratio 1 and full level.
+    pub fn new(freq: f32, algorithm: FmAlgorithm) -> Self {
+        Self {
+            freq,
+            algorithm,
+            operators: std::array::from_fn(|_| FmOperator::new(1.0, 1.0)),
+        }
+    }
+}
+
+pub struct FmOperatorState {
+    phase: f32,
+    /// Last two outputs, averaged for feedback.
+    history: [f32; 2],
+    envelope: AdsrState,
+}
+
+pub struct FmVoiceState {
+    operators: [FmOperatorState; MAX_FM_OPERATORS],
+}
+
+impl NodeDef for FmVoice {
+    type State = FmVoiceState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: auxide::graph::PortId(0),
+                rate: auxide::graph::Rate::Audio,
+            }, // freq_mod
+            Port {
+                id: auxide::graph::PortId(1),
+                rate: auxide::graph::Rate::Audio,
+            }, // voct
+            Port {
+                id: auxide::graph::PortId(2),
+                rate: auxide::graph::Rate::Audio,
+            }, // gate
+        ];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        PORTS_MONO_OUT
+    }
+
+    fn required_inputs(&self) -> usize {
+        0
+    }
+
+    fn init_state(&self, sample_rate: f32, block_size: usize) -> Self::State {
+        FmVoiceState {
+            operators: std::array::from_fn(|i| FmOperatorState {
+                phase: 0.0,
+                history: [0.0; 2],
+                envelope: self.operators[i]
+                    .envelope
+                    .init_state(sample_rate, block_size),
+            }),
+        }
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        sample_rate: f32,
+    ) {
+        let Some(out) = outputs.get_mut(0) else {
+            return;
+        };
+        let pitch = OscInputs::new(inputs);
+        let gate = input(inputs, 2);
+        let carriers = (self.algorithm.carriers & 0b11_1111).count_ones().max(1) as f32;
+        let dt = 1.0 / sample_rate;
+
+        for (i, sample) in out.iter_mut().enumerate() {
+            let freq = pitch.freq(self.freq, i);
+            let gate_on = gate.is_empty() || gate[i] > 0.5;
+            let mut outs = [0.0f32; MAX_FM_OPERATORS];
+            let mut mix = 0.0;
+            // Modulators have higher indices, so render from the top down
+            for k in (0..MAX_FM_OPERATORS).rev() {
+                let op = &self.operators[k];
+                let op_state = &mut state.operators[k];
+                let level = op.envelope.tick(&mut op_state.envelope, gate_on, dt);
+
+                let mut modulation =
+                    op.feedback * 0.5 * (op_state.history[0] + op_state.history[1]);
+                for (j, &m) in outs.iter().enumerate().skip(k + 1) {
+                    if self.algorithm.modulators[k] & (1 << j) != 0 {
+                        modulation += m;
+                    }
+                }
+                let value = op.level
+                    * level
+                    * (2.0 * std::f32::consts::PI * op_state.phase + modulation).sin();
+                outs[k] = value;
+                op_state.history = [value, op_state.history[0]];
+                if self.algorithm.carriers & (1 << k) != 0 {
+                    mix += value;
+                }
+
+                let op_freq = op.fixed_freq.unwrap_or(freq * op.ratio);
+                op_state.phase = (op_state.phase + op_freq / sample_rate).rem_euclid(1.0);
+            }
+            *sample = mix / carriers;
+        }
+    }
+}
+
+impl Parameterized for FmVoice {
+    /// `freq`, then eight parameters per operator (`op1_ratio` ..
+    /// `op6_release_ms`).
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "freq", 0.0, 20000.0, 440.0, ParamUnit::Hertz),
+            ParamDesc::new(1, "op1_ratio", 0.0, 32.0, 1.0, ParamUnit::Ratio),
+            ParamDesc::new(2, "op1_fixed_freq", 0.0, 20000.0, 0.0, ParamUnit::Hertz),
+            ParamDesc::new(3, "op1_level", 0.0, 10.0, 1.0, ParamUnit::Gain),
+            ParamDesc::new(4, "op1_feedback", 0.0, 4.0, 0.0, ParamUnit::None),
+            ParamDesc::new(
+                5,
+                "op1_attack_ms",
+                0.0,
+                10000.0,
+                1.0,
+                ParamUnit::Milliseconds,
+            ),
+            ParamDesc::new(
+                6,
+                "op1_decay_ms",
+                0.0,
+                10000.0,
+                100.0,
+                ParamUnit::Milliseconds,
+            ),
+            ParamDesc::new(7, "op1_sustain_level", 0.0, 1.0, 1.0, ParamUnit::Normalized),
+            ParamDesc::new(
+                8,
+                "op1_release_ms",
+                0.0,
+                10000.0,
+                100.0,
+                ParamUnit::Milliseconds,
+            ),
+            ParamDesc::new(9, "op2_ratio", 0.0, 32.0, 1.0, ParamUnit::Ratio),
+            ParamDesc::new(10, "op2_fixed_freq", 0.0, 20000.0, 0.0, ParamUnit::Hertz),
+            ParamDesc::new(11, "op2_level", 0.0, 10.0, 1.0, ParamUnit::Gain),
+            ParamDesc::new(12, "op2_feedback", 0.0, 4.0, 0.0, ParamUnit::None),
+            ParamDesc::new(
+                13,
+                "op2_attack_ms",
+                0.0,
+                10000.0,
+                1.0,
+                ParamUnit::Milliseconds,
+            ),
+            ParamDesc::new(
+                14,
+                "op2_decay_ms",
+                0.0,
+                10000.0,
+                100.0,
+                ParamUnit::Milliseconds,
+            ),
+            ParamDesc::new(
+                15,
+                "op2_sustain_level",
+                0.0,
+                1.0,
+                1.0,
+                ParamUnit::Normalized,
+            ),
+            ParamDesc::new(
+                16,
+                "op2_release_ms",
+                0.0,
+                10000.0,
+                100.0,
+                ParamUnit::Milliseconds,
+            ),
+            ParamDesc::new(17, "op3_ratio", 0.0, 32.0, 1.0, ParamUnit::Ratio),
+            ParamDesc::new(18, "op3_fixed_freq", 0.0, 20000.0, 0.0, ParamUnit::Hertz),
+            ParamDesc::new(19, "op3_level", 0.0, 10.0, 1.0, ParamUnit::Gain),
+            ParamDesc::new(20, "op3_feedback", 0.0, 4.0, 0.0, ParamUnit::None),
+            ParamDesc::new(
+                21,
+                "op3_attack_ms",
+                0.0,
+                10000.0,
+                1.0,
+                ParamUnit::Milliseconds,
+            ),
+            ParamDesc::new(
+                22,
+                "op3_decay_ms",
+                0.0,
+                10000.0,
+                100.0,
+                ParamUnit::Milliseconds,
+            ),
+            ParamDesc::new(
+                23,
+                "op3_sustain_level",
+                0.0,
+                1.0,
+                1.0,
+                ParamUnit::Normalized,
+            ),
+            ParamDesc::new(
+                24,
+                "op3_release_ms",
+                0.0,
+                10000.0,
+                100.0,
+                ParamUnit::Milliseconds,
+            ),
+            ParamDesc::new(25, "op4_ratio", 0.0, 32.0, 1.0, ParamUnit::Ratio),
+            ParamDesc::new(26, "op4_fixed_freq", 0.0, 20000.0, 0.0, ParamUnit::Hertz),
+            ParamDesc::new(27, "op4_level", 0.0, 10.0, 1.0, ParamUnit::Gain),
+            ParamDesc::new(28, "op4_feedback", 0.0, 4.0, 0.0, ParamUnit::None),
+            ParamDesc::new(
+                29,
+                "op4_attack_ms",
+                0.0,
+                10000.0,
+                1.0,
+                ParamUnit::Milliseconds,
+            ),
+            ParamDesc::new(
+                30,
+                "op4_decay_ms",
+                0.0,
+                10000.0,
+                100.0,
+                ParamUnit::Milliseconds,
+            ),
+            ParamDesc::new(
+                31,
+                "op4_sustain_level",
+                0.0,
+                1.0,
+                1.0,
+                ParamUnit::Normalized,
+            ),
+            ParamDesc::new(
+                32,
+                "op4_release_ms",
+                0.0,
+                10000.0,
+                100.0,
+                ParamUnit::Milliseconds,
+            ),
+            ParamDesc::new(33, "op5_ratio", 0.0, 32.0, 1.0, ParamUnit::Ratio),
+            ParamDesc::new(34, "op5_fixed_freq", 0.0, 20000.0, 0.0, ParamUnit::Hertz),
+            ParamDesc::new(35, "op5_level", 0.0, 10.0, 1.0, ParamUnit::Gain),
+            ParamDesc::new(36, "op5_feedback", 0.0, 4.0, 0.0, ParamUnit::None),
+            ParamDesc::new(
+                37,
+                "op5_attack_ms",
+                0.0,
+                10000.0,
+                1.0,
+                ParamUnit::Milliseconds,
+            ),
+            ParamDesc::new(
+                38,
+                "op5_decay_ms",
+                0.0,
+                10000.0,
+                100.0,
+                ParamUnit::Milliseconds,
+            ),
+            ParamDesc::new(
+                39,
+                "op5_sustain_level",
+                0.0,
+                1.0,
+                1.0,
+                ParamUnit::Normalized,
+            ),
+            ParamDesc::new(
+                40,
+                "op5_release_ms",
+                0.0,
+                10000.0,
+                100.0,
+                ParamUnit::Milliseconds,
+            ),
+            ParamDesc::new(41, "op6_ratio", 0.0, 32.0, 1.0, ParamUnit::Ratio),
+            ParamDesc::new(42, "op6_fixed_freq", 0.0, 20000.0, 0.0, ParamUnit::Hertz),
+            ParamDesc::new(43, "op6_level", 0.0, 10.0, 1.0, ParamUnit::Gain),
+            ParamDesc::new(44, "op6_feedback", 0.0, 4.0, 0.0, ParamUnit::None),
+            ParamDesc::new(
+                45,
+                "op6_attack_ms",
+                0.0,
+                10000.0,
+                1.0,
+                ParamUnit::Milliseconds,
+            ),
+            ParamDesc::new(
+                46,
+                "op6_decay_ms",
+                0.0,
+                10000.0,
+                100.0,
+                ParamUnit::Milliseconds,
+            ),
+            ParamDesc::new(
+                47,
+                "op6_sustain_level",
+                0.0,
+                1.0,
+                1.0,
+                ParamUnit::Normalized,
+            ),
+            ParamDesc::new(
+                48,
+                "op6_release_ms",
+                0.0,
+                10000.0,
+                100.0,
+                ParamUnit::Milliseconds,
+            ),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        if id == 0 {
+            return Some(self.freq);
+        }
+        let op = self.operators.get((id - 1) / 8)?;
+        match (id - 1) % 8 {
+            0 => Some(op.ratio),
+            1 => Some(op.fixed_freq.unwrap_or(0.0)),
+            2 => Some(op.level),
+            3 => Some(op.feedback),
+            4 => Some(op.envelope.attack_ms),
+            5 => Some(op.envelope.decay_ms),
+            6 => Some(op.envelope.sustain_level),
+            _ => Some(op.envelope.release_ms),
+        }
+    }
+
+    /// A `fixed_freq` of zero or below switches the operator back to `ratio`.
+    fn set_param(&mut self, id: usize, value: f32) {
+        if id == 0 {
+            self.freq = value;
+            return;
+        }
+        let Some(op) = self.operators.get_mut((id - 1) / 8) else {
+            return;
+        };
+        match (id - 1) % 8 {
+            0 => op.ratio = value,
+            1 => op.fixed_freq = (value > 0.0).then_some(value),
+            2 => op.level = value,
+            3 => op.feedback = value,
+            4 => op.envelope.attack_ms = value,
+            5 => op.envelope.decay_ms = value,
+            6 => op.envelope.sustain_level = value,
+            _ => op.envelope.release_ms = value,
+        }
+    }
+}
+
+impl Describe for FmVoice {
+    fn name(&self) -> &'static str {
+        "FmVoice"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::modulation(0, "freq_mod", 0, ParamUnit::Hertz),
+            PortDesc::control(1, "voct", ParamUnit::Octaves),
+            PortDesc::new(2, "gate", PortRole::Gate, ParamUnit::None),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
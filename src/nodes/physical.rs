@@ -0,0 +1,885 @@
+//! Physical modelling voices: tuned delay-line loops and a modal resonator
+//! bank.
+//!
+//! Delay-line loop tuning accounts for the phase delay of every filter in
+//! the loop at the fundamental; the remaining fraction of a sample is
+//! realised by a first-order allpass whose coefficient is solved exactly
+//! for that frequency, so the pitch is exact rather than approximated at DC.
+
+use crate::describe::{Describe, PortDesc, PortRole};
+use crate::helpers::xorshift;
+use crate::params::{ParamDesc, ParamUnit, Parameterized};
+use auxide::graph::{Port, PortId, Rate};
+use auxide::node::NodeDef;
+
+/// Lowest pitch the delay lines are sized for.
+const MIN_FREQ: f32 = 20.0;
+
+/// Pole of the DC blocker on the `KarplusStrong` output.
+const DC_BLOCK: f32 = 0.995;
+
+/// Number of dispersion allpasses used by `KarplusStrong::stretch`.
+const STRETCH_STAGES: usize = 4;
+
+/// Samples between coefficient updates of a modulated `ModalResonator`.
+const MODAL_CONTROL_INTERVAL: usize = 32;
+
+const VOICE_PORTS: &[Port] = &[
+    Port {
+        id: PortId(0),
+        rate: Rate::Audio,
+    }, // excitation
+    Port {
+        id: PortId(1),
+        rate: Rate::Audio,
+    }, // trigger / gate
+    Port {
+        id: PortId(2),
+        rate: Rate::Audio,
+    }, // freq_mod
+    Port {
+        id: PortId(3),
+        rate: Rate::Audio,
+    }, // voct
+];
+
+const MONO_OUT: &[Port] = &[Port {
+    id: PortId(0),
+    rate: Rate::Audio,
+}];
+
+fn input<'a>(inputs: &[&'a [f32]], index: usize) -> &'a [f32] {
+    if inputs.len() > index {
+        inputs[index]
+    } else {
+        &[]
+    }
+}
+
+/// Pitch from the base frequency and the `freq_mod`/`voct` inputs.
+fn pitch(base: f32, freq_mod: &[f32], voct: &[f32], i: usize, sample_rate: f32) -> f32 {
+    let mut freq = base
+        + if freq_mod.is_empty() {
+            0.0
+        } else {
+            freq_mod[i]
+        };
+    if !voct.is_empty() {
+        freq *= voct[i].exp2();
+    }
+    freq.clamp(MIN_FREQ, sample_rate / 4.0)
+}
+
+/// Phase delay in samples of the one-pole lowpass `y = (1 - a) x + a y[-1]`,
+/// evaluated at `z = radius * e^(j omega)`.
+fn one_pole_delay(a: f32, omega: f32, radius: f32) -> f32 {
+    let a = a / radius;
+    (a * omega.sin()).atan2(1.0 - a * omega.cos()) / omega
+}
+
+/// Magnitude of the one-pole lowpass at `omega`.
+fn one_pole_gain(a: f32, omega: f32) -> f32 {
+    (1.0 - a) / (1.0 - 2.0 * a * omega.cos() + a * a).sqrt()
+}
+
+/// Phase delay in samples of the allpass `(c + z^-1) / (1 + c z^-1)`,
+/// evaluated at `z = radius * e^(j omega)`.
+fn allpass_delay(c: f32, omega: f32, radius: f32) -> f32 {
+    let (sin, cos) = omega.sin_cos();
+    (sin.atan2(c * radius + cos) - (c * sin).atan2(radius + c * cos)) / omega
+}
+
+/// Delay line with a fractional length realised by a tuning allpass.
+#[derive(Debug, Clone)]
+pub struct TunedDelay {
+    buffer: Vec<f32>,
+    write: usize,
+    length: usize,
+    coef: f32,
+    x1: f32,
+    y1: f32,
+}
+
+impl TunedDelay {
+    fn new(capacity: usize) -> Self {
+        Self {
+            buffer: vec![0.0; capacity.max(4)],
+            write: 0,
+            length: 1,
+            coef: 0.0,
+            x1: 0.0,
+            y1: 0.0,
+        }
+    }
+
+    /// Set the delay in samples, exact in phase at `omega` radians/sample.
+    fn tune(&mut self, delay: f32, omega: f32) {
+        let max = (self.buffer.len() - 1) as f32;
+        // Keep the allpass fraction within 0.5..1.5 where it is well behaved
+        let delay = delay.clamp(1.5, max + 0.5);
+        self.length = ((delay - 0.5).floor() as usize).clamp(1, self.buffer.len() - 1);
+        let frac = delay - self.length as f32;
+        self.coef = (omega * (1.0 - frac) * 0.5).sin() / (omega * (1.0 + frac) * 0.5).sin();
+    }
+
+    fn read(&mut self) -> f32 {
+        let len = self.buffer.len();
+        let x = self.buffer[(self.write + len - self.length) % len];
+        let y = self.coef * x + self.x1 - self.coef * self.y1;
+        self.x1 = x;
+        self.y1 = y;
+        y
+    }
+
+    fn write(&mut self, x: f32) {
+        self.buffer[self.write] = x;
+        self.write = (self.write + 1) % self.buffer.len();
+    }
+}
+
+/// State of a KarplusStrong string
+#[derive(Debug, Clone)]
+pub struct KarplusStrongState {
+    pub delay: TunedDelay,
+    /// Recent excitation, for the pick-position comb.
+    pub excitation: Vec<f32>,
+    pub excitation_index: usize,
+    pub lowpass: f32,
+    pub dispersion: [(f32, f32); STRETCH_STAGES],
+    /// DC blocker on the output, as the loop holds any DC of the pluck.
+    pub dc_x1: f32,
+    pub dc_y1: f32,
+    pub burst: usize,
+    pub last_trigger: f32,
+    pub rng: u64,
+    pub tuned_freq: f32,
+    pub damping: f32,
+    pub stretch: f32,
+    pub gain: f32,
+    pub pick_delay: usize,
+}
+
+/// Karplus-Strong plucked string.
+///
+/// A rising edge on `trigger` plucks the string with one period of the
+/// `excitation` input, or of internal noise when nothing is connected. With
+/// no trigger connected the excitation feeds the string continuously.
+/// `damping` darkens the tone over time, `decay_ms` is the time for the
+/// fundamental to fall by 60 dB, `stretch` adds stiffness that sharpens the
+/// upper partials and `pick_position` (0..0.5 of the string, 0 = off) combs
+/// the excitation like plucking away from the bridge.
+#[derive(Debug, Clone)]
+pub struct KarplusStrong {
+    pub freq: f32,
+    pub damping: f32,
+    pub decay_ms: f32,
+    pub stretch: f32,
+    pub pick_position: f32,
+}
+
+impl KarplusStrong {
+    fn retune(&self, state: &mut KarplusStrongState, freq: f32, sample_rate: f32) {
+        let omega = std::f32::consts::TAU * freq / sample_rate;
+        let period = sample_rate / freq;
+        let damping = self.damping.clamp(0.0, 1.0) * 0.9;
+        let stretch = -0.5 * self.stretch.clamp(0.0, 1.0);
+
+        // Loop gain that gives the requested decay at the fundamental; heavy
+        // damping can make the actual decay faster
+        let periods = freq * self.decay_ms.max(1.0) / 1000.0;
+        let lowpass_gain = one_pole_gain(damping, omega);
+        state.gain = (10f32.powf(-3.0 / periods) / lowpass_gain).min(0.99999);
+
+        // A decaying mode sits inside the unit circle, where the filters'
+        // phase delay differs; tune for the mode's actual pole radius
+        let radius = (state.gain * lowpass_gain).powf(1.0 / period);
+        let filters = one_pole_delay(damping, omega, radius)
+            + STRETCH_STAGES as f32 * allpass_delay(stretch, omega, radius);
+        state.delay.tune(period - filters, omega);
+        state.damping = damping;
+        state.stretch = stretch;
+        state.pick_delay = ((self.pick_position.clamp(0.0, 0.5) * period).round() as usize)
+            .min(state.excitation.len() - 1);
+        state.tuned_freq = freq;
+    }
+}
+
+impl NodeDef for KarplusStrong {
+    type State = KarplusStrongState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        VOICE_PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        MONO_OUT
+    }
+
+    fn required_inputs(&self) -> usize {
+        0
+    }
+
+    fn init_state(&self, sample_rate: f32, _block_size: usize) -> Self::State {
+        let capacity = (sample_rate / MIN_FREQ) as usize + 4;
+        KarplusStrongState {
+            delay: TunedDelay::new(capacity),
+            excitation: vec![0.0; capacity],
+            excitation_index: 0,
+            lowpass: 0.0,
+            dispersion: [(0.0, 0.0); STRETCH_STAGES],
+            dc_x1: 0.0,
+            dc_y1: 0.0,
+            burst: 0,
+            last_trigger: 0.0,
+            rng: 0x2545_F491_4F6C_DD1D,
+            tuned_freq: 0.0,
+            damping: 0.0,
+            stretch: 0.0,
+            gain: 0.0,
+            pick_delay: 0,
+        }
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        sample_rate: f32,
+    ) {
+        let Some(out) = outputs.get_mut(0) else {
+            return;
+        };
+        let excitation = input(inputs, 0);
+        let trigger = input(inputs, 1);
+        let freq_mod = input(inputs, 2);
+        let voct = input(inputs, 3);
+
+        // Parameters may have changed since the last block
+        state.tuned_freq = 0.0;
+        for (i, sample) in out.iter_mut().enumerate() {
+            let freq = pitch(self.freq, freq_mod, voct, i, sample_rate);
+            if freq != state.tuned_freq {
+                self.retune(state, freq, sample_rate);
+            }
+
+            let exc = if trigger.is_empty() {
+                if excitation.is_empty() {
+                    0.0
+                } else {
+                    excitation[i]
+                }
+            } else {
+                if trigger[i] > 0.5 && state.last_trigger <= 0.5 {
+                    state.burst = (sample_rate / freq).round() as usize;
+                }
+                state.last_trigger = trigger[i];
+                if state.burst > 0 {
+                    state.burst -= 1;
+                    if excitation.is_empty() {
+                        xorshift(&mut state.rng) * 2.0 - 1.0
+                    } else {
+                        excitation[i]
+                    }
+                } else {
+                    0.0
+                }
+            };
+            let len = state.excitation.len();
+            state.excitation[state.excitation_index] = exc;
+            let picked = if state.pick_delay == 0 {
+                exc
+            } else {
+                exc - state.excitation[(state.excitation_index + len - state.pick_delay) % len]
+            };
+            state.excitation_index = (state.excitation_index + 1) % len;
+
+            let delayed = state.delay.read();
+            state.lowpass = (1.0 - state.damping) * delayed + state.damping * state.lowpass;
+            let mut x = state.lowpass;
+            for (x1, y1) in state.dispersion.iter_mut() {
+                let y = state.stretch * x + *x1 - state.stretch * *y1;
+                *x1 = x;
+                *y1 = y;
+                x = y;
+            }
+            let y = x * state.gain + picked;
+            state.delay.write(y);
+            state.dc_y1 = y - state.dc_x1 + DC_BLOCK * state.dc_y1;
+            state.dc_x1 = y;
+            *sample = state.dc_y1;
+        }
+    }
+}
+
+impl Parameterized for KarplusStrong {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "freq", MIN_FREQ, 5000.0, 220.0, ParamUnit::Hertz),
+            ParamDesc::new(1, "damping", 0.0, 1.0, 0.5, ParamUnit::Normalized),
+            ParamDesc::new(2, "decay_ms", 1.0, 30000.0, 2000.0, ParamUnit::Milliseconds),
+            ParamDesc::new(3, "stretch", 0.0, 1.0, 0.0, ParamUnit::Normalized),
+            ParamDesc::new(4, "pick_position", 0.0, 0.5, 0.0, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.freq),
+            1 => Some(self.damping),
+            2 => Some(self.decay_ms),
+            3 => Some(self.stretch),
+            4 => Some(self.pick_position),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.freq = value,
+            1 => self.damping = value,
+            2 => self.decay_ms = value,
+            3 => self.stretch = value,
+            4 => self.pick_position = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for KarplusStrong {
+    fn name(&self) -> &'static str {
+        "KarplusStrong"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "excitation"),
+            PortDesc::new(1, "trigger", PortRole::Trigger, ParamUnit::None),
+            PortDesc::modulation(2, "freq_mod", 0, ParamUnit::Hertz),
+            PortDesc::control(3, "voct", ParamUnit::Octaves),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// Excitation mechanism of a [`Waveguide`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaveguideModel {
+    /// Bowed string: stick-slip bow friction at `position` along the string.
+    Bowed,
+    /// Reed-blown pipe: a closed bore driven through a reed, sounding odd
+    /// harmonics.
+    Blown,
+}
+
+/// State of a Waveguide
+#[derive(Debug, Clone)]
+pub struct WaveguideState {
+    /// Bridge side of the bow, or the whole bore when blown.
+    pub bridge: TunedDelay,
+    pub neck: TunedDelay,
+    pub lowpass: f32,
+    /// Smoothed bow velocity or breath pressure.
+    pub level: f32,
+    pub tuned_freq: f32,
+    pub damping: f32,
+}
+
+/// Sustained bowed or blown waveguide instrument.
+///
+/// While `gate` is high the bow velocity or breath pressure rises to a level
+/// set by `pressure`; with no gate connected the voice is held on. The
+/// `excitation` input is added to the bow velocity or breath, so noise gives
+/// breathy or scratchy tones and any audio can drive the model.
+#[derive(Debug, Clone)]
+pub struct Waveguide {
+    pub model: WaveguideModel,
+    pub freq: f32,
+    pub pressure: f32,
+    pub position: f32,
+    pub damping: f32,
+}
+
+/// Loop gain of the waveguide reflections.
+const WAVEGUIDE_LOSS: f32 = 0.95;
+
+impl Waveguide {
+    fn retune(&self, state: &mut WaveguideState, freq: f32, sample_rate: f32) {
+        let omega = std::f32::consts::TAU * freq / sample_rate;
+        let damping = self.damping.clamp(0.0, 1.0) * 0.9;
+        let filter = one_pole_delay(damping, omega, 1.0);
+        match self.model {
+            WaveguideModel::Bowed => {
+                let length = sample_rate / freq - filter;
+                let beta = self.position.clamp(0.05, 0.5);
+                state.bridge.tune(length * beta, omega);
+                state.neck.tune(length * (1.0 - beta), omega);
+            }
+            WaveguideModel::Blown => {
+                // The inverting reflection makes the period twice the bore
+                state.bridge.tune(sample_rate / freq * 0.5 - filter, omega);
+            }
+        }
+        state.damping = damping;
+        state.tuned_freq = freq;
+    }
+}
+
+/// Bow friction: reflection coefficient for a bow/string velocity difference.
+fn bow_table(delta: f32, slope: f32) -> f32 {
+    ((delta * slope).abs() + 0.75).powi(-4).clamp(0.01, 0.98)
+}
+
+/// Reed reflection coefficient for a pressure difference.
+fn reed_table(delta: f32) -> f32 {
+    (0.7 - 0.3 * delta).clamp(-1.0, 1.0)
+}
+
+impl NodeDef for Waveguide {
+    type State = WaveguideState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        VOICE_PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        MONO_OUT
+    }
+
+    fn required_inputs(&self) -> usize {
+        0
+    }
+
+    fn init_state(&self, sample_rate: f32, _block_size: usize) -> Self::State {
+        let capacity = (sample_rate / MIN_FREQ) as usize + 4;
+        WaveguideState {
+            bridge: TunedDelay::new(capacity),
+            neck: TunedDelay::new(capacity),
+            lowpass: 0.0,
+            level: 0.0,
+            tuned_freq: 0.0,
+            damping: 0.0,
+        }
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        sample_rate: f32,
+    ) {
+        let Some(out) = outputs.get_mut(0) else {
+            return;
+        };
+        let excitation = input(inputs, 0);
+        let gate = input(inputs, 1);
+        let freq_mod = input(inputs, 2);
+        let voct = input(inputs, 3);
+
+        let pressure = self.pressure.clamp(0.0, 1.0);
+        let attack = crate::helpers::compute_exponential_coefficient(5.0, sample_rate);
+        let release = crate::helpers::compute_exponential_coefficient(30.0, sample_rate);
+        let (drive, slope) = match self.model {
+            WaveguideModel::Bowed => (0.03 + 0.2 * pressure, 5.0 - 4.0 * pressure),
+            WaveguideModel::Blown => (0.55 + 0.3 * pressure, 0.0),
+        };
+
+        // Parameters may have changed since the last block
+        state.tuned_freq = 0.0;
+        for (i, sample) in out.iter_mut().enumerate() {
+            let freq = pitch(self.freq, freq_mod, voct, i, sample_rate);
+            if freq != state.tuned_freq {
+                self.retune(state, freq, sample_rate);
+            }
+
+            let gate_on = gate.is_empty() || gate[i] > 0.5;
+            let (target, coef) = if gate_on {
+                (drive, attack)
+            } else {
+                (0.0, release)
+            };
+            state.level = target + (state.level - target) * coef;
+            let exc = if excitation.is_empty() {
+                0.0
+            } else {
+                excitation[i]
+            };
+
+            *sample = match self.model {
+                WaveguideModel::Bowed => {
+                    let bow = state.level + exc;
+                    let bridge_out = state.bridge.read();
+                    state.lowpass =
+                        (1.0 - state.damping) * bridge_out + state.damping * state.lowpass;
+                    let bridge_reflection = -WAVEGUIDE_LOSS * state.lowpass;
+                    let nut_reflection = -state.neck.read();
+                    let delta = bow - (bridge_reflection + nut_reflection);
+                    let velocity = delta * bow_table(delta, slope);
+                    state.neck.write(bridge_reflection + velocity);
+                    state.bridge.write(nut_reflection + velocity);
+                    bridge_out * 2.0
+                }
+                WaveguideModel::Blown => {
+                    let breath = state.level + exc;
+                    let bore_out = state.bridge.read();
+                    state.lowpass =
+                        (1.0 - state.damping) * bore_out + state.damping * state.lowpass;
+                    let delta = -WAVEGUIDE_LOSS * state.lowpass - breath;
+                    state.bridge.write(breath + delta * reed_table(delta));
+                    bore_out
+                }
+            };
+        }
+    }
+}
+
+impl Parameterized for Waveguide {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "freq", MIN_FREQ, 5000.0, 220.0, ParamUnit::Hertz),
+            ParamDesc::new(1, "pressure", 0.0, 1.0, 0.5, ParamUnit::Normalized),
+            ParamDesc::new(2, "position", 0.05, 0.5, 0.127, ParamUnit::Normalized),
+            ParamDesc::new(3, "damping", 0.0, 1.0, 0.5, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.freq),
+            1 => Some(self.pressure),
+            2 => Some(self.position),
+            3 => Some(self.damping),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.freq = value,
+            1 => self.pressure = value,
+            2 => self.position = value,
+            3 => self.damping = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for Waveguide {
+    fn name(&self) -> &'static str {
+        "Waveguide"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "excitation"),
+            PortDesc::new(1, "gate", PortRole::Gate, ParamUnit::None),
+            PortDesc::modulation(2, "freq_mod", 0, ParamUnit::Hertz),
+            PortDesc::control(3, "voct", ParamUnit::Octaves),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// One mode of a [`ModalResonator`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mode {
+    /// Frequency as a multiple of the resonator's base frequency.
+    pub ratio: f32,
+    /// Time to decay by 60 dB.
+    pub decay_ms: f32,
+    pub gain: f32,
+}
+
+const fn mode(ratio: f32, decay_ms: f32, gain: f32) -> Mode {
+    Mode {
+        ratio,
+        decay_ms,
+        gain,
+    }
+}
+
+/// Built-in mode sets for [`ModalResonator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModalPreset {
+    /// Free-free uniform bar (marimba, xylophone without tuning cuts).
+    Bar,
+    /// Church bell partials from the hum note up.
+    Bell,
+    /// Ideal circular membrane.
+    Membrane,
+    /// Wine glass, few and long-ringing modes.
+    Glass,
+}
+
+impl ModalPreset {
+    pub fn modes(self) -> &'static [Mode] {
+        match self {
+            ModalPreset::Bar => {
+                const MODES: &[Mode] = &[
+                    mode(1.0, 1500.0, 1.0),
+                    mode(2.756, 1000.0, 0.6),
+                    mode(5.404, 700.0, 0.4),
+                    mode(8.933, 500.0, 0.25),
+                    mode(13.344, 350.0, 0.15),
+                    mode(18.64, 250.0, 0.1),
+                ];
+                MODES
+            }
+            ModalPreset::Bell => {
+                const MODES: &[Mode] = &[
+                    mode(0.5, 6000.0, 0.5),
+                    mode(1.0, 4500.0, 1.0),
+                    mode(1.183, 4000.0, 0.8),
+                    mode(1.506, 3000.0, 0.5),
+                    mode(2.0, 2500.0, 0.7),
+                    mode(2.514, 2000.0, 0.4),
+                    mode(2.662, 1800.0, 0.35),
+                    mode(3.011, 1500.0, 0.3),
+                    mode(4.166, 1000.0, 0.2),
+                    mode(5.433, 700.0, 0.12),
+                ];
+                MODES
+            }
+            ModalPreset::Membrane => {
+                const MODES: &[Mode] = &[
+                    mode(1.0, 500.0, 1.0),
+                    mode(1.594, 400.0, 0.8),
+                    mode(2.136, 320.0, 0.6),
+                    mode(2.296, 300.0, 0.55),
+                    mode(2.653, 260.0, 0.45),
+                    mode(2.918, 230.0, 0.4),
+                    mode(3.156, 200.0, 0.35),
+                    mode(3.501, 180.0, 0.3),
+                    mode(3.6, 170.0, 0.25),
+                    mode(4.06, 150.0, 0.2),
+                ];
+                MODES
+            }
+            ModalPreset::Glass => {
+                const MODES: &[Mode] = &[
+                    mode(1.0, 4000.0, 1.0),
+                    mode(2.32, 3200.0, 0.5),
+                    mode(4.25, 2500.0, 0.3),
+                    mode(6.63, 1800.0, 0.2),
+                    mode(9.38, 1200.0, 0.12),
+                ];
+                MODES
+            }
+        }
+    }
+}
+
+/// Two-pole resonator of one mode.
+#[derive(Debug, Clone, Default)]
+pub struct ModeState {
+    pub b0: f32,
+    pub a1: f32,
+    pub a2: f32,
+    pub y1: f32,
+    pub y2: f32,
+}
+
+/// State of a ModalResonator
+#[derive(Debug, Clone)]
+pub struct ModalState {
+    pub modes: Vec<ModeState>,
+    /// Base frequency and damping the coefficients were computed for.
+    pub freq: f32,
+    pub damping: f32,
+    /// Samples until the modulation inputs are read again.
+    pub countdown: usize,
+}
+
+/// Modal synthesis: a bank of tuned two-pole resonators driven by the
+/// `excitation` input.
+///
+/// Each mode rings at `freq * ratio` for its `decay_ms`, scaled down by
+/// `damping` (0..1), which shortens higher modes more. Modes at or above
+/// Nyquist are muted. Coefficients are computed in `init_state`; the
+/// frequency and damping are then read at control rate, every 32 samples,
+/// and the coefficients recomputed only when either has changed.
+#[derive(Debug, Clone)]
+pub struct ModalResonator {
+    pub freq: f32,
+    pub damping: f32,
+    pub modes: Vec<Mode>,
+}
+
+impl ModalResonator {
+    pub fn new(freq: f32, preset: ModalPreset) -> Self {
+        Self {
+            freq,
+            damping: 0.0,
+            modes: preset.modes().to_vec(),
+        }
+    }
+
+    fn compute(&self, state: &mut ModalState, freq: f32, damping: f32, sample_rate: f32) {
+        let damping = damping.clamp(0.0, 1.0);
+        for (m, s) in self.modes.iter().zip(state.modes.iter_mut()) {
+            let f = freq * m.ratio;
+            if f <= 0.0 || f >= sample_rate * 0.49 {
+                s.b0 = 0.0;
+                s.a1 = 0.0;
+                s.a2 = 0.0;
+                continue;
+            }
+            let theta = std::f32::consts::TAU * f / sample_rate;
+            // Damping shortens the decay, higher modes more
+            let decay = m.decay_ms * (1.0 - 0.99 * damping).powf(1.0 + m.ratio.max(1.0).log2());
+            let r = 10f32.powf(-3.0 / (decay.max(1.0) / 1000.0 * sample_rate));
+            // Impulse response peaks near `gain`
+            s.b0 = m.gain * theta.sin();
+            s.a1 = 2.0 * r * theta.cos();
+            s.a2 = -r * r;
+        }
+        state.freq = freq;
+        state.damping = damping;
+    }
+}
+
+impl NodeDef for ModalResonator {
+    type State = ModalState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            }, // excitation
+            Port {
+                id: PortId(1),
+                rate: Rate::Audio,
+            }, // freq_mod
+            Port {
+                id: PortId(2),
+                rate: Rate::Audio,
+            }, // damping_mod
+        ];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        MONO_OUT
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, sample_rate: f32, _block_size: usize) -> Self::State {
+        let mut state = ModalState {
+            modes: vec![ModeState::default(); self.modes.len()],
+            freq: 0.0,
+            damping: 0.0,
+            countdown: 0,
+        };
+        self.compute(&mut state, self.freq, self.damping, sample_rate);
+        state
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        sample_rate: f32,
+    ) {
+        let excitation = inputs[0];
+        let freq_mod = input(inputs, 1);
+        let damping_mod = input(inputs, 2);
+        let output = &mut outputs[0];
+
+        for i in 0..excitation.len() {
+            if state.countdown == 0 {
+                state.countdown = MODAL_CONTROL_INTERVAL;
+                let freq = self.freq
+                    + if freq_mod.is_empty() {
+                        0.0
+                    } else {
+                        freq_mod[i]
+                    };
+                let damping = (self.damping
+                    + if damping_mod.is_empty() {
+                        0.0
+                    } else {
+                        damping_mod[i]
+                    })
+                .clamp(0.0, 1.0);
+                if freq != state.freq || damping != state.damping {
+                    self.compute(state, freq, damping, sample_rate);
+                }
+            }
+            state.countdown -= 1;
+
+            let x = excitation[i];
+            let mut y = 0.0;
+            for m in state.modes.iter_mut() {
+                let out = m.b0 * x + m.a1 * m.y1 + m.a2 * m.y2;
+                m.y2 = m.y1;
+                m.y1 = out;
+                y += out;
+            }
+            output[i] = y;
+        }
+    }
+}
+
+impl Parameterized for ModalResonator {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "freq", 20.0, 10000.0, 440.0, ParamUnit::Hertz),
+            ParamDesc::new(1, "damping", 0.0, 1.0, 0.0, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.freq),
+            1 => Some(self.damping),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.freq = value,
+            1 => self.damping = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for ModalResonator {
+    fn name(&self) -> &'static str {
+        "ModalResonator"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "excitation"),
+            PortDesc::modulation(1, "freq_mod", 0, ParamUnit::Hertz),
+            PortDesc::modulation(2, "damping_mod", 1, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
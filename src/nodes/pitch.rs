@@ -1,3 +1,7 @@
+use crate::describe::{Describe, PortDesc};
+use crate::helpers::{Interpolation, Interpolator};
+use crate::nodes::fx::FractionalDelay;
+use crate::params::{ParamDesc, ParamUnit, Parameterized};
 use auxide::graph::{Port, PortId, Rate};
 use auxide::node::NodeDef;
 
@@ -7,9 +11,13 @@ pub struct PitchShifterState {
     pub buffer: Vec<f32>,
     pub index: usize,
     pub phase: f32,
+    pub interpolator: Interpolator,
 }
 
 /// Pitch Shifter (simple delay-based)
+///
+/// The fractional delay is read linearly; see [`FractionalDelay`] for
+/// other kernels.
 #[derive(Debug, Clone)]
 pub struct PitchShifter {
     pub shift: f32, // semitones
@@ -49,13 +57,8 @@ impl NodeDef for PitchShifter {
         1
     }
 
-    fn init_state(&self, sample_rate: f32, _block_size: usize) -> Self::State {
-        let max_delay = (sample_rate / 50.0) as usize; // up to 20ms
-        PitchShifterState {
-            buffer: vec![0.0; max_delay],
-            index: 0,
-            phase: 0.0,
-        }
+    fn init_state(&self, sample_rate: f32, block_size: usize) -> Self::State {
+        self.init_state_with(Interpolation::Linear, sample_rate, block_size)
     }
 
     fn process_block(
@@ -69,6 +72,8 @@ impl NodeDef for PitchShifter {
         let shift_mod = if inputs.len() > 1 { inputs[1] } else { &[] };
         let mix_mod = if inputs.len() > 2 { inputs[2] } else { &[] };
         let output = &mut outputs[0];
+        let half_taps = (state.interpolator.kind().taps() / 2) as f32;
+        let max_delay = (state.buffer.len() - 1) as f32 - half_taps;
 
         for i in 0..input.len() {
             let shift = self.shift
@@ -80,12 +85,11 @@ impl NodeDef for PitchShifter {
             let mix = self.mix + if mix_mod.is_empty() { 0.0 } else { mix_mod[i] };
 
             let ratio = 2.0_f32.powf(shift / 12.0);
-            let delay_samples = (sample_rate / 440.0 / ratio) as usize; // approximate for A4
+            let delay_samples = (sample_rate / 440.0 / ratio).clamp(half_taps, max_delay); // approximate for A4
 
-            let delayed_idx = (state.index + state.buffer.len()
-                - delay_samples.min(state.buffer.len() - 1))
-                % state.buffer.len();
-            let delayed = state.buffer[delayed_idx];
+            let delayed = state
+                .interpolator
+                .read_delay(&state.buffer, state.index, delay_samples);
 
             output[i] = input[i] * (1.0 - mix) + delayed * mix;
 
@@ -95,6 +99,69 @@ impl NodeDef for PitchShifter {
     }
 }
 
+impl FractionalDelay for PitchShifter {
+    fn init_state_with(
+        &self,
+        interpolation: Interpolation,
+        sample_rate: f32,
+        _block_size: usize,
+    ) -> Self::State {
+        let max_delay = (sample_rate / 50.0) as usize; // up to 20ms
+        PitchShifterState {
+            buffer: vec![0.0; max_delay + interpolation.taps()],
+            index: 0,
+            phase: 0.0,
+            interpolator: Interpolator::new(interpolation),
+        }
+    }
+}
+
+impl Parameterized for PitchShifter {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "shift", -24.0, 24.0, 0.0, ParamUnit::Semitones),
+            ParamDesc::new(1, "mix", 0.0, 1.0, 1.0, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.shift),
+            1 => Some(self.mix),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.shift = value,
+            1 => self.mix = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for PitchShifter {
+    fn name(&self) -> &'static str {
+        "PitchShifter"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "shift_mod", 0, ParamUnit::Semitones),
+            PortDesc::modulation(2, "mix_mod", 1, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 /// State of a Spectral Gate
 #[derive(Debug, Clone)]
 pub struct SpectralGateState {
@@ -172,6 +239,48 @@ impl NodeDef for SpectralGate {
     }
 }
 
+impl Parameterized for SpectralGate {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "threshold", 0.0, 1.0, 0.1, ParamUnit::Gain),
+            ParamDesc::new(1, "ratio", 1.0, 100.0, 10.0, ParamUnit::Ratio),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.threshold),
+            1 => Some(self.ratio),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.threshold = value,
+            1 => self.ratio = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for SpectralGate {
+    fn name(&self) -> &'static str {
+        "SpectralGate"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 /// State of a Pitch Detector
 #[derive(Debug, Clone)]
 pub struct PitchDetectorState {
@@ -239,3 +348,31 @@ impl NodeDef for PitchDetector {
         }
     }
 }
+
+impl Parameterized for PitchDetector {
+    fn params(&self) -> &'static [ParamDesc] {
+        &[]
+    }
+
+    fn get_param(&self, _id: usize) -> Option<f32> {
+        None
+    }
+
+    fn set_param(&mut self, _id: usize, _value: f32) {}
+}
+
+impl Describe for PitchDetector {
+    fn name(&self) -> &'static str {
+        "PitchDetector"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::control(0, "pitch", ParamUnit::Hertz)];
+        PORTS
+    }
+}
@@ -0,0 +1,352 @@
+//! Sample playback with loop points and pitch tracking.
+
+use std::sync::Arc;
+
+use crate::describe::{Describe, PortDesc, PortRole};
+use crate::helpers::Interpolator;
+use crate::params::{ParamDesc, ParamUnit, Parameterized};
+use crate::wav::WavData;
+use auxide::graph::{Port, PortId, Rate};
+use auxide::node::NodeDef;
+
+pub use crate::helpers::Interpolation;
+
+/// Samples gathered around each read, enough for any `Interpolation`.
+const READ_TAPS: usize = 8;
+
+/// How playback behaves at the loop points while the gate is held.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoopMode {
+    /// Play once to the end of the sample.
+    Off,
+    /// Jump from the loop end back to the loop start.
+    Forward,
+    /// Bounce between the loop points.
+    PingPong,
+    /// Turn around at the loop end, then play the loop backwards.
+    Reverse,
+}
+
+/// State of a SamplePlayer
+#[derive(Debug, Clone)]
+pub struct SamplePlayerState {
+    /// Read position in frames.
+    pub position: f64,
+    /// 1 playing forwards, -1 backwards.
+    pub direction: f64,
+    pub playing: bool,
+    pub last_gate: f32,
+    pub interpolator: Interpolator,
+}
+
+/// Sampler voice over a mono or interleaved stereo buffer.
+///
+/// A rising edge on `gate` starts playback at `start` (0..1 of the sample);
+/// with no gate connected playback starts at once and the gate counts as
+/// held. While the gate is held the loop between `loop_start` and
+/// `loop_end` (in frames) repeats according to `loop_mode`; releasing it
+/// plays on to the edge of the sample. Forward and reverse loops crossfade
+/// over `crossfade` frames across the loop seam, using audio outside the
+/// loop, so the fade is shortened when the loop sits near the sample edge.
+///
+/// The playback rate is `ratio` scaled by `freq / root_freq` when the
+/// `freq` input is connected and positive, and corrected for the sample's
+/// own `sample_rate`. Mono samples are sent to both outputs.
+#[derive(Debug, Clone)]
+pub struct SamplePlayer {
+    pub sample: Arc<[f32]>,
+    pub channels: usize,
+    pub sample_rate: f32,
+    pub root_freq: f32,
+    pub ratio: f32,
+    pub start: f32,
+    pub loop_mode: LoopMode,
+    pub loop_start: usize,
+    pub loop_end: usize,
+    pub crossfade: usize,
+    pub interpolation: Interpolation,
+}
+
+impl SamplePlayer {
+    /// One-shot player over the whole sample with cubic interpolation.
+    pub fn new(sample: Arc<[f32]>, channels: usize, sample_rate: f32) -> Self {
+        let channels = channels.clamp(1, 2);
+        let frames = sample.len() / channels;
+        Self {
+            sample,
+            channels,
+            sample_rate,
+            root_freq: 440.0,
+            ratio: 1.0,
+            start: 0.0,
+            loop_mode: LoopMode::Off,
+            loop_start: 0,
+            loop_end: frames,
+            crossfade: 0,
+            interpolation: Interpolation::Cubic,
+        }
+    }
+
+    /// Player for decoded WAV audio; more than two channels are mixed to mono.
+    pub fn from_wav(wav: &WavData) -> Self {
+        let (samples, channels) = if wav.channels <= 2 {
+            (wav.samples.clone(), wav.channels.max(1) as usize)
+        } else {
+            (wav.to_mono(), 1)
+        };
+        Self::new(samples.into(), channels, wav.sample_rate as f32)
+    }
+
+    /// Number of sample frames.
+    pub fn frames(&self) -> usize {
+        self.sample.len() / self.channel_count()
+    }
+
+    /// `channels` clamped to 1..=2, as in `new`.
+    fn channel_count(&self) -> usize {
+        self.channels.clamp(1, 2)
+    }
+
+    fn frame(&self, channel: usize, index: i64) -> f32 {
+        if index < 0 || index as usize >= self.frames() {
+            0.0
+        } else {
+            self.sample[index as usize * self.channel_count() + channel]
+        }
+    }
+
+    /// Interpolated read of one channel; outside the sample is silent.
+    fn read(&self, interpolator: &Interpolator, channel: usize, position: f64) -> f32 {
+        let base = position.floor();
+        let mut taps = [0.0; READ_TAPS];
+        for (k, tap) in taps.iter_mut().enumerate() {
+            *tap = self.frame(channel, base as i64 + k as i64 - 3);
+        }
+        interpolator.read_at(&taps, 3, (position - base) as f32)
+    }
+
+    /// Loop points clamped to the sample, or None when there is no loop.
+    fn loop_points(&self) -> Option<(f64, f64)> {
+        let end = self.loop_end.min(self.frames());
+        (self.loop_mode != LoopMode::Off && end >= self.loop_start + 2)
+            .then_some((self.loop_start as f64, end as f64))
+    }
+
+    /// Read one channel, crossfading across the loop seam.
+    fn read_looped(&self, state: &SamplePlayerState, channel: usize, sustain: bool) -> f32 {
+        let pos = state.position;
+        let dry = self.read(&state.interpolator, channel, pos);
+        let Some((start, end)) = self.loop_points().filter(|_| sustain) else {
+            return dry;
+        };
+        let len = end - start;
+        match self.loop_mode {
+            LoopMode::Forward => {
+                let xf = (self.crossfade as f64).min(start).min(len);
+                if xf > 0.0 && pos >= end - xf && pos < end {
+                    let t = ((pos - (end - xf)) / xf) as f32;
+                    return dry + (self.read(&state.interpolator, channel, pos - len) - dry) * t;
+                }
+            }
+            LoopMode::Reverse if state.direction < 0.0 => {
+                let tail = self.frames() as f64 - end;
+                let xf = (self.crossfade as f64).min(tail).min(len);
+                if xf > 0.0 && pos >= start && pos < start + xf {
+                    let t = ((start + xf - pos) / xf) as f32;
+                    return dry + (self.read(&state.interpolator, channel, pos + len) - dry) * t;
+                }
+            }
+            _ => {}
+        }
+        dry
+    }
+
+    /// Move the read position one step, applying the loop while sustained.
+    fn advance(&self, state: &mut SamplePlayerState, rate: f64, sustain: bool) {
+        let prev = state.position;
+        state.position += state.direction * rate;
+        if let Some((start, end)) = self.loop_points().filter(|_| sustain) {
+            let len = end - start;
+            let pos = state.position;
+            let forward_over = state.direction > 0.0 && prev < end && pos >= end;
+            let backward_under = state.direction < 0.0 && prev >= start && pos < start;
+            match self.loop_mode {
+                LoopMode::Forward if forward_over => {
+                    state.position = start + (pos - end) % len;
+                }
+                LoopMode::PingPong | LoopMode::Reverse if forward_over => {
+                    state.position = (end - (pos - end) % len).max(start);
+                    state.direction = -1.0;
+                }
+                LoopMode::PingPong if backward_under => {
+                    state.position = (start + (start - pos) % len).min(end);
+                    state.direction = 1.0;
+                }
+                LoopMode::Reverse if backward_under => {
+                    state.position = end - (start - pos) % len;
+                }
+                _ => {}
+            }
+        }
+        if state.position < 0.0 || state.position >= self.frames() as f64 {
+            state.playing = false;
+        }
+    }
+
+    fn restart(&self, state: &mut SamplePlayerState) {
+        state.position = self.start.clamp(0.0, 1.0) as f64 * self.frames().saturating_sub(1) as f64;
+        state.direction = 1.0;
+        state.playing = self.frames() > 0;
+    }
+}
+
+impl NodeDef for SamplePlayer {
+    type State = SamplePlayerState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            }, // gate
+            Port {
+                id: PortId(1),
+                rate: Rate::Audio,
+            }, // freq
+            Port {
+                id: PortId(2),
+                rate: Rate::Audio,
+            }, // ratio_mod
+        ];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            }, // left
+            Port {
+                id: PortId(1),
+                rate: Rate::Audio,
+            }, // right
+        ];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        0
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        SamplePlayerState {
+            position: 0.0,
+            direction: 1.0,
+            playing: false,
+            last_gate: 0.0,
+            interpolator: Interpolator::new(self.interpolation),
+        }
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        sample_rate: f32,
+    ) {
+        let gate = if !inputs.is_empty() { inputs[0] } else { &[] };
+        let freq = if inputs.len() > 1 { inputs[1] } else { &[] };
+        let ratio_mod = if inputs.len() > 2 { inputs[2] } else { &[] };
+        let len = outputs[0].len();
+        let base_rate = (self.sample_rate / sample_rate) as f64;
+
+        for i in 0..len {
+            let g = if gate.is_empty() { 1.0 } else { gate[i] };
+            let sustain = g > 0.5;
+            if sustain && state.last_gate <= 0.5 {
+                self.restart(state);
+            }
+            state.last_gate = g;
+            if !state.playing {
+                for out in outputs.iter_mut().take(2) {
+                    out[i] = 0.0;
+                }
+                continue;
+            }
+
+            let left = self.read_looped(state, 0, sustain);
+            let right = if self.channel_count() > 1 {
+                self.read_looped(state, 1, sustain)
+            } else {
+                left
+            };
+            outputs[0][i] = left;
+            if outputs.len() > 1 {
+                outputs[1][i] = right;
+            }
+
+            let mut ratio = self.ratio
+                + if ratio_mod.is_empty() {
+                    0.0
+                } else {
+                    ratio_mod[i]
+                };
+            if !freq.is_empty() && freq[i] > 0.0 && self.root_freq > 0.0 {
+                ratio *= freq[i] / self.root_freq;
+            }
+            let rate = base_rate * ratio.clamp(0.0, 64.0) as f64;
+            self.advance(state, rate, sustain);
+        }
+    }
+}
+
+impl Parameterized for SamplePlayer {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "ratio", 0.0, 16.0, 1.0, ParamUnit::None),
+            ParamDesc::new(1, "start", 0.0, 1.0, 0.0, ParamUnit::Normalized),
+            ParamDesc::new(2, "root_freq", 8.0, 20000.0, 440.0, ParamUnit::Hertz),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.ratio),
+            1 => Some(self.start),
+            2 => Some(self.root_freq),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.ratio = value,
+            1 => self.start = value,
+            2 => self.root_freq = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for SamplePlayer {
+    fn name(&self) -> &'static str {
+        "SamplePlayer"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::new(0, "gate", PortRole::Gate, ParamUnit::None),
+            PortDesc::control(1, "freq", ParamUnit::Hertz),
+            PortDesc::modulation(2, "ratio_mod", 0, ParamUnit::None),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "left"), PortDesc::audio(1, "right")];
+        PORTS
+    }
+}
@@ -1,9 +1,486 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::describe::{Describe, PortDesc};
+use crate::helpers::{Interpolation, Interpolator};
+use crate::params::{ParamDesc, ParamUnit, Parameterized};
 use auxide::graph::{Port, PortId, Rate};
 use auxide::node::NodeDef;
 
+/// Below this spacing between curve inputs the antiderivative differences
+/// are ill-conditioned and the curve is evaluated directly instead.
+const ADAA_TOLERANCE: f64 = 1e-4;
+
+/// Antiderivative anti-aliasing for the static shaping curves, selected
+/// with [`AdaaShaper::with_anti_alias`].
+///
+/// The first order averages the curve between consecutive inputs and adds
+/// half a sample of delay to the shaped signal; the second order averages
+/// twice and adds a full sample. Both attenuate the top of the band a little.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AntiAlias {
+    #[default]
+    Off,
+    FirstOrder,
+    SecondOrder,
+}
+
+/// A shaper whose static curve can run with antiderivative anti-aliasing.
+///
+/// `init_state` evaluates the curve directly; [`with_anti_alias`] picks an
+/// order.
+///
+/// [`with_anti_alias`]: AdaaShaper::with_anti_alias
+pub trait AdaaShaper: NodeDef {
+    /// State whose curve runs with `anti_alias`.
+    fn init_state_with(
+        &self,
+        anti_alias: AntiAlias,
+        sample_rate: f32,
+        block_size: usize,
+    ) -> Self::State;
+
+    /// Wraps the node so its curve runs with `anti_alias`.
+    fn with_anti_alias(self, anti_alias: AntiAlias) -> AntiAliased<Self>
+    where
+        Self: Sized,
+    {
+        AntiAliased::new(self, anti_alias)
+    }
+}
+
+/// Runs an [`AdaaShaper`] node with a chosen anti-aliasing order.
+#[derive(Debug, Clone)]
+pub struct AntiAliased<T> {
+    pub node: T,
+    pub anti_alias: AntiAlias,
+}
+
+impl<T: AdaaShaper> AntiAliased<T> {
+    pub fn new(node: T, anti_alias: AntiAlias) -> Self {
+        Self { node, anti_alias }
+    }
+}
+
+impl<T: AdaaShaper> NodeDef for AntiAliased<T> {
+    type State = T::State;
+
+    fn input_ports(&self) -> &'static [Port] {
+        self.node.input_ports()
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        self.node.output_ports()
+    }
+
+    fn required_inputs(&self) -> usize {
+        self.node.required_inputs()
+    }
+
+    fn init_state(&self, sample_rate: f32, block_size: usize) -> Self::State {
+        self.node
+            .init_state_with(self.anti_alias, sample_rate, block_size)
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        sample_rate: f32,
+    ) {
+        self.node.process_block(state, inputs, outputs, sample_rate)
+    }
+}
+
+impl<T: AdaaShaper + Parameterized> Parameterized for AntiAliased<T> {
+    fn params(&self) -> &'static [ParamDesc] {
+        self.node.params()
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        self.node.get_param(id)
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        self.node.set_param(id, value)
+    }
+}
+
+impl<T: AdaaShaper + Describe> Describe for AntiAliased<T> {
+    fn name(&self) -> &'static str {
+        self.node.name()
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        self.node.inputs()
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        self.node.outputs()
+    }
+}
+
+/// A static curve with its first and second antiderivatives.
+trait Curve {
+    fn f(&self, x: f64) -> f64;
+    fn f1(&self, x: f64) -> f64;
+    fn f2(&self, x: f64) -> f64;
+}
+
+/// Antiderivative anti-aliasing history: the previous two curve inputs and
+/// the antiderivative terms already evaluated there, so each sample only
+/// evaluates the curve at the new input.
+///
+/// The cached terms come from the curve as it was on the previous sample,
+/// which under per-sample parameter modulation is a small approximation.
+#[derive(Debug, Clone, Default)]
+pub struct AdaaState {
+    /// Order the curve runs with.
+    pub anti_alias: AntiAlias,
+    pub x1: f64,
+    pub x2: f64,
+    /// F1 at `x1`, kept while running first order.
+    f1: f64,
+    /// F2 at `x1`, kept while running second order.
+    f2: f64,
+    /// Divided difference of F2 between `x2` and `x1`.
+    slope: f64,
+    /// Mode the cached terms were computed for.
+    mode: AntiAlias,
+}
+
+impl AdaaState {
+    pub fn new(anti_alias: AntiAlias) -> Self {
+        Self {
+            anti_alias,
+            ..Self::default()
+        }
+    }
+
+    /// Shape `x0`, the newest curve input, and push it into the history.
+    fn shape<C: Curve>(&mut self, curve: &C, x0: f64) -> f32 {
+        let (mode, x1, x2) = (self.anti_alias, self.x1, self.x2);
+        if mode != self.mode {
+            // Switching modes: fill the terms this mode reads
+            match mode {
+                AntiAlias::Off => {}
+                AntiAlias::FirstOrder => self.f1 = curve.f1(x1),
+                AntiAlias::SecondOrder => {
+                    self.f2 = curve.f2(x1);
+                    self.slope = f2_slope(curve, x1, x2, self.f2, curve.f2(x2));
+                }
+            }
+            self.mode = mode;
+        }
+        let y = match mode {
+            AntiAlias::Off => curve.f(x0),
+            AntiAlias::FirstOrder => {
+                let f1 = curve.f1(x0);
+                let dx = x0 - x1;
+                let y = if dx.abs() < ADAA_TOLERANCE {
+                    curve.f(0.5 * (x0 + x1))
+                } else {
+                    (f1 - self.f1) / dx
+                };
+                self.f1 = f1;
+                y
+            }
+            AntiAlias::SecondOrder => {
+                let f2 = curve.f2(x0);
+                let slope = f2_slope(curve, x0, x1, f2, self.f2);
+                let span = x0 - x2;
+                let y = if span.abs() < ADAA_TOLERANCE {
+                    let mid = 0.5 * (x0 + x2);
+                    let delta = mid - x1;
+                    if delta.abs() < ADAA_TOLERANCE {
+                        curve.f(0.5 * (mid + x1))
+                    } else {
+                        2.0 / delta * (curve.f1(mid) + (self.f2 - curve.f2(mid)) / delta)
+                    }
+                } else {
+                    2.0 * (slope - self.slope) / span
+                };
+                self.f2 = f2;
+                self.slope = slope;
+                y
+            }
+        };
+        self.x2 = x1;
+        self.x1 = x0;
+        y as f32
+    }
+}
+
+/// Divided difference of F2 between `a` and `b`, given F2 at both, falling
+/// back to F1 at the midpoint.
+fn f2_slope<C: Curve>(curve: &C, a: f64, b: f64, f2_a: f64, f2_b: f64) -> f64 {
+    if (a - b).abs() < ADAA_TOLERANCE {
+        curve.f1(0.5 * (a + b))
+    } else {
+        (f2_a - f2_b) / (a - b)
+    }
+}
+
+/// Dilogarithm of `1 - e^-u` for u in [0, ln 2], i.e. arguments in [0, 1/2].
+///
+/// The Bernoulli series in u converges fast there; the terms up to u^13 are
+/// accurate to a few parts in 1e15.
+fn dilog_bernoulli(u: f64) -> f64 {
+    // B_2k / (2k + 1)! for k = 1..=6
+    const COEFFS: [f64; 6] = [
+        1.0 / 36.0,
+        -1.0 / 3600.0,
+        1.0 / 211_680.0,
+        -1.0 / 10_886_400.0,
+        1.0 / 526_901_760.0,
+        -691.0 / 16_999_766_784_000.0,
+    ];
+    let u2 = u * u;
+    let odd = COEFFS.iter().rev().fold(0.0, |acc, c| acc * u2 + c);
+    u - 0.25 * u2 + u * u2 * odd
+}
+
+/// ln(cosh(x)), the antiderivative of tanh, without overflow.
+fn ln_cosh(x: f64) -> f64 {
+    let a = x.abs();
+    a + (-2.0 * a).exp().ln_1p() - std::f64::consts::LN_2
+}
+
+/// Antiderivative of ln(cosh(x)) that vanishes at zero.
+fn ln_cosh_integral(x: f64) -> f64 {
+    let a = x.abs();
+    // Li2(-e^-2a) = -Li2(1 - e^-u) - u^2 / 2 with u = ln(1 + e^-2a)
+    let u = (-2.0 * a).exp().ln_1p();
+    let dilog = -dilog_bernoulli(u) - 0.5 * u * u;
+    let value = 0.5 * a * a - std::f64::consts::LN_2 * a
+        + 0.5 * dilog
+        + std::f64::consts::PI * std::f64::consts::PI / 24.0;
+    value.copysign(x)
+}
+
+/// `gain * tanh(slope * x)`
+struct TanhCurve {
+    gain: f64,
+    slope: f64,
+}
+
+impl Curve for TanhCurve {
+    fn f(&self, x: f64) -> f64 {
+        self.gain * (self.slope * x).tanh()
+    }
+
+    fn f1(&self, x: f64) -> f64 {
+        self.gain * ln_cosh(self.slope * x) / self.slope
+    }
+
+    fn f2(&self, x: f64) -> f64 {
+        self.gain * ln_cosh_integral(self.slope * x) / (self.slope * self.slope)
+    }
+}
+
+/// tanh with a separate slope on each side of zero.
+struct AsymmetricTanhCurve {
+    positive: f64,
+    negative: f64,
+}
+
+impl AsymmetricTanhCurve {
+    fn side(&self, x: f64) -> TanhCurve {
+        let slope = if x > 0.0 {
+            self.positive
+        } else {
+            self.negative
+        };
+        TanhCurve { gain: 1.0, slope }
+    }
+}
+
+impl Curve for AsymmetricTanhCurve {
+    fn f(&self, x: f64) -> f64 {
+        self.side(x).f(x)
+    }
+
+    // A flat side (slope zero) has flat antiderivatives
+    fn f1(&self, x: f64) -> f64 {
+        let side = self.side(x);
+        if side.slope.abs() < 1e-9 {
+            0.0
+        } else {
+            side.f1(x)
+        }
+    }
+
+    fn f2(&self, x: f64) -> f64 {
+        let side = self.side(x);
+        if side.slope.abs() < 1e-9 {
+            0.0
+        } else {
+            side.f2(x)
+        }
+    }
+}
+
+/// Clamp to `[-threshold, threshold]`.
+struct ClipCurve {
+    threshold: f64,
+}
+
+impl Curve for ClipCurve {
+    fn f(&self, x: f64) -> f64 {
+        x.clamp(-self.threshold, self.threshold)
+    }
+
+    fn f1(&self, x: f64) -> f64 {
+        let t = self.threshold;
+        if x.abs() <= t {
+            0.5 * x * x
+        } else {
+            t * x.abs() - 0.5 * t * t
+        }
+    }
+
+    fn f2(&self, x: f64) -> f64 {
+        let t = self.threshold;
+        let a = x.abs();
+        if a <= t {
+            x * x * x / 6.0
+        } else {
+            (0.5 * t * a * a - 0.5 * t * t * a + t * t * t / 6.0).copysign(x)
+        }
+    }
+}
+
+/// Cubic below 1/3, then a constant 2/3.
+struct CubicCurve;
+
+impl CubicCurve {
+    const KNEE: f64 = 1.0 / 3.0;
+    const LEVEL: f64 = 2.0 / 3.0;
+
+    fn inner_f1(x: f64) -> f64 {
+        0.5 * x * x - x * x * x * x / 12.0
+    }
+
+    fn inner_f2(x: f64) -> f64 {
+        x * x * x / 6.0 - x * x * x * x * x / 60.0
+    }
+}
+
+impl Curve for CubicCurve {
+    fn f(&self, x: f64) -> f64 {
+        if x.abs() < Self::KNEE {
+            x - x * x * x / 3.0
+        } else {
+            Self::LEVEL.copysign(x)
+        }
+    }
+
+    fn f1(&self, x: f64) -> f64 {
+        let a = x.abs();
+        if a < Self::KNEE {
+            Self::inner_f1(x)
+        } else {
+            Self::inner_f1(Self::KNEE) + Self::LEVEL * (a - Self::KNEE)
+        }
+    }
+
+    fn f2(&self, x: f64) -> f64 {
+        let a = x.abs();
+        if a < Self::KNEE {
+            Self::inner_f2(x)
+        } else {
+            let d = a - Self::KNEE;
+            let value = Self::inner_f2(Self::KNEE)
+                + Self::inner_f1(Self::KNEE) * d
+                + 0.5 * Self::LEVEL * d * d;
+            value.copysign(x)
+        }
+    }
+}
+
+/// Triangle folding between -1 and 1, reflecting at each odd integer, that
+/// holds its value past `limit` (an odd integer).
+struct FoldCurve {
+    limit: f64,
+}
+
+impl FoldCurve {
+    /// Position within the fold period, in [-1, 3).
+    fn wrap(x: f64) -> f64 {
+        (x + 1.0).rem_euclid(4.0) - 1.0
+    }
+
+    fn triangle(x: f64) -> f64 {
+        let r = Self::wrap(x);
+        if r <= 1.0 {
+            r
+        } else {
+            2.0 - r
+        }
+    }
+
+    fn triangle_f1(x: f64) -> f64 {
+        let r = Self::wrap(x);
+        if r <= 1.0 {
+            0.5 * r * r
+        } else {
+            2.0 * r - 0.5 * r * r - 1.0
+        }
+    }
+
+    // F1 of the triangle averages 1/2 over a period, the rest is periodic
+    fn triangle_f2(x: f64) -> f64 {
+        let r = Self::wrap(x);
+        let periodic = if r <= 1.0 {
+            r * r * r / 6.0 - 0.5 * r
+        } else {
+            r * r - r * r * r / 6.0 - 1.5 * r + 1.0 / 3.0
+        };
+        0.5 * x + periodic
+    }
+}
+
+impl Curve for FoldCurve {
+    fn f(&self, x: f64) -> f64 {
+        Self::triangle(x.clamp(-self.limit, self.limit))
+    }
+
+    fn f1(&self, x: f64) -> f64 {
+        let a = x.abs();
+        if a <= self.limit {
+            Self::triangle_f1(a)
+        } else {
+            Self::triangle_f1(self.limit) + Self::triangle(self.limit) * (a - self.limit)
+        }
+    }
+
+    fn f2(&self, x: f64) -> f64 {
+        let a = x.abs();
+        let value = if a <= self.limit {
+            Self::triangle_f2(a)
+        } else {
+            let d = a - self.limit;
+            Self::triangle_f2(self.limit)
+                + Self::triangle_f1(self.limit) * d
+                + 0.5 * Self::triangle(self.limit) * d * d
+        };
+        // Odd, but unlike the other curves F2 turns negative past a
+        // negative hold level, so the sign is flipped rather than copied
+        if x < 0.0 {
+            -value
+        } else {
+            value
+        }
+    }
+}
+
 /// State of a WaveShaper
 #[derive(Debug, Clone)]
-pub struct WaveShaperState;
+pub struct WaveShaperState {
+    /// Anti-aliasing history of the shaping curve.
+    pub adaa: AdaaState,
+}
 
 /// Wave Shaper (tanh soft clip)
 #[derive(Debug, Clone)]
@@ -45,13 +522,13 @@ impl NodeDef for WaveShaper {
         1
     }
 
-    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
-        WaveShaperState
+    fn init_state(&self, sample_rate: f32, block_size: usize) -> Self::State {
+        self.init_state_with(AntiAlias::Off, sample_rate, block_size)
     }
 
     fn process_block(
         &self,
-        _state: &mut Self::State,
+        state: &mut Self::State,
         inputs: &[&[f32]],
         outputs: &mut [Vec<f32>],
         _sample_rate: f32,
@@ -70,15 +547,82 @@ impl NodeDef for WaveShaper {
                 };
             let mix = self.mix + if mix_mod.is_empty() { 0.0 } else { mix_mod[i] };
 
-            let shaped = (input[i] * drive).tanh();
+            let curve = TanhCurve {
+                gain: 1.0,
+                slope: 1.0,
+            };
+            let x = (input[i] * drive) as f64;
+            let shaped = state.adaa.shape(&curve, x);
             output[i] = input[i] * (1.0 - mix) + shaped * mix;
         }
     }
 }
 
+impl AdaaShaper for WaveShaper {
+    fn init_state_with(
+        &self,
+        anti_alias: AntiAlias,
+        _sample_rate: f32,
+        _block_size: usize,
+    ) -> Self::State {
+        WaveShaperState {
+            adaa: AdaaState::new(anti_alias),
+        }
+    }
+}
+
+impl Parameterized for WaveShaper {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "drive", 0.0, 100.0, 1.0, ParamUnit::Gain),
+            ParamDesc::new(1, "mix", 0.0, 1.0, 1.0, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.drive),
+            1 => Some(self.mix),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.drive = value,
+            1 => self.mix = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for WaveShaper {
+    fn name(&self) -> &'static str {
+        "WaveShaper"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "drive_mod", 0, ParamUnit::Gain),
+            PortDesc::modulation(2, "mix_mod", 1, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 /// State of a HardClip
 #[derive(Debug, Clone)]
-pub struct HardClipState;
+pub struct HardClipState {
+    /// Anti-aliasing history of the shaping curve.
+    pub adaa: AdaaState,
+}
 
 /// Hard Clip
 #[derive(Debug, Clone)]
@@ -120,13 +664,13 @@ impl NodeDef for HardClip {
         1
     }
 
-    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
-        HardClipState
+    fn init_state(&self, sample_rate: f32, block_size: usize) -> Self::State {
+        self.init_state_with(AntiAlias::Off, sample_rate, block_size)
     }
 
     fn process_block(
         &self,
-        _state: &mut Self::State,
+        state: &mut Self::State,
         inputs: &[&[f32]],
         outputs: &mut [Vec<f32>],
         _sample_rate: f32,
@@ -145,12 +689,75 @@ impl NodeDef for HardClip {
                 };
             let mix = self.mix + if mix_mod.is_empty() { 0.0 } else { mix_mod[i] };
 
-            let shaped = input[i].clamp(-threshold, threshold);
+            let curve = ClipCurve {
+                threshold: threshold.max(0.0) as f64,
+            };
+            let x = input[i] as f64;
+            let shaped = state.adaa.shape(&curve, x);
             output[i] = input[i] * (1.0 - mix) + shaped * mix;
         }
     }
 }
 
+impl AdaaShaper for HardClip {
+    fn init_state_with(
+        &self,
+        anti_alias: AntiAlias,
+        _sample_rate: f32,
+        _block_size: usize,
+    ) -> Self::State {
+        HardClipState {
+            adaa: AdaaState::new(anti_alias),
+        }
+    }
+}
+
+impl Parameterized for HardClip {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "threshold", 0.0, 4.0, 0.8, ParamUnit::Gain),
+            ParamDesc::new(1, "mix", 0.0, 1.0, 1.0, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.threshold),
+            1 => Some(self.mix),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.threshold = value,
+            1 => self.mix = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for HardClip {
+    fn name(&self) -> &'static str {
+        "HardClip"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "threshold_mod", 0, ParamUnit::Gain),
+            PortDesc::modulation(2, "mix_mod", 1, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 /// State of a BitCrusher
 #[derive(Debug, Clone)]
 pub struct BitCrusherState;
@@ -227,9 +834,58 @@ impl NodeDef for BitCrusher {
     }
 }
 
+impl Parameterized for BitCrusher {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "bits", 1.0, 24.0, 8.0, ParamUnit::Bits),
+            ParamDesc::new(1, "mix", 0.0, 1.0, 1.0, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.bits),
+            1 => Some(self.mix),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.bits = value,
+            1 => self.mix = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for BitCrusher {
+    fn name(&self) -> &'static str {
+        "BitCrusher"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "bits_mod", 0, ParamUnit::Bits),
+            PortDesc::modulation(2, "mix_mod", 1, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 /// State of a SoftClip
 #[derive(Debug, Clone)]
-pub struct SoftClipState;
+pub struct SoftClipState {
+    /// Anti-aliasing history of the shaping curve.
+    pub adaa: AdaaState,
+}
 
 /// Soft Clip (cubic)
 #[derive(Debug, Clone)]
@@ -271,13 +927,13 @@ impl NodeDef for SoftClip {
         1
     }
 
-    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
-        SoftClipState
+    fn init_state(&self, sample_rate: f32, block_size: usize) -> Self::State {
+        self.init_state_with(AntiAlias::Off, sample_rate, block_size)
     }
 
     fn process_block(
         &self,
-        _state: &mut Self::State,
+        state: &mut Self::State,
         inputs: &[&[f32]],
         outputs: &mut [Vec<f32>],
         _sample_rate: f32,
@@ -296,38 +952,96 @@ impl NodeDef for SoftClip {
                 };
             let mix = self.mix + if mix_mod.is_empty() { 0.0 } else { mix_mod[i] };
 
-            let x = input[i] * drive;
-            let shaped = if x.abs() < 1.0 / 3.0 {
-                x - (1.0 / 3.0) * x * x * x
-            } else {
-                x.signum() * (2.0 / 3.0)
-            };
+            let x = (input[i] * drive) as f64;
+            let shaped = state.adaa.shape(&CubicCurve, x);
             output[i] = input[i] * (1.0 - mix) + shaped * mix;
         }
     }
 }
 
-/// State of a TubeSaturation
-#[derive(Debug, Clone)]
-pub struct TubeSaturationState;
-
-/// Tube Saturation (asymmetric tanh)
-#[derive(Debug, Clone)]
-pub struct TubeSaturation {
-    pub drive: f32,
-    pub asymmetry: f32,
-    pub mix: f32,
+impl AdaaShaper for SoftClip {
+    fn init_state_with(
+        &self,
+        anti_alias: AntiAlias,
+        _sample_rate: f32,
+        _block_size: usize,
+    ) -> Self::State {
+        SoftClipState {
+            adaa: AdaaState::new(anti_alias),
+        }
+    }
 }
 
-impl NodeDef for TubeSaturation {
-    type State = TubeSaturationState;
+impl Parameterized for SoftClip {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "drive", 0.0, 100.0, 1.0, ParamUnit::Gain),
+            ParamDesc::new(1, "mix", 0.0, 1.0, 1.0, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
 
-    fn input_ports(&self) -> &'static [Port] {
-        const PORTS: &[Port] = &[
-            Port {
-                id: PortId(0),
-                rate: Rate::Audio,
-            }, // input
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.drive),
+            1 => Some(self.mix),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.drive = value,
+            1 => self.mix = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for SoftClip {
+    fn name(&self) -> &'static str {
+        "SoftClip"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "drive_mod", 0, ParamUnit::Gain),
+            PortDesc::modulation(2, "mix_mod", 1, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// State of a TubeSaturation
+#[derive(Debug, Clone)]
+pub struct TubeSaturationState {
+    /// Anti-aliasing history of the shaping curve.
+    pub adaa: AdaaState,
+}
+
+/// Tube Saturation (asymmetric tanh)
+#[derive(Debug, Clone)]
+pub struct TubeSaturation {
+    pub drive: f32,
+    pub asymmetry: f32,
+    pub mix: f32,
+}
+
+impl NodeDef for TubeSaturation {
+    type State = TubeSaturationState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            }, // input
             Port {
                 id: PortId(1),
                 rate: Rate::Audio,
@@ -356,13 +1070,13 @@ impl NodeDef for TubeSaturation {
         1
     }
 
-    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
-        TubeSaturationState
+    fn init_state(&self, sample_rate: f32, block_size: usize) -> Self::State {
+        self.init_state_with(AntiAlias::Off, sample_rate, block_size)
     }
 
     fn process_block(
         &self,
-        _state: &mut Self::State,
+        state: &mut Self::State,
         inputs: &[&[f32]],
         outputs: &mut [Vec<f32>],
         _sample_rate: f32,
@@ -388,17 +1102,80 @@ impl NodeDef for TubeSaturation {
                 };
             let mix = self.mix + if mix_mod.is_empty() { 0.0 } else { mix_mod[i] };
 
-            let x = input[i] * drive;
-            let shaped = if x > 0.0 {
-                (x * (1.0 + asymmetry)).tanh()
-            } else {
-                (x * (1.0 - asymmetry)).tanh()
+            let curve = AsymmetricTanhCurve {
+                positive: (1.0 + asymmetry) as f64,
+                negative: (1.0 - asymmetry) as f64,
             };
+            let x = (input[i] * drive) as f64;
+            let shaped = state.adaa.shape(&curve, x);
             output[i] = input[i] * (1.0 - mix) + shaped * mix;
         }
     }
 }
 
+impl AdaaShaper for TubeSaturation {
+    fn init_state_with(
+        &self,
+        anti_alias: AntiAlias,
+        _sample_rate: f32,
+        _block_size: usize,
+    ) -> Self::State {
+        TubeSaturationState {
+            adaa: AdaaState::new(anti_alias),
+        }
+    }
+}
+
+impl Parameterized for TubeSaturation {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "drive", 0.0, 100.0, 1.0, ParamUnit::Gain),
+            ParamDesc::new(1, "asymmetry", -1.0, 1.0, 0.2, ParamUnit::None),
+            ParamDesc::new(2, "mix", 0.0, 1.0, 1.0, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.drive),
+            1 => Some(self.asymmetry),
+            2 => Some(self.mix),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.drive = value,
+            1 => self.asymmetry = value,
+            2 => self.mix = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for TubeSaturation {
+    fn name(&self) -> &'static str {
+        "TubeSaturation"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "drive_mod", 0, ParamUnit::Gain),
+            PortDesc::modulation(2, "asymmetry_mod", 1, ParamUnit::None),
+            PortDesc::modulation(3, "mix_mod", 2, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 /// State of a DC Blocker
 #[derive(Debug, Clone)]
 pub struct DcBlockerState {
@@ -461,9 +1238,52 @@ impl NodeDef for DcBlocker {
     }
 }
 
+impl Parameterized for DcBlocker {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[ParamDesc::new(
+            0,
+            "cutoff",
+            1.0,
+            200.0,
+            20.0,
+            ParamUnit::Hertz,
+        )];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        (id == 0).then_some(self.cutoff)
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        if id == 0 {
+            self.cutoff = value;
+        }
+    }
+}
+
+impl Describe for DcBlocker {
+    fn name(&self) -> &'static str {
+        "DcBlocker"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 /// State of an Overdrive
 #[derive(Debug, Clone)]
-pub struct OverdriveState;
+pub struct OverdriveState {
+    /// Anti-aliasing history of the shaping curve.
+    pub adaa: AdaaState,
+}
 
 /// Overdrive Distortion
 #[derive(Debug, Clone)]
@@ -505,8 +1325,343 @@ impl NodeDef for Overdrive {
         1
     }
 
+    fn init_state(&self, sample_rate: f32, block_size: usize) -> Self::State {
+        self.init_state_with(AntiAlias::Off, sample_rate, block_size)
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let drive_mod = if inputs.len() > 1 { inputs[1] } else { &[] };
+        let mix_mod = if inputs.len() > 2 { inputs[2] } else { &[] };
+        let output = &mut outputs[0];
+
+        for i in 0..input.len() {
+            let drive = self.drive
+                + if drive_mod.is_empty() {
+                    0.0
+                } else {
+                    drive_mod[i]
+                };
+            let mix = self.mix + if mix_mod.is_empty() { 0.0 } else { mix_mod[i] };
+
+            // Simple overdrive: tanh with pre-gain
+            let curve = TanhCurve {
+                gain: 0.8,
+                slope: 2.0,
+            };
+            let x = (input[i] * drive) as f64;
+            let shaped = state.adaa.shape(&curve, x);
+            output[i] = input[i] * (1.0 - mix) + shaped * mix;
+        }
+    }
+}
+
+impl AdaaShaper for Overdrive {
+    fn init_state_with(
+        &self,
+        anti_alias: AntiAlias,
+        _sample_rate: f32,
+        _block_size: usize,
+    ) -> Self::State {
+        OverdriveState {
+            adaa: AdaaState::new(anti_alias),
+        }
+    }
+}
+
+impl Parameterized for Overdrive {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "drive", 0.0, 100.0, 1.0, ParamUnit::Gain),
+            ParamDesc::new(1, "mix", 0.0, 1.0, 1.0, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.drive),
+            1 => Some(self.mix),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.drive = value,
+            1 => self.mix = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for Overdrive {
+    fn name(&self) -> &'static str {
+        "Overdrive"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "drive_mod", 0, ParamUnit::Gain),
+            PortDesc::modulation(2, "mix_mod", 1, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// State of a Wavefolder
+#[derive(Debug, Clone)]
+pub struct WavefolderState {
+    /// Anti-aliasing history of the folding curve.
+    pub adaa: AdaaState,
+}
+
+/// Serge-style series wavefolder.
+///
+/// The input is scaled by `fold`, offset by `bias` and folded back each
+/// time it crosses ±1. Every stage adds one fold per polarity; past the last
+/// one the output holds at ±1, so `stages = 0` is a plain clipper. A nonzero
+/// bias folds the two half-waves differently, adding even harmonics.
+/// Aliasing is controlled with [`AdaaShaper::with_anti_alias`], or by
+/// wrapping the node in `Oversampled`.
+#[derive(Debug, Clone)]
+pub struct Wavefolder {
+    pub fold: f32,
+    pub bias: f32,
+    pub stages: usize,
+    pub mix: f32,
+}
+
+impl NodeDef for Wavefolder {
+    type State = WavefolderState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            }, // input
+            Port {
+                id: PortId(1),
+                rate: Rate::Audio,
+            }, // fold_mod
+            Port {
+                id: PortId(2),
+                rate: Rate::Audio,
+            }, // bias_mod
+            Port {
+                id: PortId(3),
+                rate: Rate::Audio,
+            }, // mix_mod
+        ];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, sample_rate: f32, block_size: usize) -> Self::State {
+        self.init_state_with(AntiAlias::Off, sample_rate, block_size)
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let fold_mod = if inputs.len() > 1 { inputs[1] } else { &[] };
+        let bias_mod = if inputs.len() > 2 { inputs[2] } else { &[] };
+        let mix_mod = if inputs.len() > 3 { inputs[3] } else { &[] };
+        let output = &mut outputs[0];
+        let curve = FoldCurve {
+            limit: (2 * self.stages + 1) as f64,
+        };
+
+        for i in 0..input.len() {
+            let fold = self.fold
+                + if fold_mod.is_empty() {
+                    0.0
+                } else {
+                    fold_mod[i]
+                };
+            let bias = self.bias
+                + if bias_mod.is_empty() {
+                    0.0
+                } else {
+                    bias_mod[i]
+                };
+            let mix = self.mix + if mix_mod.is_empty() { 0.0 } else { mix_mod[i] };
+
+            let x = (input[i] * fold + bias) as f64;
+            let shaped = state.adaa.shape(&curve, x);
+            output[i] = input[i] * (1.0 - mix) + shaped * mix;
+        }
+    }
+}
+
+impl AdaaShaper for Wavefolder {
+    fn init_state_with(
+        &self,
+        anti_alias: AntiAlias,
+        _sample_rate: f32,
+        _block_size: usize,
+    ) -> Self::State {
+        WavefolderState {
+            adaa: AdaaState::new(anti_alias),
+        }
+    }
+}
+
+impl Parameterized for Wavefolder {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "fold", 0.0, 20.0, 1.0, ParamUnit::Gain),
+            ParamDesc::new(1, "bias", -1.0, 1.0, 0.0, ParamUnit::None),
+            ParamDesc::new(2, "mix", 0.0, 1.0, 1.0, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.fold),
+            1 => Some(self.bias),
+            2 => Some(self.mix),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.fold = value,
+            1 => self.bias = value,
+            2 => self.mix = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for Wavefolder {
+    fn name(&self) -> &'static str {
+        "Wavefolder"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "fold_mod", 0, ParamUnit::Gain),
+            PortDesc::modulation(2, "bias_mod", 1, ParamUnit::None),
+            PortDesc::modulation(3, "mix_mod", 2, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// State of a ChebyshevShaper
+#[derive(Debug, Clone)]
+pub struct ChebyshevShaperState;
+
+/// Harmonic exciter built from a sum of Chebyshev polynomials.
+///
+/// `harmonics[k]` is the amplitude of harmonic `k + 1`: a full-scale sine
+/// at the input comes out as exactly that mix of harmonics; quieter inputs
+/// shift the balance toward the low harmonics, and even ones then add some
+/// DC. The driven input is clamped to ±1, where the polynomials stay bounded.
+#[derive(Debug, Clone)]
+pub struct ChebyshevShaper {
+    pub harmonics: Vec<f32>,
+    pub drive: f32,
+    pub mix: f32,
+}
+
+impl ChebyshevShaper {
+    /// Fully wet shaper at unity drive.
+    pub fn new(harmonics: Vec<f32>) -> Self {
+        Self {
+            harmonics,
+            drive: 1.0,
+            mix: 1.0,
+        }
+    }
+
+    /// Evaluate the polynomial sum with Clenshaw's recurrence.
+    fn eval(&self, x: f32) -> f32 {
+        let mut b1 = 0.0;
+        let mut b2 = 0.0;
+        for &a in self.harmonics.iter().rev() {
+            let b = a + 2.0 * x * b1 - b2;
+            b2 = b1;
+            b1 = b;
+        }
+        // No T0 term: the sum starts at the fundamental
+        x * b1 - b2
+    }
+}
+
+impl NodeDef for ChebyshevShaper {
+    type State = ChebyshevShaperState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            }, // input
+            Port {
+                id: PortId(1),
+                rate: Rate::Audio,
+            }, // drive_mod
+            Port {
+                id: PortId(2),
+                rate: Rate::Audio,
+            }, // mix_mod
+        ];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
     fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
-        OverdriveState
+        ChebyshevShaperState
     }
 
     fn process_block(
@@ -530,10 +1685,274 @@ impl NodeDef for Overdrive {
                 };
             let mix = self.mix + if mix_mod.is_empty() { 0.0 } else { mix_mod[i] };
 
-            let x = input[i] * drive;
-            // Simple overdrive: tanh with pre-gain
-            let shaped = (x * 2.0).tanh() * 0.8;
+            let shaped = self.eval((input[i] * drive).clamp(-1.0, 1.0));
+            output[i] = input[i] * (1.0 - mix) + shaped * mix;
+        }
+    }
+}
+
+impl Parameterized for ChebyshevShaper {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "drive", 0.0, 4.0, 1.0, ParamUnit::Gain),
+            ParamDesc::new(1, "mix", 0.0, 1.0, 1.0, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.drive),
+            1 => Some(self.mix),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.drive = value,
+            1 => self.mix = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for ChebyshevShaper {
+    fn name(&self) -> &'static str {
+        "ChebyshevShaper"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "drive_mod", 0, ParamUnit::Gain),
+            PortDesc::modulation(2, "mix_mod", 1, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
+/// Samples gathered around each table read, enough for any `Interpolation`.
+const TABLE_READ_TAPS: usize = 8;
+
+/// Transfer curve of a TableShaper.
+#[derive(Clone)]
+pub enum TransferCurve {
+    /// Output values spread evenly over inputs -1 to 1.
+    Table(Arc<[f32]>),
+    /// Function sampled into a table of `size` points at `init_state`.
+    Function {
+        curve: Arc<dyn Fn(f32) -> f32 + Send + Sync>,
+        size: usize,
+    },
+}
+
+impl fmt::Debug for TransferCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransferCurve::Table(table) => f.debug_tuple("Table").field(&table.len()).finish(),
+            TransferCurve::Function { size, .. } => {
+                f.debug_struct("Function").field("size", size).finish()
+            }
+        }
+    }
+}
+
+/// State of a TableShaper
+#[derive(Debug, Clone)]
+pub struct TableShaperState {
+    pub table: Arc<[f32]>,
+    pub interpolator: Interpolator,
+}
+
+/// Shaper with an arbitrary transfer curve.
+///
+/// The driven input is clamped to ±1 and looked up in the curve's table
+/// with the chosen `interpolation`, so inputs past ±1 hold the end values.
+/// Kernel taps that fall off the table continue its end slopes, which keeps
+/// reads between the last entries on the curve.
+#[derive(Debug, Clone)]
+pub struct TableShaper {
+    pub curve: TransferCurve,
+    pub drive: f32,
+    pub mix: f32,
+    pub interpolation: Interpolation,
+}
+
+impl TableShaper {
+    /// Fully wet shaper over a table, with linear interpolation.
+    pub fn new(table: Arc<[f32]>) -> Self {
+        Self {
+            curve: TransferCurve::Table(table),
+            drive: 1.0,
+            mix: 1.0,
+            interpolation: Interpolation::Linear,
+        }
+    }
+
+    /// Fully wet shaper over `curve` sampled at `size` points.
+    pub fn from_fn<F>(curve: F, size: usize) -> Self
+    where
+        F: Fn(f32) -> f32 + Send + Sync + 'static,
+    {
+        Self {
+            curve: TransferCurve::Function {
+                curve: Arc::new(curve),
+                size,
+            },
+            ..Self::new(Arc::new([]))
+        }
+    }
+
+    fn lookup(table: &[f32], interpolator: &Interpolator, x: f32) -> f32 {
+        let last = table.len() as i64 - 1;
+        if last < 1 {
+            return table.first().copied().unwrap_or(0.0);
+        }
+        let position = (x + 1.0) * 0.5 * last as f32;
+        let base = position.floor();
+        let mut taps = [0.0; TABLE_READ_TAPS];
+        // Taps past the ends continue the end slopes
+        let head = table[1] - table[0];
+        let tail = table[last as usize] - table[last as usize - 1];
+        for (k, tap) in taps.iter_mut().enumerate() {
+            let index = base as i64 + k as i64 - 3;
+            *tap = if index < 0 {
+                table[0] + head * index as f32
+            } else if index > last {
+                table[last as usize] + tail * (index - last) as f32
+            } else {
+                table[index as usize]
+            };
+        }
+        interpolator.read_at(&taps, 3, position - base)
+    }
+}
+
+impl NodeDef for TableShaper {
+    type State = TableShaperState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            }, // input
+            Port {
+                id: PortId(1),
+                rate: Rate::Audio,
+            }, // drive_mod
+            Port {
+                id: PortId(2),
+                rate: Rate::Audio,
+            }, // mix_mod
+        ];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, _sample_rate: f32, _block_size: usize) -> Self::State {
+        let table = match &self.curve {
+            TransferCurve::Table(table) => table.clone(),
+            TransferCurve::Function { curve, size } => {
+                let last = size.saturating_sub(1).max(1) as f32;
+                (0..*size)
+                    .map(|i| curve(2.0 * i as f32 / last - 1.0))
+                    .collect()
+            }
+        };
+        TableShaperState {
+            table,
+            interpolator: Interpolator::new(self.interpolation),
+        }
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        _sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let drive_mod = if inputs.len() > 1 { inputs[1] } else { &[] };
+        let mix_mod = if inputs.len() > 2 { inputs[2] } else { &[] };
+        let output = &mut outputs[0];
+
+        for i in 0..input.len() {
+            let drive = self.drive
+                + if drive_mod.is_empty() {
+                    0.0
+                } else {
+                    drive_mod[i]
+                };
+            let mix = self.mix + if mix_mod.is_empty() { 0.0 } else { mix_mod[i] };
+
+            let x = (input[i] * drive).clamp(-1.0, 1.0);
+            let shaped = Self::lookup(&state.table, &state.interpolator, x);
             output[i] = input[i] * (1.0 - mix) + shaped * mix;
         }
     }
 }
+
+impl Parameterized for TableShaper {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "drive", 0.0, 4.0, 1.0, ParamUnit::Gain),
+            ParamDesc::new(1, "mix", 0.0, 1.0, 1.0, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.drive),
+            1 => Some(self.mix),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.drive = value,
+            1 => self.mix = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for TableShaper {
+    fn name(&self) -> &'static str {
+        "TableShaper"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "drive_mod", 0, ParamUnit::Gain),
+            PortDesc::modulation(2, "mix_mod", 1, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
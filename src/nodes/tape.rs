@@ -0,0 +1,383 @@
+//! Tape emulation: Jiles-Atherton magnetic hysteresis followed by the
+//! transport and playback head.
+//!
+//! The recorded magnetisation is solved per sample with fourth-order
+//! Runge-Kutta over the change in the applied field, sub-stepped so each
+//! step stays within the width of the anhysteretic curve. The transport is
+//! a modulated delay line read like `Delay`, and the playback head adds a
+//! low-frequency bump and a high-frequency rolloff.
+
+use crate::describe::{Describe, PortDesc};
+use crate::helpers::{Interpolation, Interpolator};
+use crate::params::{ParamDesc, ParamUnit, Parameterized};
+use auxide::graph::{Port, PortId, Rate};
+use auxide::node::NodeDef;
+
+/// Saturation magnetisation; the model works in units of it.
+const MS: f64 = 1.0;
+
+/// Inter-domain coupling of the Jiles-Atherton model.
+const ALPHA: f64 = 1.6e-3;
+
+/// Domain-wall pinning, relative to the anhysteretic shape `a`.
+const PINNING: f64 = 0.478_75;
+
+/// Upper bound on Runge-Kutta sub-steps per sample.
+const MAX_SUBSTEPS: usize = 64;
+
+/// Wow and flutter rates in Hz.
+const WOW_RATE: f32 = 0.5;
+const FLUTTER_RATE: f32 = 7.0;
+
+/// Delay swing at full wow and flutter depth, in milliseconds.
+const WOW_DEPTH_MS: f32 = 2.0;
+const FLUTTER_DEPTH_MS: f32 = 0.2;
+
+/// Q of the head bump.
+const HEAD_BUMP_Q: f32 = 1.0;
+
+/// Langevin function coth(x) - 1/x.
+fn langevin(x: f64) -> f64 {
+    if x.abs() < 1e-4 {
+        x / 3.0
+    } else {
+        1.0 / x.tanh() - 1.0 / x
+    }
+}
+
+/// Derivative of the Langevin function.
+fn langevin_deriv(x: f64) -> f64 {
+    if x.abs() < 1e-4 {
+        1.0 / 3.0 - x * x / 15.0
+    } else if x.abs() > 20.0 {
+        1.0 / (x * x)
+    } else {
+        1.0 / (x * x) - 1.0 / (x.sinh() * x.sinh())
+    }
+}
+
+/// Jiles-Atherton material for one sample's settings.
+struct Hysteresis {
+    /// Anhysteretic shape; smaller saturates sooner.
+    a: f64,
+    /// Pinning, the width of the loop in field units.
+    k: f64,
+    /// Reversible fraction of the magnetisation.
+    c: f64,
+}
+
+impl Hysteresis {
+    /// dM/dH at magnetisation `m` and field `h`, with the field moving in
+    /// direction `delta` (±1).
+    fn slope(&self, m: f64, h: f64, delta: f64) -> f64 {
+        let q = (h + ALPHA * m) / self.a;
+        let anhysteretic = MS * langevin(q);
+        let anhysteretic_slope = MS / self.a * langevin_deriv(q);
+        let diff = anhysteretic - m;
+        // Irreversible motion only while the field pulls toward the curve
+        let irreversible = if delta * diff > 0.0 {
+            (1.0 - self.c) * diff / ((1.0 - self.c) * delta * self.k - ALPHA * diff)
+        } else {
+            0.0
+        };
+        let numerator = irreversible + self.c * anhysteretic_slope;
+        numerator / (1.0 - self.c * ALPHA * anhysteretic_slope)
+    }
+
+    /// Advance `m` as the field moves from `h0` to `h1`.
+    fn step(&self, m: f64, h0: f64, h1: f64) -> f64 {
+        let dh = h1 - h0;
+        let delta = if dh >= 0.0 { 1.0 } else { -1.0 };
+        let steps = ((dh.abs() / self.a).ceil() as usize).clamp(1, MAX_SUBSTEPS);
+        let step = dh / steps as f64;
+        let mut m = m;
+        for n in 0..steps {
+            let h = h0 + step * n as f64;
+            let k1 = self.slope(m, h, delta);
+            let k2 = self.slope(m + 0.5 * step * k1, h + 0.5 * step, delta);
+            let k3 = self.slope(m + 0.5 * step * k2, h + 0.5 * step, delta);
+            let k4 = self.slope(m + step * k3, h + step, delta);
+            m += step / 6.0 * (k1 + 2.0 * k2 + 2.0 * k3 + k4);
+            // Keep very large steps from overshooting saturation
+            m = m.clamp(-MS, MS);
+        }
+        m
+    }
+}
+
+/// State of a TapeSaturation
+#[derive(Debug, Clone)]
+pub struct TapeSaturationState {
+    /// Magnetisation and the field that produced it.
+    pub m: f64,
+    pub h: f64,
+    pub buffer: Vec<f32>,
+    pub index: usize,
+    pub interpolator: Interpolator,
+    pub wow_phase: f32,
+    pub flutter_phase: f32,
+    /// Head bump biquad, transposed direct form II.
+    pub bump_z1: f32,
+    pub bump_z2: f32,
+    pub loss_z: f32,
+}
+
+/// Tape machine: magnetic hysteresis, wow and flutter, and head response.
+///
+/// `drive` scales the input into the record field. `saturation` narrows the
+/// magnetisation curve so the tape saturates sooner, and `bias` sets how
+/// much of the magnetisation is reversible: well-biased tape (1) is nearly
+/// free of hysteresis, under-biased tape (0) has a wide loop and loses low
+/// levels. The output is scaled so a full-scale input at unity drive comes
+/// out near full scale.
+///
+/// `wow` and `flutter` set the depth of slow and fast speed variations,
+/// which modulate a delay line. The line always adds a short fixed delay,
+/// plus half the swing of any wow and flutter. `head_bump` boosts around
+/// `head_bump_freq` by that many dB, and `hf_loss` rolls off the top
+/// octaves; both are off at zero.
+#[derive(Debug, Clone)]
+pub struct TapeSaturation {
+    pub drive: f32,
+    pub saturation: f32,
+    pub bias: f32,
+    pub wow: f32,
+    pub flutter: f32,
+    pub head_bump: f32,
+    pub head_bump_freq: f32,
+    pub hf_loss: f32,
+    pub interpolation: Interpolation,
+}
+
+impl TapeSaturation {
+    /// Moderately driven tape with no transport or head coloration.
+    pub fn new() -> Self {
+        Self {
+            drive: 1.0,
+            saturation: 0.5,
+            bias: 0.5,
+            wow: 0.0,
+            flutter: 0.0,
+            head_bump: 0.0,
+            head_bump_freq: 60.0,
+            hf_loss: 0.0,
+            interpolation: Interpolation::Cubic,
+        }
+    }
+
+    fn material(&self, bias: f32) -> Hysteresis {
+        let a = 1.0 - 0.9 * self.saturation.clamp(0.0, 1.0) as f64;
+        Hysteresis {
+            a,
+            k: PINNING * a,
+            c: 0.02 + 0.96 * bias.clamp(0.0, 1.0) as f64,
+        }
+    }
+
+    /// Peaking biquad coefficients (b0, b1, b2, a1, a2) for the head bump.
+    fn bump_coefs(&self, sample_rate: f32) -> [f32; 5] {
+        let gain = 10f32.powf(self.head_bump / 40.0);
+        let w0 = std::f32::consts::TAU * self.head_bump_freq.clamp(10.0, sample_rate * 0.45)
+            / sample_rate;
+        let alpha = w0.sin() / (2.0 * HEAD_BUMP_Q);
+        let cos = w0.cos();
+        let a0 = 1.0 + alpha / gain;
+        [
+            (1.0 + alpha * gain) / a0,
+            -2.0 * cos / a0,
+            (1.0 - alpha * gain) / a0,
+            -2.0 * cos / a0,
+            (1.0 - alpha / gain) / a0,
+        ]
+    }
+}
+
+impl Default for TapeSaturation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NodeDef for TapeSaturation {
+    type State = TapeSaturationState;
+
+    fn input_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[
+            Port {
+                id: PortId(0),
+                rate: Rate::Audio,
+            }, // input
+            Port {
+                id: PortId(1),
+                rate: Rate::Audio,
+            }, // drive_mod
+            Port {
+                id: PortId(2),
+                rate: Rate::Audio,
+            }, // bias_mod
+        ];
+        PORTS
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        const PORTS: &[Port] = &[Port {
+            id: PortId(0),
+            rate: Rate::Audio,
+        }];
+        PORTS
+    }
+
+    fn required_inputs(&self) -> usize {
+        1
+    }
+
+    fn init_state(&self, sample_rate: f32, _block_size: usize) -> Self::State {
+        let taps = self.interpolation.taps();
+        let swing = (WOW_DEPTH_MS + FLUTTER_DEPTH_MS) * sample_rate / 1000.0;
+        TapeSaturationState {
+            m: 0.0,
+            h: 0.0,
+            buffer: vec![0.0; swing.ceil() as usize + taps + taps / 2],
+            index: 0,
+            interpolator: Interpolator::new(self.interpolation),
+            wow_phase: 0.0,
+            flutter_phase: 0.0,
+            bump_z1: 0.0,
+            bump_z2: 0.0,
+            loss_z: 0.0,
+        }
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        sample_rate: f32,
+    ) {
+        let input = &inputs[0];
+        let drive_mod = if inputs.len() > 1 { inputs[1] } else { &[] };
+        let bias_mod = if inputs.len() > 2 { inputs[2] } else { &[] };
+        let output = &mut outputs[0];
+
+        let min_delay = (state.interpolator.kind().taps() / 2) as f32;
+        let wow_depth = self.wow.clamp(0.0, 1.0) * WOW_DEPTH_MS * sample_rate / 1000.0;
+        let flutter_depth = self.flutter.clamp(0.0, 1.0) * FLUTTER_DEPTH_MS * sample_rate / 1000.0;
+        let [b0, b1, b2, a1, a2] = self.bump_coefs(sample_rate);
+        let loss_cutoff = 20_000f32.min(sample_rate * 0.45) * 10f32.powf(-self.hf_loss);
+        let loss_coef = 1.0 - (-std::f32::consts::TAU * loss_cutoff / sample_rate).exp();
+        let a = self.material(self.bias).a;
+        let makeup = (1.0 / (MS * langevin(1.0 / a))) as f32;
+
+        for i in 0..input.len() {
+            let drive = self.drive
+                + if drive_mod.is_empty() {
+                    0.0
+                } else {
+                    drive_mod[i]
+                };
+            let bias = self.bias
+                + if bias_mod.is_empty() {
+                    0.0
+                } else {
+                    bias_mod[i]
+                };
+
+            // Record
+            let h = (input[i] * drive) as f64;
+            state.m = self.material(bias).step(state.m, state.h, h);
+            state.h = h;
+            let recorded = state.m as f32 * makeup;
+
+            // Transport
+            state.wow_phase = (state.wow_phase + WOW_RATE / sample_rate).fract();
+            state.flutter_phase = (state.flutter_phase + FLUTTER_RATE / sample_rate).fract();
+            let wow = 0.5 - 0.5 * (state.wow_phase * std::f32::consts::TAU).cos();
+            let flutter = 0.5 - 0.5 * (state.flutter_phase * std::f32::consts::TAU).cos();
+            let delay = min_delay + wow * wow_depth + flutter * flutter_depth;
+            state.buffer[state.index] = recorded;
+            let played = state
+                .interpolator
+                .read_delay(&state.buffer, state.index, delay);
+            state.index = (state.index + 1) % state.buffer.len();
+
+            // Playback head
+            let mut y = played;
+            if self.head_bump != 0.0 {
+                let out = b0 * y + state.bump_z1;
+                state.bump_z1 = b1 * y - a1 * out + state.bump_z2;
+                state.bump_z2 = b2 * y - a2 * out;
+                y = out;
+            }
+            if self.hf_loss > 0.0 {
+                state.loss_z += (y - state.loss_z) * loss_coef;
+                y = state.loss_z;
+            }
+            output[i] = y;
+        }
+    }
+}
+
+impl Parameterized for TapeSaturation {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[
+            ParamDesc::new(0, "drive", 0.0, 10.0, 1.0, ParamUnit::Gain),
+            ParamDesc::new(1, "saturation", 0.0, 1.0, 0.5, ParamUnit::Normalized),
+            ParamDesc::new(2, "bias", 0.0, 1.0, 0.5, ParamUnit::Normalized),
+            ParamDesc::new(3, "wow", 0.0, 1.0, 0.0, ParamUnit::Normalized),
+            ParamDesc::new(4, "flutter", 0.0, 1.0, 0.0, ParamUnit::Normalized),
+            ParamDesc::new(5, "head_bump", 0.0, 12.0, 0.0, ParamUnit::Decibels),
+            ParamDesc::new(6, "head_bump_freq", 20.0, 200.0, 60.0, ParamUnit::Hertz),
+            ParamDesc::new(7, "hf_loss", 0.0, 1.0, 0.0, ParamUnit::Normalized),
+        ];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        match id {
+            0 => Some(self.drive),
+            1 => Some(self.saturation),
+            2 => Some(self.bias),
+            3 => Some(self.wow),
+            4 => Some(self.flutter),
+            5 => Some(self.head_bump),
+            6 => Some(self.head_bump_freq),
+            7 => Some(self.hf_loss),
+            _ => None,
+        }
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        match id {
+            0 => self.drive = value,
+            1 => self.saturation = value,
+            2 => self.bias = value,
+            3 => self.wow = value,
+            4 => self.flutter = value,
+            5 => self.head_bump = value,
+            6 => self.head_bump_freq = value,
+            7 => self.hf_loss = value,
+            _ => {}
+        }
+    }
+}
+
+impl Describe for TapeSaturation {
+    fn name(&self) -> &'static str {
+        "TapeSaturation"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "drive_mod", 0, ParamUnit::Gain),
+            PortDesc::modulation(2, "bias_mod", 2, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
@@ -1,3 +1,5 @@
+use crate::describe::{Describe, PortDesc};
+use crate::params::{ParamDesc, ParamUnit, Parameterized};
 use auxide::graph::{Port, PortId, Rate};
 use auxide::node::NodeDef;
 
@@ -73,6 +75,50 @@ impl NodeDef for RingMod {
     }
 }
 
+impl Parameterized for RingMod {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[ParamDesc::new(
+            0,
+            "mix",
+            0.0,
+            1.0,
+            1.0,
+            ParamUnit::Normalized,
+        )];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        (id == 0).then_some(self.mix)
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        if id == 0 {
+            self.mix = value;
+        }
+    }
+}
+
+impl Describe for RingMod {
+    fn name(&self) -> &'static str {
+        "RingMod"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::audio(1, "modulator"),
+            PortDesc::modulation(2, "mix_mod", 0, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 /// State of a Crossfader
 #[derive(Debug, Clone)]
 pub struct CrossfaderState;
@@ -141,6 +187,50 @@ impl NodeDef for Crossfader {
     }
 }
 
+impl Parameterized for Crossfader {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[ParamDesc::new(
+            0,
+            "position",
+            0.0,
+            1.0,
+            0.5,
+            ParamUnit::Normalized,
+        )];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        (id == 0).then_some(self.position)
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        if id == 0 {
+            self.position = value;
+        }
+    }
+}
+
+impl Describe for Crossfader {
+    fn name(&self) -> &'static str {
+        "Crossfader"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "a"),
+            PortDesc::audio(1, "b"),
+            PortDesc::modulation(2, "position_mod", 0, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "out")];
+        PORTS
+    }
+}
+
 /// State of a Stereo Width
 #[derive(Debug, Clone)]
 pub struct StereoWidthState;
@@ -220,6 +310,50 @@ impl NodeDef for StereoWidth {
     }
 }
 
+impl Parameterized for StereoWidth {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[ParamDesc::new(
+            0,
+            "width",
+            0.0,
+            2.0,
+            1.0,
+            ParamUnit::Normalized,
+        )];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        (id == 0).then_some(self.width)
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        if id == 0 {
+            self.width = value;
+        }
+    }
+}
+
+impl Describe for StereoWidth {
+    fn name(&self) -> &'static str {
+        "StereoWidth"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "left"),
+            PortDesc::audio(1, "right"),
+            PortDesc::modulation(2, "width_mod", 0, ParamUnit::Normalized),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "left"), PortDesc::audio(1, "right")];
+        PORTS
+    }
+}
+
 /// State of a Parameter Smoother
 #[derive(Debug, Clone)]
 pub struct ParamSmootherState {
@@ -278,6 +412,46 @@ impl NodeDef for ParamSmoother {
     }
 }
 
+impl Parameterized for ParamSmoother {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[ParamDesc::new(
+            0,
+            "smoothing",
+            0.0,
+            1000.0,
+            10.0,
+            ParamUnit::Milliseconds,
+        )];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        (id == 0).then_some(self.smoothing)
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        if id == 0 {
+            self.smoothing = value;
+        }
+    }
+}
+
+impl Describe for ParamSmoother {
+    fn name(&self) -> &'static str {
+        "ParamSmoother"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::control(0, "input", ParamUnit::None)];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::control(0, "out", ParamUnit::None)];
+        PORTS
+    }
+}
+
 /// State of a MidSideProcessor
 #[derive(Debug, Clone)]
 pub struct MidSideProcessorState;
@@ -344,6 +518,34 @@ impl NodeDef for MidSideProcessor {
     }
 }
 
+impl Parameterized for MidSideProcessor {
+    fn params(&self) -> &'static [ParamDesc] {
+        &[]
+    }
+
+    fn get_param(&self, _id: usize) -> Option<f32> {
+        None
+    }
+
+    fn set_param(&mut self, _id: usize, _value: f32) {}
+}
+
+impl Describe for MidSideProcessor {
+    fn name(&self) -> &'static str {
+        "MidSideProcessor"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "left"), PortDesc::audio(1, "right")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "mid"), PortDesc::audio(1, "side")];
+        PORTS
+    }
+}
+
 /// State of a StereoPanner
 #[derive(Debug, Clone)]
 pub struct StereoPannerState;
@@ -414,6 +616,42 @@ impl NodeDef for StereoPanner {
     }
 }
 
+impl Parameterized for StereoPanner {
+    fn params(&self) -> &'static [ParamDesc] {
+        const PARAMS: &[ParamDesc] = &[ParamDesc::new(0, "pan", -1.0, 1.0, 0.0, ParamUnit::None)];
+        PARAMS
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        (id == 0).then_some(self.pan)
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        if id == 0 {
+            self.pan = value;
+        }
+    }
+}
+
+impl Describe for StereoPanner {
+    fn name(&self) -> &'static str {
+        "StereoPanner"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[
+            PortDesc::audio(0, "input"),
+            PortDesc::modulation(1, "pan_mod", 0, ParamUnit::None),
+        ];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "left"), PortDesc::audio(1, "right")];
+        PORTS
+    }
+}
+
 /// State of an RMSMeter
 #[derive(Debug, Clone)]
 pub struct RMSMeterState {
@@ -478,3 +716,31 @@ impl NodeDef for RMSMeter {
         }
     }
 }
+
+impl Parameterized for RMSMeter {
+    fn params(&self) -> &'static [ParamDesc] {
+        &[]
+    }
+
+    fn get_param(&self, _id: usize) -> Option<f32> {
+        None
+    }
+
+    fn set_param(&mut self, _id: usize, _value: f32) {}
+}
+
+impl Describe for RMSMeter {
+    fn name(&self) -> &'static str {
+        "RMSMeter"
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::audio(0, "input")];
+        PORTS
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        const PORTS: &[PortDesc] = &[PortDesc::control(0, "rms", ParamUnit::Gain)];
+        PORTS
+    }
+}
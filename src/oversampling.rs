@@ -0,0 +1,500 @@
+#![forbid(unsafe_code)]
+
+//! Oversampling for nonlinear processors.
+//!
+//! [`Oversampled`] wraps any node and runs it at 2x to 16x the graph rate.
+//! Each octave of rate change is a half-band stage: either a linear-phase
+//! polyphase FIR ([`HalfBandFir`]) or a low-latency polyphase allpass IIR
+//! ([`HalfBandIir`]). Every buffer and filter is allocated in `init_state`.
+
+use std::f64::consts::PI;
+
+use auxide::graph::Port;
+use auxide::node::NodeDef;
+
+use crate::describe::{Describe, PortDesc};
+use crate::params::{ParamDesc, Parameterized};
+
+/// Maximum number of input ports forwarded to the wrapped node.
+const MAX_OVERSAMPLED_INPUTS: usize = 16;
+
+/// Length of the half-band FIR; of the form 4m + 3.
+const FIR_TAPS: usize = 63;
+
+/// Kaiser window beta for the FIR, about 80 dB stopband.
+const FIR_KAISER_BETA: f64 = 7.86;
+
+/// Allpass coefficients of the IIR, split between its two branches.
+const IIR_COEFS: usize = 10;
+
+/// Transition band of the IIR as a fraction of the oversampled rate.
+const IIR_TRANSITION: f64 = 0.04;
+
+/// Oversampling ratio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OversampleFactor {
+    X2,
+    X4,
+    X8,
+    X16,
+}
+
+impl OversampleFactor {
+    /// Number of half-band stages.
+    pub const fn stages(self) -> usize {
+        match self {
+            OversampleFactor::X2 => 1,
+            OversampleFactor::X4 => 2,
+            OversampleFactor::X8 => 3,
+            OversampleFactor::X16 => 4,
+        }
+    }
+
+    /// Rate multiplier.
+    pub const fn ratio(self) -> usize {
+        1 << self.stages()
+    }
+}
+
+/// Half-band filter design used by each stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OversampleFilter {
+    /// Linear-phase polyphase FIR; constant latency, more of it.
+    Fir,
+    /// Polyphase allpass IIR; little latency, nonlinear phase near the band edge.
+    Iir,
+}
+
+fn bessel_i0(x: f64) -> f64 {
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let mut k = 1.0;
+    while term > sum * 1e-12 {
+        term *= (x / (2.0 * k)) * (x / (2.0 * k));
+        sum += term;
+        k += 1.0;
+    }
+    sum
+}
+
+/// Kaiser-windowed half-band FIR taps at odd offsets from the centre tap
+/// (the other even offsets are zero and the centre is 0.5).
+fn fir_phase_taps() -> Vec<f32> {
+    let centre = (FIR_TAPS - 1) / 2;
+    let norm = bessel_i0(FIR_KAISER_BETA);
+    (0..FIR_TAPS)
+        .step_by(2)
+        .map(|j| {
+            let x = (j as f64 - centre as f64) / 2.0;
+            let sinc = (PI * x).sin() / (PI * x);
+            let r = (j as f64 - centre as f64) / centre as f64;
+            let window = bessel_i0(FIR_KAISER_BETA * (1.0 - r * r).max(0.0).sqrt()) / norm;
+            (0.5 * sinc * window) as f32
+        })
+        .collect()
+}
+
+/// Polyphase half-band FIR for one direction of one stage.
+#[derive(Debug, Clone)]
+pub struct HalfBandFir {
+    taps: Vec<f32>,
+    /// Doubled history so the newest-first window is contiguous.
+    history: Vec<f32>,
+    odd: Vec<f32>,
+    pos: usize,
+    odd_pos: usize,
+}
+
+impl HalfBandFir {
+    pub fn new() -> Self {
+        let taps = fir_phase_taps();
+        let len = taps.len();
+        Self {
+            taps,
+            history: vec![0.0; 2 * len],
+            odd: vec![0.0; 2 * len],
+            pos: 0,
+            odd_pos: 0,
+        }
+    }
+
+    /// Delay of one pass, in samples at the higher rate.
+    pub fn latency() -> f32 {
+        ((FIR_TAPS - 1) / 2) as f32
+    }
+
+    fn push(buffer: &mut [f32], pos: &mut usize, value: f32) {
+        let len = buffer.len() / 2;
+        *pos = (*pos + len - 1) % len;
+        buffer[*pos] = value;
+        buffer[*pos + len] = value;
+    }
+
+    fn even_phase(&self) -> f32 {
+        let window = &self.history[self.pos..self.pos + self.taps.len()];
+        window.iter().zip(&self.taps).map(|(x, h)| x * h).sum()
+    }
+
+    /// One input sample in, two output samples out.
+    pub fn upsample(&mut self, input: f32) -> (f32, f32) {
+        Self::push(&mut self.history, &mut self.pos, input);
+        let centre = self.history[self.pos + (FIR_TAPS - 3) / 4];
+        (2.0 * self.even_phase(), centre)
+    }
+
+    /// Two input samples in, one output sample out.
+    pub fn downsample(&mut self, first: f32, second: f32) -> f32 {
+        Self::push(&mut self.history, &mut self.pos, first);
+        let centre = self.odd[self.odd_pos + (FIR_TAPS - 3) / 4];
+        let out = self.even_phase() + 0.5 * centre;
+        Self::push(&mut self.odd, &mut self.odd_pos, second);
+        out
+    }
+}
+
+impl Default for HalfBandFir {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Allpass coefficients for a half-band with the given number of sections
+/// and transition band, after the elliptic design used by de Soras' HIIR.
+fn iir_coefs(count: usize, transition: f64) -> Vec<f32> {
+    let k = ((1.0 - 2.0 * transition) * PI / 4.0).tan().powi(2);
+    let kk = (1.0 - k * k).powf(0.25);
+    let e = 0.5 * (1.0 - kk) / (1.0 + kk);
+    let e4 = e.powi(4);
+    let q = e * (1.0 + e4 * (2.0 + e4 * (15.0 + 150.0 * e4)));
+    let order = (2 * count + 1) as f64;
+    (1..=count)
+        .map(|c| {
+            let c = c as f64;
+            let mut num = 0.0;
+            let mut sign = 1.0;
+            for i in 0..64 {
+                let i = i as f64;
+                num += sign * q.powf(i * (i + 1.0)) * ((2.0 * i + 1.0) * c * PI / order).sin();
+                sign = -sign;
+            }
+            let mut den = 0.5;
+            let mut sign = -1.0;
+            for i in 1..64 {
+                let i = i as f64;
+                den += sign * q.powf(i * i) * (2.0 * i * c * PI / order).cos();
+                sign = -sign;
+            }
+            let w = num * q.powf(0.25) / den;
+            let w2 = w * w;
+            let x = ((1.0 - w2 * k) * (1.0 - w2 / k)).sqrt() / (1.0 + w2);
+            ((1.0 - x) / (1.0 + x)) as f32
+        })
+        .collect()
+}
+
+/// Polyphase allpass half-band IIR for one direction of one stage.
+#[derive(Debug, Clone)]
+pub struct HalfBandIir {
+    coefs: Vec<f32>,
+    /// Previous input and output of each allpass section.
+    x1: Vec<f32>,
+    y1: Vec<f32>,
+}
+
+impl HalfBandIir {
+    pub fn new() -> Self {
+        let coefs = iir_coefs(IIR_COEFS, IIR_TRANSITION);
+        let len = coefs.len();
+        Self {
+            coefs,
+            x1: vec![0.0; len],
+            y1: vec![0.0; len],
+        }
+    }
+
+    /// Low-frequency group delay of one pass, in samples at the higher rate.
+    /// The branches are offset by one sample, which adds half a sample on
+    /// the way up and takes it back on the way down; this is the mean.
+    pub fn latency() -> f32 {
+        let coefs = iir_coefs(IIR_COEFS, IIR_TRANSITION);
+        let branch = |first: usize| -> f32 {
+            coefs
+                .iter()
+                .skip(first)
+                .step_by(2)
+                .map(|c| 2.0 * (1.0 - c) / (1.0 + c))
+                .sum()
+        };
+        (branch(0) + branch(1)) / 2.0
+    }
+
+    /// Run both branches; section `i` belongs to branch `i % 2`.
+    fn branches(&mut self, mut even: f32, mut odd: f32) -> (f32, f32) {
+        for (i, c) in self.coefs.iter().enumerate() {
+            let x = if i % 2 == 0 { &mut even } else { &mut odd };
+            let y = c * (*x - self.y1[i]) + self.x1[i];
+            self.x1[i] = *x;
+            self.y1[i] = y;
+            *x = y;
+        }
+        (even, odd)
+    }
+
+    /// One input sample in, two output samples out.
+    pub fn upsample(&mut self, input: f32) -> (f32, f32) {
+        self.branches(input, input)
+    }
+
+    /// Two input samples in, one output sample out.
+    pub fn downsample(&mut self, first: f32, second: f32) -> f32 {
+        let (a, b) = self.branches(second, first);
+        0.5 * (a + b)
+    }
+}
+
+impl Default for HalfBandIir {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One half-band stage of either design.
+#[derive(Debug, Clone)]
+pub enum HalfBand {
+    Fir(HalfBandFir),
+    Iir(HalfBandIir),
+}
+
+impl HalfBand {
+    pub fn new(filter: OversampleFilter) -> Self {
+        match filter {
+            OversampleFilter::Fir => HalfBand::Fir(HalfBandFir::new()),
+            OversampleFilter::Iir => HalfBand::Iir(HalfBandIir::new()),
+        }
+    }
+
+    /// Upsample `input` into `output`, which must be twice as long.
+    pub fn upsample(&mut self, input: &[f32], output: &mut [f32]) {
+        for (x, pair) in input.iter().zip(output.chunks_exact_mut(2)) {
+            let (a, b) = match self {
+                HalfBand::Fir(fir) => fir.upsample(*x),
+                HalfBand::Iir(iir) => iir.upsample(*x),
+            };
+            pair[0] = a;
+            pair[1] = b;
+        }
+    }
+
+    /// Downsample `input` into `output`, which must be half as long.
+    pub fn downsample(&mut self, input: &[f32], output: &mut [f32]) {
+        for (pair, y) in input.chunks_exact(2).zip(output.iter_mut()) {
+            *y = match self {
+                HalfBand::Fir(fir) => fir.downsample(pair[0], pair[1]),
+                HalfBand::Iir(iir) => iir.downsample(pair[0], pair[1]),
+            };
+        }
+    }
+}
+
+/// Runs a node at a multiple of the graph sample rate.
+///
+/// Connected inputs are upsampled through the half-band chain, the wrapped
+/// node processes the oversampled block, and its outputs are filtered and
+/// decimated back. Unconnected inputs stay unconnected. The added delay is
+/// reported by [`Oversampled::latency`].
+#[derive(Debug, Clone)]
+pub struct Oversampled<T> {
+    pub node: T,
+    pub factor: OversampleFactor,
+    pub filter: OversampleFilter,
+}
+
+/// State of an Oversampled node
+pub struct OversampledState<T: NodeDef> {
+    pub inner: T::State,
+    /// Per port, one filter per stage, lowest rate first.
+    pub up: Vec<Vec<HalfBand>>,
+    pub down: Vec<Vec<HalfBand>>,
+    pub block_size: usize,
+    inputs: Vec<Vec<f32>>,
+    outputs: Vec<Vec<f32>>,
+    scratch: Vec<f32>,
+}
+
+impl<T: NodeDef> Oversampled<T> {
+    pub fn new(node: T, factor: OversampleFactor, filter: OversampleFilter) -> Self {
+        Self {
+            node,
+            factor,
+            filter,
+        }
+    }
+
+    /// Delay added by the up- and downsampling chain, in samples at the
+    /// graph rate. For the IIR this is the group delay at low frequencies.
+    pub fn latency(&self) -> f32 {
+        let per_pass = match self.filter {
+            OversampleFilter::Fir => HalfBandFir::latency(),
+            OversampleFilter::Iir => HalfBandIir::latency(),
+        };
+        (1..=self.factor.stages())
+            .map(|stage| 2.0 * per_pass / (1 << stage) as f32)
+            .sum()
+    }
+}
+
+impl<T: NodeDef> NodeDef for Oversampled<T> {
+    type State = OversampledState<T>;
+
+    fn input_ports(&self) -> &'static [Port] {
+        self.node.input_ports()
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        self.node.output_ports()
+    }
+
+    fn required_inputs(&self) -> usize {
+        self.node.required_inputs()
+    }
+
+    fn init_state(&self, sample_rate: f32, block_size: usize) -> Self::State {
+        // process_block walks the graph block in chunks of this size
+        let block_size = block_size.max(1);
+        let ratio = self.factor.ratio();
+        let stages = || {
+            (0..self.factor.stages())
+                .map(|_| HalfBand::new(self.filter))
+                .collect::<Vec<_>>()
+        };
+        let num_inputs = self.node.input_ports().len().min(MAX_OVERSAMPLED_INPUTS);
+        let num_outputs = self.node.output_ports().len();
+        OversampledState {
+            inner: self
+                .node
+                .init_state(sample_rate * ratio as f32, block_size * ratio),
+            up: (0..num_inputs).map(|_| stages()).collect(),
+            down: (0..num_outputs).map(|_| stages()).collect(),
+            block_size,
+            inputs: vec![vec![0.0; block_size * ratio]; num_inputs],
+            outputs: vec![vec![0.0; block_size * ratio]; num_outputs],
+            scratch: vec![0.0; block_size * ratio],
+        }
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        sample_rate: f32,
+    ) {
+        let ratio = self.factor.ratio();
+        let stages = self.factor.stages();
+        let frames = outputs.first().map_or(0, |o| o.len());
+        let OversampledState {
+            inner,
+            up,
+            down,
+            block_size,
+            inputs: up_inputs,
+            outputs: up_outputs,
+            scratch,
+        } = state;
+
+        let mut start = 0;
+        while start < frames {
+            let n = (frames - start).min(*block_size);
+            let high = n * ratio;
+
+            // Upsample each connected input, finishing in its port buffer
+            let num_inputs = inputs.len().min(up_inputs.len());
+            for port in 0..num_inputs {
+                let Some(input) = inputs[port].get(start..start + n) else {
+                    continue;
+                };
+                let buffer = &mut up_inputs[port];
+                for (stage, filter) in up[port].iter_mut().enumerate() {
+                    let len = n << stage;
+                    let into_buffer = (stages - stage) % 2 == 1;
+                    match (stage, into_buffer) {
+                        (0, true) => filter.upsample(input, &mut buffer[..2 * len]),
+                        (0, false) => filter.upsample(input, &mut scratch[..2 * len]),
+                        (_, true) => filter.upsample(&scratch[..len], &mut buffer[..2 * len]),
+                        (_, false) => filter.upsample(&buffer[..len], &mut scratch[..2 * len]),
+                    }
+                }
+            }
+
+            let mut inner_inputs: [&[f32]; MAX_OVERSAMPLED_INPUTS] = [&[]; MAX_OVERSAMPLED_INPUTS];
+            for (port, slot) in inner_inputs.iter_mut().enumerate().take(num_inputs) {
+                if inputs[port].len() >= start + n {
+                    *slot = &up_inputs[port][..high];
+                }
+            }
+            for out in up_outputs.iter_mut() {
+                out.resize(high, 0.0);
+            }
+            self.node.process_block(
+                inner,
+                &inner_inputs[..num_inputs],
+                up_outputs,
+                sample_rate * ratio as f32,
+            );
+            for out in up_outputs.iter_mut() {
+                out.resize(*block_size * ratio, 0.0);
+            }
+
+            // Decimate each output, highest rate first, ending in the caller's buffer
+            for ((out, buffer), filters) in outputs
+                .iter_mut()
+                .zip(up_outputs.iter_mut())
+                .zip(down.iter_mut())
+            {
+                for (stage, filter) in filters.iter_mut().enumerate().rev() {
+                    let len = n << stage;
+                    let from_buffer = (stages - stage) % 2 == 1;
+                    match (stage, from_buffer) {
+                        (0, true) => {
+                            filter.downsample(&buffer[..2 * len], &mut out[start..start + n])
+                        }
+                        (0, false) => {
+                            filter.downsample(&scratch[..2 * len], &mut out[start..start + n])
+                        }
+                        (_, true) => filter.downsample(&buffer[..2 * len], &mut scratch[..len]),
+                        (_, false) => filter.downsample(&scratch[..2 * len], &mut buffer[..len]),
+                    }
+                }
+            }
+            start += n;
+        }
+    }
+}
+
+impl<T: NodeDef + Parameterized> Parameterized for Oversampled<T> {
+    fn params(&self) -> &'static [ParamDesc] {
+        self.node.params()
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        self.node.get_param(id)
+    }
+
+    fn set_param(&mut self, id: usize, value: f32) {
+        self.node.set_param(id, value)
+    }
+}
+
+impl<T: NodeDef + Describe> Describe for Oversampled<T> {
+    fn name(&self) -> &'static str {
+        self.node.name()
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        self.node.inputs()
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        self.node.outputs()
+    }
+}
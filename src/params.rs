@@ -0,0 +1,324 @@
+#![forbid(unsafe_code)]
+
+//! Runtime parameter control for DSP nodes.
+//!
+//! Nodes declare their parameters through [`Parameterized`]. Wrapping a node in
+//! [`ParamNode`] yields a [`ParamHandle`] the host can write from any thread;
+//! the audio thread picks the values up at the start of each block and ramps
+//! towards them once per block, so no graph rebuild or plan recompile is
+//! needed.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+use auxide::graph::Port;
+use auxide::node::NodeDef;
+
+use crate::describe::{Describe, PortDesc};
+use crate::helpers::compute_exponential_coefficient;
+
+/// Default parameter smoothing time in milliseconds.
+pub const DEFAULT_SMOOTHING_MS: f32 = 20.0;
+
+/// Unit of a node parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamUnit {
+    /// Unitless value.
+    None,
+    /// Frequency in Hz.
+    Hertz,
+    /// Time in milliseconds.
+    Milliseconds,
+    /// Level in decibels.
+    Decibels,
+    /// Linear gain factor.
+    Gain,
+    /// Pitch offset in semitones.
+    Semitones,
+    /// Pitch offset in octaves (1.0 per octave, as in 1 V/oct).
+    Octaves,
+    /// Ratio such as a compression ratio (n:1).
+    Ratio,
+    /// Normalized amount, typically 0..1 (mix, depth, resonance).
+    Normalized,
+    /// Bit depth.
+    Bits,
+}
+
+/// Descriptor of a single node parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParamDesc {
+    pub id: usize,
+    pub name: &'static str,
+    pub min: f32,
+    pub max: f32,
+    pub default: f32,
+    pub unit: ParamUnit,
+}
+
+impl ParamDesc {
+    pub const fn new(
+        id: usize,
+        name: &'static str,
+        min: f32,
+        max: f32,
+        default: f32,
+        unit: ParamUnit,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            min,
+            max,
+            default,
+            unit,
+        }
+    }
+
+    /// Clamp a value into this parameter's valid range.
+    pub fn clamp(&self, value: f32) -> f32 {
+        value.clamp(self.min, self.max)
+    }
+}
+
+/// Nodes whose parameters can be read and written by id.
+///
+/// Only continuous parameters are exposed; structural settings that size
+/// buffers in `init_state` (delay lengths, voice counts, tables) are not.
+pub trait Parameterized {
+    /// Parameter descriptors, indexed by `ParamDesc::id`.
+    fn params(&self) -> &'static [ParamDesc];
+
+    /// Current value of a parameter, or `None` for an unknown id.
+    fn get_param(&self, id: usize) -> Option<f32>;
+
+    /// Set a parameter; unknown ids are ignored.
+    fn set_param(&mut self, id: usize, value: f32);
+}
+
+/// Host-side handle for pushing parameter changes to a [`ParamNode`].
+///
+/// Writes are single atomic stores, so the handle can be used from a UI or
+/// MIDI thread while the audio thread is running.
+#[derive(Clone)]
+pub struct ParamHandle {
+    params: &'static [ParamDesc],
+    values: Arc<[AtomicU32]>,
+}
+
+impl ParamHandle {
+    /// Parameter descriptors of the controlled node.
+    pub fn params(&self) -> &'static [ParamDesc] {
+        self.params
+    }
+
+    /// Look up a parameter id by name.
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.params.iter().find(|p| p.name == name).map(|p| p.id)
+    }
+
+    /// Set a parameter target; the value is clamped to its range.
+    /// Returns false for an unknown id or a NaN value.
+    pub fn set(&self, id: usize, value: f32) -> bool {
+        let Some(index) = self.params.iter().position(|p| p.id == id) else {
+            return false;
+        };
+        if value.is_nan() {
+            return false;
+        }
+        let value = self.params[index].clamp(value);
+        self.values[index].store(value.to_bits(), Ordering::Relaxed);
+        true
+    }
+
+    /// Set a parameter target by name.
+    pub fn set_by_name(&self, name: &str, value: f32) -> bool {
+        match self.find(name) {
+            Some(id) => self.set(id, value),
+            None => false,
+        }
+    }
+
+    /// Last target written for a parameter.
+    pub fn get(&self, id: usize) -> Option<f32> {
+        let index = self.params.iter().position(|p| p.id == id)?;
+        Some(f32::from_bits(self.values[index].load(Ordering::Relaxed)))
+    }
+}
+
+/// One-pole smoothed parameter value.
+#[derive(Debug, Clone, Copy)]
+pub struct SmoothedValue {
+    pub current: f32,
+    pub target: f32,
+}
+
+impl SmoothedValue {
+    pub fn new(value: f32) -> Self {
+        Self {
+            current: value,
+            target: value,
+        }
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.current == self.target
+    }
+
+    /// Advance one sample towards the target; snaps once close enough, or
+    /// once rounding stalls the step short of the target.
+    pub fn step(&mut self, coeff: f32) -> f32 {
+        let next = self.target + (self.current - self.target) * coeff;
+        if next == self.current || (next - self.target).abs() <= 1.0e-5 * self.target.abs().max(1.0)
+        {
+            self.current = self.target;
+        } else {
+            self.current = next;
+        }
+        self.current
+    }
+}
+
+/// Wraps a node so its parameters can be changed at runtime.
+#[derive(Clone)]
+pub struct ParamNode<T> {
+    pub node: T,
+    pub smoothing_ms: f32,
+    values: Arc<[AtomicU32]>,
+}
+
+/// State of a ParamNode
+pub struct ParamNodeState<T: NodeDef> {
+    pub node: T,
+    pub inner: T::State,
+    pub smoothed: Vec<SmoothedValue>,
+    pub coeff: f32,
+}
+
+impl<T: NodeDef + Parameterized + Clone> ParamNode<T> {
+    /// Wrap a node, returning the node and a handle for the host.
+    pub fn new(node: T) -> (Self, ParamHandle) {
+        let params = node.params();
+        let values: Arc<[AtomicU32]> = params
+            .iter()
+            .map(|p| {
+                let value = node.get_param(p.id).unwrap_or(p.default);
+                AtomicU32::new(value.to_bits())
+            })
+            .collect();
+        let handle = ParamHandle {
+            params,
+            values: values.clone(),
+        };
+        (
+            Self {
+                node,
+                smoothing_ms: DEFAULT_SMOOTHING_MS,
+                values,
+            },
+            handle,
+        )
+    }
+
+    pub fn with_smoothing_ms(mut self, smoothing_ms: f32) -> Self {
+        self.smoothing_ms = smoothing_ms;
+        self
+    }
+}
+
+impl<T: NodeDef + Parameterized + Clone> NodeDef for ParamNode<T> {
+    type State = ParamNodeState<T>;
+
+    fn input_ports(&self) -> &'static [Port] {
+        self.node.input_ports()
+    }
+
+    fn output_ports(&self) -> &'static [Port] {
+        self.node.output_ports()
+    }
+
+    fn required_inputs(&self) -> usize {
+        self.node.required_inputs()
+    }
+
+    fn init_state(&self, sample_rate: f32, block_size: usize) -> Self::State {
+        let node = self.node.clone();
+        let smoothed = node
+            .params()
+            .iter()
+            .map(|p| SmoothedValue::new(node.get_param(p.id).unwrap_or(p.default)))
+            .collect();
+        ParamNodeState {
+            inner: node.init_state(sample_rate, block_size),
+            node,
+            smoothed,
+            coeff: compute_exponential_coefficient(self.smoothing_ms, sample_rate),
+        }
+    }
+
+    fn process_block(
+        &self,
+        state: &mut Self::State,
+        inputs: &[&[f32]],
+        outputs: &mut [Vec<f32>],
+        sample_rate: f32,
+    ) {
+        // Pick up host changes
+        for (smoothed, slot) in state.smoothed.iter_mut().zip(self.values.iter()) {
+            smoothed.target = f32::from_bits(slot.load(Ordering::Relaxed));
+        }
+
+        // Ramping: advance each ramp across the block and hand the inner node
+        // the value it reaches, keeping the host block whole
+        let frames = outputs.first().map_or(0, |o| o.len());
+        let params = state.node.params();
+        for (smoothed, desc) in state.smoothed.iter_mut().zip(params) {
+            if !smoothed.is_settled() {
+                let mut value = smoothed.current;
+                for _ in 0..frames {
+                    value = smoothed.step(state.coeff);
+                }
+                state.node.set_param(desc.id, value);
+            }
+        }
+        state
+            .node
+            .process_block(&mut state.inner, inputs, outputs, sample_rate);
+    }
+}
+
+impl<T: NodeDef + Parameterized + Clone> Parameterized for ParamNode<T> {
+    fn params(&self) -> &'static [ParamDesc] {
+        self.node.params()
+    }
+
+    fn get_param(&self, id: usize) -> Option<f32> {
+        let index = self.params().iter().position(|p| p.id == id)?;
+        Some(f32::from_bits(self.values[index].load(Ordering::Relaxed)))
+    }
+
+    /// Sets the shared target, exactly like `ParamHandle::set`.
+    fn set_param(&mut self, id: usize, value: f32) {
+        let params = self.params();
+        if let Some(index) = params.iter().position(|p| p.id == id) {
+            if !value.is_nan() {
+                let value = params[index].clamp(value);
+                self.values[index].store(value.to_bits(), Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+impl<T: NodeDef + Describe + Clone> Describe for ParamNode<T> {
+    fn name(&self) -> &'static str {
+        self.node.name()
+    }
+
+    fn inputs(&self) -> &'static [PortDesc] {
+        self.node.inputs()
+    }
+
+    fn outputs(&self) -> &'static [PortDesc] {
+        self.node.outputs()
+    }
+}
@@ -0,0 +1,158 @@
+#![forbid(unsafe_code)]
+
+//! Minimal WAV reader for loading wavetables and samples.
+//!
+//! Supports PCM (8/16/24/32-bit) and IEEE float (32/64-bit) data, including
+//! `WAVE_FORMAT_EXTENSIBLE` files. Samples are converted to `f32` in -1..1.
+//! Loading allocates and must not happen on the audio thread.
+
+use std::fmt;
+use std::path::Path;
+
+/// Errors when reading a WAV file.
+#[derive(Debug)]
+pub enum WavError {
+    Io(std::io::Error),
+    /// The data is not a WAV file this reader understands.
+    Format(&'static str),
+}
+
+impl fmt::Display for WavError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WavError::Io(e) => write!(f, "wav io error: {}", e),
+            WavError::Format(msg) => write!(f, "invalid wav: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for WavError {}
+
+impl From<std::io::Error> for WavError {
+    fn from(e: std::io::Error) -> Self {
+        WavError::Io(e)
+    }
+}
+
+/// Decoded WAV audio.
+#[derive(Debug, Clone)]
+pub struct WavData {
+    pub sample_rate: u32,
+    pub channels: u16,
+    /// Interleaved samples.
+    pub samples: Vec<f32>,
+}
+
+impl WavData {
+    /// Number of sample frames.
+    pub fn frames(&self) -> usize {
+        self.samples.len() / self.channels.max(1) as usize
+    }
+
+    /// Samples of one channel.
+    pub fn channel(&self, index: usize) -> Vec<f32> {
+        let channels = self.channels.max(1) as usize;
+        self.samples
+            .iter()
+            .skip(index)
+            .step_by(channels)
+            .copied()
+            .collect()
+    }
+
+    /// Average of all channels.
+    pub fn to_mono(&self) -> Vec<f32> {
+        let channels = self.channels.max(1) as usize;
+        self.samples
+            .chunks_exact(channels)
+            .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+            .collect()
+    }
+}
+
+/// Read a WAV file from disk.
+pub fn read_wav(path: impl AsRef<Path>) -> Result<WavData, WavError> {
+    let bytes = std::fs::read(path)?;
+    parse_wav(&bytes)
+}
+
+/// Decode WAV file contents.
+pub fn parse_wav(bytes: &[u8]) -> Result<WavData, WavError> {
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return Err(WavError::Format("missing RIFF/WAVE header"));
+    }
+
+    let mut format = None;
+    let mut data = None;
+    let mut pos = 12;
+    while pos + 8 <= bytes.len() {
+        let id = &bytes[pos..pos + 4];
+        let len = u32::from_le_bytes([
+            bytes[pos + 4],
+            bytes[pos + 5],
+            bytes[pos + 6],
+            bytes[pos + 7],
+        ]) as usize;
+        let start = pos + 8;
+        let end = start.saturating_add(len).min(bytes.len());
+        match id {
+            b"fmt " => format = Some(&bytes[start..end]),
+            b"data" => data = Some(&bytes[start..end]),
+            _ => {}
+        }
+        // Chunks are padded to an even length
+        pos = start.saturating_add(len).saturating_add(len & 1);
+    }
+
+    let format = format.ok_or(WavError::Format("missing fmt chunk"))?;
+    let data = data.ok_or(WavError::Format("missing data chunk"))?;
+    if format.len() < 16 {
+        return Err(WavError::Format("fmt chunk too short"));
+    }
+    let u16_at = |i: usize| u16::from_le_bytes([format[i], format[i + 1]]);
+    let mut tag = u16_at(0);
+    let channels = u16_at(2);
+    let sample_rate = u32::from_le_bytes([format[4], format[5], format[6], format[7]]);
+    let bits = u16_at(14);
+    if tag == 0xFFFE {
+        // WAVE_FORMAT_EXTENSIBLE: the sub-format GUID starts with the tag
+        if format.len() < 26 {
+            return Err(WavError::Format("extensible fmt chunk too short"));
+        }
+        tag = u16_at(24);
+    }
+    if channels == 0 {
+        return Err(WavError::Format("zero channels"));
+    }
+
+    let samples: Vec<f32> = match (tag, bits) {
+        (1, 8) => data.iter().map(|&b| (b as f32 - 128.0) / 128.0).collect(),
+        (1, 16) => data
+            .chunks_exact(2)
+            .map(|b| i16::from_le_bytes([b[0], b[1]]) as f32 / 32768.0)
+            .collect(),
+        (1, 24) => data
+            .chunks_exact(3)
+            .map(|b| (i32::from_le_bytes([0, b[0], b[1], b[2]]) >> 8) as f32 / 8_388_608.0)
+            .collect(),
+        (1, 32) => data
+            .chunks_exact(4)
+            .map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f32 / 2_147_483_648.0)
+            .collect(),
+        (3, 32) => data
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect(),
+        (3, 64) => data
+            .chunks_exact(8)
+            .map(|b| f64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]) as f32)
+            .collect(),
+        _ => return Err(WavError::Format("unsupported sample format")),
+    };
+
+    Ok(WavData {
+        sample_rate,
+        channels,
+        samples,
+    })
+}
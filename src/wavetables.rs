@@ -1,5 +1,13 @@
 #![forbid(unsafe_code)]
 
+use std::path::Path;
+
+use num_complex::Complex;
+use realfft::RealFftPlanner;
+
+use crate::helpers::cubic_interpolate;
+use crate::wav::{read_wav, WavError};
+
 /// Generate a sine wavetable of the given size.
 pub fn generate_sine_table(size: usize) -> Vec<f32> {
     (0..size)
@@ -33,3 +41,186 @@ pub fn generate_triangle_table(size: usize) -> Vec<f32> {
         })
         .collect()
 }
+
+/// Per-octave band-limited mipmaps of a single-cycle wavetable.
+///
+/// Level `k` keeps the harmonics below `size / 2^(k+1)`, so it plays without
+/// aliasing while the phase increment is at most `2^k` table samples per
+/// output sample. All levels have the source table's length.
+#[derive(Debug, Clone)]
+pub struct MipmappedWavetable {
+    levels: Vec<Vec<f32>>,
+}
+
+impl MipmappedWavetable {
+    /// Build the mipmaps from one cycle of a waveform via FFT.
+    pub fn new(table: &[f32]) -> Self {
+        let size = table.len();
+        if size < 4 {
+            return Self {
+                levels: vec![table.to_vec()],
+            };
+        }
+
+        let mut planner = RealFftPlanner::<f32>::new();
+        let forward = planner.plan_fft_forward(size);
+        let inverse = planner.plan_fft_inverse(size);
+
+        let mut input = table.to_vec();
+        let mut spectrum = forward.make_output_vec();
+        if forward.process(&mut input, &mut spectrum).is_err() {
+            return Self {
+                levels: vec![table.to_vec()],
+            };
+        }
+        spectrum[0].im = 0.0;
+
+        let norm = 1.0 / size as f32;
+        let mut levels = Vec::new();
+        let mut harmonics = size / 2;
+        while harmonics >= 2 {
+            let mut bins: Vec<Complex<f32>> = spectrum
+                .iter()
+                .enumerate()
+                .map(|(k, &bin)| {
+                    if k < harmonics {
+                        bin * norm
+                    } else {
+                        Complex::new(0.0, 0.0)
+                    }
+                })
+                .collect();
+            let mut level = vec![0.0; size];
+            if inverse.process(&mut bins, &mut level).is_err() {
+                break;
+            }
+            levels.push(level);
+            harmonics /= 2;
+        }
+        if levels.is_empty() {
+            levels.push(table.to_vec());
+        }
+        Self { levels }
+    }
+
+    /// Number of mip levels.
+    pub fn num_levels(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Samples of one mip level (0 is the brightest).
+    pub fn level(&self, index: usize) -> &[f32] {
+        &self.levels[index.min(self.levels.len() - 1)]
+    }
+
+    /// Length of each level in samples.
+    pub fn table_size(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Interpolated sample at `phase` (0..1) for playback at `freq` Hz.
+    ///
+    /// Level `n` holds partials up to Nyquist for table increments up to
+    /// `2^n`, so playback crossfades from level `floor(log2 inc) + 1` towards
+    /// the next darker one across each octave. Neither level played has
+    /// content above Nyquist, and sweeps have no audible level switches.
+    pub fn sample(&self, phase: f32, freq: f32, sample_rate: f32) -> f32 {
+        let size = self.table_size() as f32;
+        let pos = phase * size;
+        let inc = freq.abs() * size / sample_rate;
+        if inc <= 0.5 {
+            return cubic_interpolate(&self.levels[0], pos);
+        }
+        let octave = inc.log2();
+        let lower = (octave.floor() + 1.0) as usize;
+        let last = self.levels.len() - 1;
+        if lower >= last {
+            return cubic_interpolate(&self.levels[last], pos);
+        }
+        let frac = octave - octave.floor();
+        let a = cubic_interpolate(&self.levels[lower], pos);
+        if frac <= 0.0 {
+            return a;
+        }
+        let b = cubic_interpolate(&self.levels[lower + 1], pos);
+        a + (b - a) * frac
+    }
+}
+
+/// A stack of single-cycle frames for morphing wavetable oscillators.
+///
+/// Each frame is mipmapped on construction, so building a bank allocates
+/// and should happen off the audio thread.
+#[derive(Debug, Clone)]
+pub struct WavetableBank {
+    frames: Vec<MipmappedWavetable>,
+}
+
+impl WavetableBank {
+    /// Build a bank from single-cycle frames.
+    pub fn from_frames(frames: &[Vec<f32>]) -> Self {
+        Self {
+            frames: frames.iter().map(|f| MipmappedWavetable::new(f)).collect(),
+        }
+    }
+
+    /// Slice a sample stream into frames of `frame_size`; a trailing partial
+    /// frame is dropped.
+    pub fn from_samples(samples: &[f32], frame_size: usize) -> Self {
+        let frame_size = frame_size.max(1);
+        Self {
+            frames: samples
+                .chunks_exact(frame_size)
+                .map(MipmappedWavetable::new)
+                .collect(),
+        }
+    }
+
+    /// Build one frame per generator, e.g. `&[generate_sine_table, generate_saw_table]`.
+    pub fn from_generators(generators: &[fn(usize) -> Vec<f32>], frame_size: usize) -> Self {
+        Self {
+            frames: generators
+                .iter()
+                .map(|generate| MipmappedWavetable::new(&generate(frame_size)))
+                .collect(),
+        }
+    }
+
+    /// Load a wavetable WAV file (Serum/Vital style: frames stored back to
+    /// back), downmixed to mono and sliced into `frame_size` cycles.
+    pub fn from_wav(path: impl AsRef<Path>, frame_size: usize) -> Result<Self, WavError> {
+        let wav = read_wav(path)?;
+        let bank = Self::from_samples(&wav.to_mono(), frame_size);
+        if bank.frames.is_empty() {
+            return Err(WavError::Format("shorter than one frame"));
+        }
+        Ok(bank)
+    }
+
+    /// Number of frames.
+    pub fn num_frames(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Mipmaps of one frame.
+    pub fn frame(&self, index: usize) -> &MipmappedWavetable {
+        &self.frames[index]
+    }
+
+    /// Sample at `phase` with `position` (0..1) sweeping from the first to
+    /// the last frame, interpolating between adjacent frames.
+    pub fn sample(&self, position: f32, phase: f32, freq: f32, sample_rate: f32) -> f32 {
+        let Some(last) = self.frames.len().checked_sub(1) else {
+            return 0.0;
+        };
+        let pos = position.clamp(0.0, 1.0) * last as f32;
+        let index = (pos.floor() as usize).min(last);
+        let frac = pos - index as f32;
+        let a = self.frames[index].sample(phase, freq, sample_rate);
+        if frac <= 0.0 || index == last {
+            return a;
+        }
+        let b = self.frames[index + 1].sample(phase, freq, sample_rate);
+        a + (b - a) * frac
+    }
+}
@@ -1,6 +1,30 @@
-use auxide_dsp::builders::{EffectsChainBuilder, SynthBuilder};
+use auxide::graph::NodeType;
+use auxide::rt::Runtime;
+use auxide_dsp::builders::{BuildError, EffectsChainBuilder, SynthBuilder};
+use auxide_dsp::nodes::envelopes::AdsrEnvelope;
+use auxide_dsp::nodes::filters::{SvfFilter, SvfMode};
+use auxide_dsp::nodes::fx::{Delay, Tremolo};
+use auxide_dsp::nodes::lfo::{Lfo, LfoWaveform};
 use auxide_dsp::nodes::oscillators::SawOsc;
 
+fn svf() -> SvfFilter {
+    SvfFilter {
+        cutoff: 1000.0,
+        resonance: 0.5,
+        mode: SvfMode::Lowpass,
+    }
+}
+
+fn adsr() -> AdsrEnvelope {
+    AdsrEnvelope {
+        attack_ms: 1.0,
+        decay_ms: 10.0,
+        sustain_level: 0.7,
+        release_ms: 10.0,
+        curve: 1.0,
+    }
+}
+
 #[test]
 fn synth_builder_runs() {
     let builder = SynthBuilder::new().add_oscillator(SawOsc { freq: 440.0 });
@@ -23,8 +47,125 @@ fn effects_chain_builder_runs() {
 }
 
 #[test]
-fn effects_chain_builder_build_fails_without_connections() {
+fn effects_chain_builder_connects_input_to_output() {
     let builder = EffectsChainBuilder::new().add_input().add_output();
-    let result = builder.build(64);
-    assert!(result.is_err()); // Fails because no edges
+    let built = builder.build(64).unwrap();
+    assert_eq!(built.graph.edges.len(), 1);
+    assert_eq!(built.nodes.len(), 2);
+}
+
+#[test]
+fn synth_builder_wires_in_series() {
+    let built = SynthBuilder::new()
+        .add_oscillator(SawOsc { freq: 440.0 })
+        .add_filter(svf())
+        .add_envelope(adsr())
+        .add_output()
+        .build(64)
+        .unwrap();
+    // osc, filter, envelope, output
+    assert_eq!(built.nodes.len(), 4);
+    let (osc, filter) = (built.nodes[0], built.nodes[1]);
+    assert!(built
+        .graph
+        .edges
+        .iter()
+        .any(|e| e.from_node == osc && e.to_node == filter));
+
+    let mut runtime = Runtime::new(built.plan, &built.graph, 44100.0);
+    let mut out = vec![0.0; 64];
+    for _ in 0..8 {
+        runtime.process_block(&mut out).unwrap();
+    }
+    assert!(out.iter().all(|x| x.is_finite()));
+    assert!(out.iter().any(|&x| x.abs() > 1e-4));
+}
+
+#[test]
+fn parallel_branches_are_merged() {
+    let built = EffectsChainBuilder::new()
+        .add_input()
+        .split()
+        .parallel(|b| b)
+        .parallel(|b| {
+            b.add_effect(Delay {
+                delay_ms: 10.0,
+                feedback: 0.2,
+                mix: 1.0,
+            })
+        })
+        .parallel(|b| {
+            b.add_effect(Tremolo {
+                rate: 5.0,
+                depth: 0.5,
+            })
+        })
+        .merge()
+        .add_output()
+        .build(64)
+        .unwrap();
+    let mixers = built
+        .graph
+        .nodes
+        .iter()
+        .flatten()
+        .filter(|n| matches!(n.node_type, NodeType::Mix))
+        .count();
+    assert_eq!(mixers, 2);
+    let input = built.nodes[0];
+    assert_eq!(
+        built
+            .graph
+            .edges
+            .iter()
+            .filter(|e| e.from_node == input)
+            .count(),
+        3
+    );
+}
+
+#[test]
+fn modulator_routes_to_named_port() {
+    let builder = SynthBuilder::new()
+        .add_oscillator(SawOsc { freq: 220.0 })
+        .add_filter(svf())
+        .modulate("cutoff", adsr());
+    let filter = builder.last_node().unwrap();
+    let built = builder.build(64).unwrap();
+    let env = built.nodes[2];
+    let edge = built
+        .graph
+        .edges
+        .iter()
+        .find(|e| e.from_node == env)
+        .unwrap();
+    assert_eq!(edge.to_node, filter);
+    assert_eq!(edge.to_port.0, 1);
+}
+
+#[test]
+fn unknown_modulation_param_is_reported() {
+    let result = EffectsChainBuilder::new()
+        .add_input()
+        .add_effect(Tremolo {
+            rate: 5.0,
+            depth: 0.5,
+        })
+        .modulate(
+            "cutoff",
+            Lfo {
+                frequency: 1.0,
+                waveform: LfoWaveform::Sine,
+                amplitude: 1.0,
+                offset: 0.0,
+            },
+        )
+        .build(64);
+    assert!(matches!(result, Err(BuildError::UnknownParam { .. })));
+}
+
+#[test]
+fn merge_without_split_fails() {
+    let result = EffectsChainBuilder::new().add_input().merge().build(64);
+    assert!(matches!(result, Err(BuildError::NothingToMerge)));
 }
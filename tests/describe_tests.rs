@@ -0,0 +1,460 @@
+use std::sync::Arc;
+
+use auxide::node::NodeDef;
+use auxide_dsp::describe::{Describe, PortRole};
+use auxide_dsp::filter_design::{
+    BiquadResponse, CrossoverOrder, FilterBand, FilterDesign, FilterFamily,
+};
+use auxide_dsp::nodes::analysis::{
+    AutocorrPitchDetector, FrequencyAnalyzer, OnsetDetector, Peak, Rms, SpectralCentroid,
+};
+use auxide_dsp::nodes::dither::{Dither, DitherType, NoiseShaping};
+use auxide_dsp::nodes::dynamics::{Compressor, Expander, Limiter, NoiseGate};
+use auxide_dsp::nodes::envelopes::{AdEnvelope, AdsrEnvelope, ArEnvelope};
+use auxide_dsp::nodes::filters::{
+    AllpassFilter, BandSplitter, BiquadFilter, CascadeFilter, CombFilter, DiodeLadderFilter,
+    FormantFilter, LadderFilter, ParametricBiquad, SvfFilter, SvfMode, ZdfLadderFilter,
+    ZdfSvfFilter,
+};
+use auxide_dsp::nodes::fx::{
+    Chorus, ConvolutionReverb, Delay, Flanger, MultitapDelay, Phaser, SimpleReverb, Tremolo,
+};
+use auxide_dsp::nodes::granular::{GrainSource, Granulator};
+use auxide_dsp::nodes::lfo::{Lfo, LfoWaveform};
+use auxide_dsp::nodes::math::{
+    Abs, Add, Clamp, Cos, Div, Exp, Ln, Multiply, Offset, Pow, Scale, Sin, Sub,
+};
+use auxide_dsp::nodes::oscillators::{
+    BrownNoise, Constant, FmAlgorithm, FmVoice, PinkNoise, PulseOsc, SawOsc, SquareOsc, SuperSaw,
+    TriangleOsc, WavetableMorphOsc, WavetableOsc, WhiteNoise,
+};
+use auxide_dsp::nodes::physical::{
+    KarplusStrong, ModalPreset, ModalResonator, Waveguide, WaveguideModel,
+};
+use auxide_dsp::nodes::pitch::{PitchDetector, PitchShifter, SpectralGate};
+use auxide_dsp::nodes::sampler::SamplePlayer;
+use auxide_dsp::nodes::shapers::{
+    AdaaShaper, AntiAlias, BitCrusher, ChebyshevShaper, DcBlocker, HardClip, Overdrive, SoftClip,
+    TableShaper, TubeSaturation, WaveShaper, Wavefolder,
+};
+use auxide_dsp::nodes::tape::TapeSaturation;
+use auxide_dsp::nodes::utility::{
+    Crossfader, MidSideProcessor, ParamSmoother, RMSMeter, RingMod, StereoPanner, StereoWidth,
+};
+use auxide_dsp::oversampling::{OversampleFactor, OversampleFilter, Oversampled};
+use auxide_dsp::params::ParamNode;
+use auxide_dsp::wavetables::WavetableBank;
+
+/// Port descriptors must line up with the NodeDef ports, and every
+/// modulation port must point at a declared parameter.
+fn check<T: NodeDef + Describe>(node: T) {
+    let desc = node.describe();
+    assert_eq!(desc.inputs.len(), node.input_ports().len(), "{}", desc.name);
+    assert_eq!(
+        desc.outputs.len(),
+        node.output_ports().len(),
+        "{}",
+        desc.name
+    );
+    for (d, p) in desc.inputs.iter().zip(node.input_ports()) {
+        assert_eq!(d.id, p.id, "{}: {}", desc.name, d.name);
+    }
+    for (d, p) in desc.outputs.iter().zip(node.output_ports()) {
+        assert_eq!(d.id, p.id, "{}: {}", desc.name, d.name);
+    }
+    for port in desc.inputs {
+        if let PortRole::Modulation { param } = port.role {
+            let target = desc.params.iter().find(|p| p.id == param);
+            assert!(target.is_some(), "{}: {}", desc.name, port.name);
+            assert_eq!(target.unwrap().unit, port.unit, "{}", desc.name);
+        }
+    }
+    for (i, param) in desc.params.iter().enumerate() {
+        assert_eq!(param.id, i, "{}", desc.name);
+        assert!(param.min <= param.default && param.default <= param.max);
+        assert!(node.get_param(param.id).is_some(), "{}", desc.name);
+    }
+}
+
+#[test]
+fn oscillators_describe_ports() {
+    check(SawOsc::new(440.0));
+    check(SquareOsc {
+        freq: 440.0,
+        pulse_width: 0.5,
+    });
+    check(TriangleOsc { freq: 440.0 });
+    check(PulseOsc {
+        freq: 440.0,
+        pulse_width: 0.5,
+    });
+    check(WavetableOsc {
+        freq: 440.0,
+        table: Arc::new(vec![0.0; 8]),
+    });
+    check(WavetableMorphOsc {
+        freq: 440.0,
+        position: 0.0,
+        phase: 0.0,
+        bank: Arc::new(WavetableBank::from_frames(&[vec![0.0; 8]])),
+    });
+    check(FmVoice::new(220.0, FmAlgorithm::four_op(1)));
+    check(SuperSaw {
+        freq: 440.0,
+        detune: 0.1,
+        voices: 3,
+    });
+    check(WhiteNoise);
+    check(PinkNoise);
+    check(BrownNoise);
+    check(Constant { value: 1.0 });
+}
+
+#[test]
+fn filters_describe_ports() {
+    check(SvfFilter {
+        cutoff: 1000.0,
+        resonance: 0.5,
+        mode: SvfMode::Lowpass,
+    });
+    check(LadderFilter {
+        cutoff: 1000.0,
+        resonance: 0.5,
+        drive: 1.0,
+    });
+    check(CombFilter {
+        delay_ms: 10.0,
+        feedback: 0.5,
+        damp: 0.1,
+    });
+    check(FormantFilter {
+        freq1: 700.0,
+        freq2: 1200.0,
+        bw1: 100.0,
+        bw2: 100.0,
+        gain1: 1.0,
+        gain2: 1.0,
+    });
+    check(BiquadFilter {
+        b0: 1.0,
+        b1: 0.0,
+        b2: 0.0,
+        a1: 0.0,
+        a2: 0.0,
+    });
+    check(AllpassFilter {
+        delay_samples: 10,
+        gain: 0.5,
+    });
+    check(ParametricBiquad::new(
+        BiquadResponse::LowShelf,
+        200.0,
+        0.707,
+    ));
+    check(CascadeFilter::new(FilterDesign::new(
+        FilterFamily::Bessel,
+        FilterBand::Lowpass,
+        6,
+        1000.0,
+    )));
+    check(ZdfSvfFilter::default());
+    check(ZdfLadderFilter::default());
+    check(DiodeLadderFilter::default());
+    for frequencies in [vec![], vec![500.0, 2000.0]] {
+        check(BandSplitter::new(frequencies, CrossoverOrder::Lr4));
+    }
+    let splitter = BandSplitter::new(vec![100.0; 12], CrossoverOrder::Lr2);
+    assert_eq!(splitter.describe().outputs.len(), 8);
+}
+
+#[test]
+fn envelopes_and_lfo_describe_ports() {
+    check(AdsrEnvelope {
+        attack_ms: 10.0,
+        decay_ms: 100.0,
+        sustain_level: 0.7,
+        release_ms: 200.0,
+        curve: 0.0,
+    });
+    check(ArEnvelope {
+        attack_ms: 10.0,
+        release_ms: 200.0,
+        curve: 0.0,
+    });
+    check(AdEnvelope {
+        attack_ms: 10.0,
+        decay_ms: 200.0,
+        curve: 0.0,
+    });
+    check(Lfo {
+        frequency: 1.0,
+        waveform: LfoWaveform::Sine,
+        amplitude: 1.0,
+        offset: 0.0,
+    });
+    let env = AdsrEnvelope {
+        attack_ms: 10.0,
+        decay_ms: 100.0,
+        sustain_level: 0.7,
+        release_ms: 200.0,
+        curve: 0.0,
+    };
+    assert_eq!(env.inputs()[0].role, PortRole::Gate);
+}
+
+#[test]
+fn fx_describe_ports() {
+    check(Delay {
+        delay_ms: 100.0,
+        feedback: 0.5,
+        mix: 0.5,
+    });
+    check(Chorus {
+        delay_ms: 20.0,
+        depth_ms: 5.0,
+        rate: 1.0,
+        mix: 0.5,
+    });
+    check(Flanger {
+        delay_ms: 5.0,
+        depth_ms: 2.0,
+        rate: 0.5,
+        feedback: 0.5,
+        mix: 0.5,
+    });
+    check(Phaser {
+        rate: 0.5,
+        depth: 0.5,
+        feedback: 0.3,
+        mix: 0.5,
+    });
+    check(SimpleReverb {
+        decay: 0.5,
+        mix: 0.3,
+    });
+    check(MultitapDelay {
+        taps: vec![(10.0, 0.5)],
+        feedback: 0.3,
+        mix: 0.5,
+    });
+    check(ConvolutionReverb {
+        ir: vec![1.0],
+        mix: 0.3,
+    });
+    check(Tremolo {
+        rate: 5.0,
+        depth: 0.5,
+    });
+}
+
+#[test]
+fn dynamics_describe_ports() {
+    let comp = Compressor {
+        threshold: 0.5,
+        ratio: 4.0,
+        attack_ms: 10.0,
+        release_ms: 100.0,
+        makeup_gain: 1.0,
+    };
+    assert_eq!(comp.inputs()[1].role, PortRole::Sidechain);
+    check(comp);
+    check(Limiter {
+        threshold: 0.9,
+        attack_ms: 1.0,
+        release_ms: 50.0,
+    });
+    check(NoiseGate {
+        threshold: 0.05,
+        ratio: 10.0,
+        attack_ms: 1.0,
+        release_ms: 100.0,
+    });
+    check(Expander {
+        threshold: 0.1,
+        ratio: 2.0,
+        attack_ms: 5.0,
+        release_ms: 100.0,
+    });
+}
+
+#[test]
+fn shapers_pitch_utility_describe_ports() {
+    check(WaveShaper {
+        drive: 1.0,
+        mix: 1.0,
+    });
+    check(HardClip {
+        threshold: 0.8,
+        mix: 1.0,
+    });
+    check(BitCrusher {
+        bits: 8.0,
+        mix: 1.0,
+    });
+    check(SoftClip {
+        drive: 1.0,
+        mix: 1.0,
+    });
+    check(TubeSaturation {
+        drive: 1.0,
+        asymmetry: 0.2,
+        mix: 1.0,
+    });
+    check(DcBlocker { cutoff: 20.0 });
+    check(Overdrive {
+        drive: 1.0,
+        mix: 1.0,
+    });
+    check(
+        Wavefolder {
+            fold: 4.0,
+            bias: 0.1,
+            stages: 3,
+            mix: 1.0,
+        }
+        .with_anti_alias(AntiAlias::FirstOrder),
+    );
+    check(ChebyshevShaper::new(vec![1.0, 0.5]));
+    check(TableShaper::from_fn(|x: f32| x.tanh(), 256));
+    check(PitchShifter {
+        shift: 0.0,
+        mix: 1.0,
+    });
+    check(SpectralGate {
+        threshold: 0.1,
+        ratio: 10.0,
+    });
+    check(PitchDetector);
+    check(RingMod { mix: 1.0 });
+    check(Crossfader { position: 0.5 });
+    check(StereoWidth { width: 1.0 });
+    check(ParamSmoother { smoothing: 10.0 });
+    check(MidSideProcessor);
+    check(StereoPanner { pan: 0.0 });
+    check(RMSMeter { window_size: 10 });
+}
+
+#[test]
+fn analysis_describe_ports() {
+    check(Rms { window_size: 10 });
+    check(Peak {
+        hold_ms: 10.0,
+        release_ms: 100.0,
+    });
+    check(FrequencyAnalyzer { window_size: 10 });
+    check(SpectralCentroid { fft_size: 64 });
+    let onset = OnsetDetector {
+        threshold: 0.1,
+        sensitivity: 1.0,
+        fft_size: 64,
+    };
+    assert_eq!(onset.outputs()[0].role, PortRole::Trigger);
+    check(onset);
+    check(AutocorrPitchDetector {
+        min_freq: 50.0,
+        max_freq: 1000.0,
+        window_size: 64,
+    });
+}
+
+#[test]
+fn math_describe_ports() {
+    check(Add { inputs: 3 });
+    check(Sub { inputs: 2 });
+    check(Multiply { inputs: 8 });
+    check(Div { inputs: 2 });
+    check(Scale { factor: 1.0 });
+    check(Offset { amount: 0.0 });
+    check(Abs);
+    check(Exp);
+    check(Ln);
+    check(Pow { exponent: 2.0 });
+    check(Sin);
+    check(Cos);
+    check(Clamp {
+        min: -1.0,
+        max: 1.0,
+    });
+    assert_eq!(Add { inputs: 4 }.inputs().len(), 4);
+}
+
+#[test]
+fn physical_describe_ports() {
+    check(KarplusStrong {
+        freq: 220.0,
+        damping: 0.5,
+        decay_ms: 2000.0,
+        stretch: 0.0,
+        pick_position: 0.0,
+    });
+    check(Waveguide {
+        model: WaveguideModel::Bowed,
+        freq: 220.0,
+        pressure: 0.5,
+        position: 0.127,
+        damping: 0.5,
+    });
+    check(ModalResonator::new(220.0, ModalPreset::Bell));
+}
+
+#[test]
+fn granular_describe_ports() {
+    check(Granulator::new(GrainSource::Live { length_ms: 1000.0 }));
+}
+
+#[test]
+fn sampler_describe_ports() {
+    check(SamplePlayer::new(vec![0.0; 64].into(), 1, 48_000.0));
+}
+
+#[test]
+fn tape_describe_ports() {
+    check(TapeSaturation::new());
+}
+
+#[test]
+fn dither_describe_ports() {
+    check(Dither {
+        dither: DitherType::HighPassTpdf,
+        shaping: NoiseShaping::FWeighted,
+        ..Dither::new(16.0)
+    });
+}
+
+#[test]
+fn oversampled_describe_ports() {
+    let node = Oversampled::new(
+        Overdrive {
+            drive: 1.0,
+            mix: 1.0,
+        },
+        OversampleFactor::X8,
+        OversampleFilter::Iir,
+    );
+    assert_eq!(node.name(), "Overdrive");
+    check(node);
+}
+
+#[test]
+fn modulation_port_lookup() {
+    let desc = TubeSaturation {
+        drive: 1.0,
+        asymmetry: 0.2,
+        mix: 1.0,
+    }
+    .describe();
+    let asymmetry = desc.param("asymmetry").unwrap();
+    assert_eq!(desc.modulation_port(asymmetry.id).map(|p| p.0), Some(2));
+    assert_eq!(desc.modulation_port(99), None);
+}
+
+#[test]
+fn param_node_forwards_description() {
+    let (node, _handle) = ParamNode::new(LadderFilter {
+        cutoff: 1000.0,
+        resonance: 0.5,
+        drive: 1.0,
+    });
+    check(node.clone());
+    assert_eq!(node.name(), "LadderFilter");
+}
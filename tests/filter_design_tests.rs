@@ -0,0 +1,357 @@
+use auxide_dsp::filter_design::{
+    Bandwidth, BiquadCoefficients, BiquadResponse, FilterBand, FilterDesign, FilterFamily,
+};
+use num_complex::Complex64;
+
+const SR: f32 = 48_000.0;
+
+fn design(response: BiquadResponse, bandwidth: Bandwidth, gain_db: f32) -> BiquadCoefficients {
+    BiquadCoefficients::design(response, 1000.0, bandwidth, gain_db, SR)
+}
+
+fn db(c: &BiquadCoefficients, freq: f32) -> f32 {
+    20.0 * c.magnitude(freq, SR).log10()
+}
+
+/// Bisect for the -3 dB point of a notch between a passband frequency and
+/// its centre.
+fn half_power(c: &BiquadCoefficients, mut pass: f32, mut stop: f32) -> f32 {
+    for _ in 0..40 {
+        let mid = 0.5 * (pass + stop);
+        if c.magnitude(mid, SR) > std::f32::consts::FRAC_1_SQRT_2 {
+            pass = mid;
+        } else {
+            stop = mid;
+        }
+    }
+    pass
+}
+
+const BUTTERWORTH: Bandwidth = Bandwidth::Q(std::f32::consts::FRAC_1_SQRT_2);
+
+#[test]
+fn lowpass_and_highpass_cross_at_cutoff() {
+    let lp = design(BiquadResponse::Lowpass, BUTTERWORTH, 0.0);
+    let hp = design(BiquadResponse::Highpass, BUTTERWORTH, 0.0);
+    assert!((db(&lp, 1000.0) + 3.01).abs() < 0.01);
+    assert!((db(&hp, 1000.0) + 3.01).abs() < 0.01);
+    assert!(db(&lp, 10.0).abs() < 0.01);
+    assert!(db(&hp, 23_000.0).abs() < 0.01);
+    // Second order: 40 dB per decade, steeper towards Nyquist for the lowpass
+    assert!(db(&lp, 10_000.0) < -40.0);
+    assert!((db(&hp, 100.0) + 40.0).abs() < 0.1);
+
+    // Q sets the gain at cutoff
+    let resonant = design(BiquadResponse::Lowpass, Bandwidth::Q(4.0), 0.0);
+    assert!((resonant.magnitude(1000.0, SR) - 4.0).abs() < 0.01);
+}
+
+#[test]
+fn bandpass_gain_conventions() {
+    let skirt = design(BiquadResponse::BandpassSkirt, Bandwidth::Q(5.0), 0.0);
+    let peak = design(BiquadResponse::BandpassPeak, Bandwidth::Q(5.0), 0.0);
+    assert!((skirt.magnitude(1000.0, SR) - 5.0).abs() < 1e-3);
+    assert!((peak.magnitude(1000.0, SR) - 1.0).abs() < 1e-4);
+    // Same shape, scaled
+    for freq in [100.0, 800.0, 5000.0] {
+        let ratio = skirt.magnitude(freq, SR) / peak.magnitude(freq, SR);
+        assert!((ratio - 5.0).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn bandwidth_in_octaves_sets_band_edges() {
+    let peak = BiquadCoefficients::design(
+        BiquadResponse::BandpassPeak,
+        100.0,
+        Bandwidth::Octaves(1.0),
+        0.0,
+        SR,
+    );
+    let edge = std::f32::consts::SQRT_2;
+    for freq in [100.0 / edge, 100.0 * edge] {
+        assert!((db(&peak, freq) + 3.01).abs() < 0.05, "{}", db(&peak, freq));
+    }
+    // Bilinear warping is compensated to first order at high frequencies
+    let notch = BiquadCoefficients::design(
+        BiquadResponse::Notch,
+        10_000.0,
+        Bandwidth::Octaves(1.0),
+        0.0,
+        SR,
+    );
+    assert!(notch.magnitude(10_000.0, SR) < 1e-3);
+    let lower = half_power(&notch, 1000.0, 10_000.0);
+    let upper = half_power(&notch, 23_000.0, 10_000.0);
+    let octaves = (upper / lower).log2();
+    assert!((octaves - 1.0).abs() < 0.02, "{}", octaves);
+}
+
+#[test]
+fn allpass_is_flat() {
+    let ap = design(BiquadResponse::Allpass, Bandwidth::Q(2.0), 0.0);
+    for freq in [20.0, 500.0, 1000.0, 4000.0, 20_000.0] {
+        assert!((ap.magnitude(freq, SR) - 1.0).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn peaking_and_shelves_reach_their_gain() {
+    for gain in [-12.0, 6.0] {
+        let bell = design(BiquadResponse::Peaking, Bandwidth::Q(1.0), gain);
+        assert!((db(&bell, 1000.0) - gain).abs() < 0.01);
+        assert!(db(&bell, 10.0).abs() < 0.01);
+        assert!(db(&bell, 23_900.0).abs() < 0.1);
+
+        let low = design(BiquadResponse::LowShelf, Bandwidth::Slope(1.0), gain);
+        let high = design(BiquadResponse::HighShelf, Bandwidth::Slope(1.0), gain);
+        assert!((db(&low, 1.0) - gain).abs() < 0.01);
+        assert!(db(&low, 23_900.0).abs() < 0.01);
+        assert!((db(&high, 23_900.0) - gain).abs() < 0.01);
+        assert!(db(&high, 1.0).abs() < 0.01);
+        // Half the gain at the corner
+        assert!((db(&low, 1000.0) - gain / 2.0).abs() < 0.01);
+        assert!((db(&high, 1000.0) - gain / 2.0).abs() < 0.01);
+    }
+}
+
+#[test]
+fn unit_slope_matches_butterworth_q() {
+    let slope = design(BiquadResponse::LowShelf, Bandwidth::Slope(1.0), 9.0);
+    let q = design(BiquadResponse::LowShelf, BUTTERWORTH, 9.0);
+    for (a, b) in [
+        (slope.b0, q.b0),
+        (slope.b1, q.b1),
+        (slope.b2, q.b2),
+        (slope.a1, q.a1),
+        (slope.a2, q.a2),
+    ] {
+        assert!((a - b).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn out_of_range_designs_stay_stable() {
+    let responses = [
+        BiquadResponse::Lowpass,
+        BiquadResponse::Highpass,
+        BiquadResponse::BandpassSkirt,
+        BiquadResponse::BandpassPeak,
+        BiquadResponse::Notch,
+        BiquadResponse::Allpass,
+        BiquadResponse::Peaking,
+        BiquadResponse::LowShelf,
+        BiquadResponse::HighShelf,
+    ];
+    for response in responses {
+        for freq in [-100.0, 0.0, 30_000.0] {
+            for bandwidth in [
+                Bandwidth::Q(0.0),
+                Bandwidth::Octaves(-1.0),
+                Bandwidth::Slope(50.0),
+            ] {
+                let c = BiquadCoefficients::design(response, freq, bandwidth, 12.0, SR);
+                // Poles inside the unit circle: |a2| < 1 and |a1| < 1 + a2,
+                // allowing a near-DC pole to round onto z = 1
+                assert!(c.a2.abs() < 1.0, "{:?} {} {:?}", response, freq, bandwidth);
+                assert!(
+                    c.a1.abs() <= 1.0 + c.a2,
+                    "{:?} {} {:?}",
+                    response,
+                    freq,
+                    bandwidth
+                );
+            }
+        }
+    }
+}
+
+fn cascade_db(sections: &[BiquadCoefficients], freq: f32) -> f32 {
+    sections.iter().map(|s| db(s, freq)).sum()
+}
+
+/// Extreme gain in dB over `lo..hi` Hz on a log grid.
+fn extreme_db(sections: &[BiquadCoefficients], lo: f32, hi: f32, max: bool) -> f32 {
+    (0..=400)
+        .map(|i| cascade_db(sections, lo * (hi / lo).powf(i as f32 / 400.0)))
+        .fold(if max { f32::MIN } else { f32::MAX }, |m, v| {
+            if max {
+                m.max(v)
+            } else {
+                m.min(v)
+            }
+        })
+}
+
+const FAMILIES: [FilterFamily; 5] = [
+    FilterFamily::Butterworth,
+    FilterFamily::ChebyshevI { ripple_db: 1.0 },
+    FilterFamily::ChebyshevII {
+        attenuation_db: 60.0,
+    },
+    FilterFamily::Bessel,
+    FilterFamily::Elliptic {
+        ripple_db: 0.5,
+        attenuation_db: 70.0,
+    },
+];
+
+#[test]
+fn high_order_designs_are_stable_and_match_their_poles() {
+    for family in FAMILIES {
+        for band in [FilterBand::Lowpass, FilterBand::Highpass] {
+            for order in 1..=12 {
+                for cutoff in [200.0, 1000.0, 18_000.0] {
+                    let design = FilterDesign::new(family, band, order, cutoff);
+                    let digital = design.digital(SR);
+                    assert_eq!(digital.poles.len(), order);
+                    assert!(digital.poles.iter().all(|p| p.norm() < 1.0));
+                    let sections = design.sections(SR);
+                    assert_eq!(sections.len(), order.div_ceil(2));
+                    for freq in [30.0, 700.0, 3000.0, 15_000.0] {
+                        let w = std::f64::consts::TAU * freq as f64 / SR as f64;
+                        let z = Complex64::from_polar(1.0, w);
+                        // Relative error in the passband, absolute in the stopband
+                        let expected = digital.evaluate(z).norm();
+                        let actual = sections
+                            .iter()
+                            .map(|s| s.magnitude(freq, SR) as f64)
+                            .product::<f64>();
+                        assert!(
+                            (expected - actual).abs() < 1e-3 * expected.max(1.0),
+                            "{:?} {:?} {} {}: {} vs {}",
+                            family,
+                            band,
+                            order,
+                            cutoff,
+                            expected,
+                            actual
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn butterworth_and_bessel_are_3db_down_at_cutoff() {
+    for family in [FilterFamily::Butterworth, FilterFamily::Bessel] {
+        for order in [1, 2, 5, 8] {
+            let lp = FilterDesign::new(family, FilterBand::Lowpass, order, 2000.0).sections(SR);
+            let hp = FilterDesign::new(family, FilterBand::Highpass, order, 2000.0).sections(SR);
+            assert!((cascade_db(&lp, 2000.0) + 3.01).abs() < 0.02);
+            assert!((cascade_db(&hp, 2000.0) + 3.01).abs() < 0.02);
+            assert!(cascade_db(&lp, 1.0).abs() < 0.01);
+            assert!(cascade_db(&hp, 23_999.0).abs() < 0.01);
+        }
+    }
+    // 6 dB per octave per pole
+    let steep = FilterDesign::new(FilterFamily::Butterworth, FilterBand::Lowpass, 8, 100.0);
+    let slope = cascade_db(&steep.sections(SR), 800.0) - cascade_db(&steep.sections(SR), 1600.0);
+    assert!((slope - 48.0).abs() < 1.0, "{}", slope);
+}
+
+#[test]
+fn bessel_prototype_matches_reference_poles() {
+    // Third order, normalized for -3 dB at 1 rad/s
+    let proto = FilterDesign::new(FilterFamily::Bessel, FilterBand::Lowpass, 3, 1.0).prototype();
+    let expected = [
+        Complex64::new(-1.3227, 0.0),
+        Complex64::new(-1.0474, 0.9993),
+        Complex64::new(-1.0474, -0.9993),
+    ];
+    for e in expected {
+        assert!(
+            proto.poles.iter().any(|p| (p - e).norm() < 1e-3),
+            "{:?}",
+            proto.poles
+        );
+    }
+    // Maximally flat group delay
+    let delay = |w: f64| {
+        proto
+            .poles
+            .iter()
+            .map(|p| -p.re / (p.re * p.re + (w - p.im) * (w - p.im)))
+            .sum::<f64>()
+    };
+    assert!((delay(0.5) / delay(0.0) - 1.0).abs() < 0.01);
+}
+
+#[test]
+fn chebyshev_ripple_sits_in_its_band() {
+    for order in [4, 5] {
+        let cheby1 = FilterDesign::new(
+            FilterFamily::ChebyshevI { ripple_db: 1.0 },
+            FilterBand::Lowpass,
+            order,
+            1000.0,
+        )
+        .sections(SR);
+        assert!(extreme_db(&cheby1, 10.0, 1000.0, true) < 0.01);
+        assert!(extreme_db(&cheby1, 10.0, 1000.0, false) > -1.01);
+        assert!((cascade_db(&cheby1, 1000.0) + 1.0).abs() < 0.02);
+        let dc = cascade_db(&cheby1, 0.1);
+        assert!((dc - if order % 2 == 0 { -1.0 } else { 0.0 }).abs() < 0.01);
+
+        let cheby2 = FilterDesign::new(
+            FilterFamily::ChebyshevII {
+                attenuation_db: 50.0,
+            },
+            FilterBand::Highpass,
+            order,
+            1000.0,
+        )
+        .sections(SR);
+        assert!(extreme_db(&cheby2, 1.0, 1000.0, true) < -49.9);
+        assert!(extreme_db(&cheby2, 1.0, 1000.0, true) > -50.2);
+        assert!(cascade_db(&cheby2, 23_999.0).abs() < 0.01);
+    }
+}
+
+#[test]
+fn elliptic_is_equiripple_in_both_bands() {
+    for order in [3, 6] {
+        let design = FilterDesign::new(
+            FilterFamily::Elliptic {
+                ripple_db: 0.5,
+                attenuation_db: 60.0,
+            },
+            FilterBand::Lowpass,
+            order,
+            1000.0,
+        );
+        let sections = design.sections(SR);
+        assert!(extreme_db(&sections, 10.0, 1000.0, true) < 0.01);
+        assert!(extreme_db(&sections, 10.0, 1000.0, false) > -0.51);
+        assert!((cascade_db(&sections, 1000.0) + 0.5).abs() < 0.02);
+        // Transmission zeros on the unit circle bound the stopband
+        let digital = design.digital(SR);
+        assert!(digital.zeros.iter().all(|z| (z.norm() - 1.0).abs() < 1e-9));
+        let edge = digital
+            .zeros
+            .iter()
+            .map(|z| z.arg().abs() * SR as f64 / std::f64::consts::TAU)
+            .fold(f64::MAX, f64::min) as f32;
+        let stop = extreme_db(&sections, edge * 0.99, 23_000.0, true);
+        assert!((-60.5..-59.8).contains(&stop), "{} {}", order, stop);
+    }
+    // Steeper than a Chebyshev of the same order
+    let cheby = FilterDesign::new(
+        FilterFamily::ChebyshevI { ripple_db: 0.5 },
+        FilterBand::Lowpass,
+        6,
+        1000.0,
+    );
+    let ellip = FilterDesign::new(
+        FilterFamily::Elliptic {
+            ripple_db: 0.5,
+            attenuation_db: 60.0,
+        },
+        FilterBand::Lowpass,
+        6,
+        1000.0,
+    );
+    assert!(cascade_db(&ellip.sections(SR), 1300.0) < cascade_db(&cheby.sections(SR), 1300.0));
+}
@@ -40,3 +40,139 @@ fn interpolate_wraps() {
     approx(linear_interpolate(&buf, 3.5), -0.5);
     approx(linear_interpolate(&buf, 4.0), 0.0);
 }
+
+#[test]
+fn cubic_interpolate_hits_samples_and_wraps() {
+    let buf = [0.0, 1.0, 0.0, -1.0];
+    approx(cubic_interpolate(&buf, 1.0), 1.0);
+    approx(cubic_interpolate(&buf, 3.0), -1.0);
+    approx(cubic_interpolate(&buf, 0.5), cubic_interpolate(&buf, 4.5));
+    // Symmetric neighbourhood around the peak
+    approx(cubic_interpolate(&buf, 0.75), cubic_interpolate(&buf, 1.25));
+}
+
+const KERNELS: [Interpolation; 5] = [
+    Interpolation::Linear,
+    Interpolation::Cubic,
+    Interpolation::Lagrange,
+    Interpolation::Optimal,
+    Interpolation::Sinc,
+];
+
+#[test]
+fn interpolators_hit_samples_and_wrap() {
+    let buf = [0.0, 1.0, 0.0, -1.0, 0.5, 0.25, -0.75, 0.0];
+    for kind in KERNELS {
+        let interp = Interpolator::new(kind);
+        assert_eq!(interp.kind(), kind);
+        approx(interp.read(&buf, 8.0), interp.read(&buf, 0.0));
+        approx(interp.read(&buf, 10.5), interp.read(&buf, 2.5));
+        // The optimal kernel smooths; its sample positions are checked
+        // against the published polynomial below
+        if kind == Interpolation::Optimal {
+            continue;
+        }
+        for (i, &v) in buf.iter().enumerate() {
+            approx(interp.read(&buf, i as f32), v);
+        }
+    }
+    let linear = Interpolator::new(Interpolation::Linear);
+    let cubic = Interpolator::new(Interpolation::Cubic);
+    for pos in [0.3, 2.75, 7.5] {
+        approx(linear.read(&buf, pos), linear_interpolate(&buf, pos));
+        approx(cubic.read(&buf, pos), cubic_interpolate(&buf, pos));
+    }
+}
+
+#[test]
+fn interpolators_keep_unity_gain() {
+    let buf = [0.7; 16];
+    for kind in KERNELS {
+        let interp = Interpolator::new(kind);
+        for k in 0..=32 {
+            let pos = 5.0 + k as f32 / 32.0;
+            assert!((interp.read(&buf, pos) - 0.7).abs() < 1e-5, "{:?}", kind);
+        }
+    }
+}
+
+#[test]
+fn optimal_follows_published_kernel() {
+    // Niemitalo's optimal 2x, 6-point, 5th-order z-form, evaluated directly
+    let reference = |y: &[f64], x: f64| {
+        let z = x - 0.5;
+        let (even1, odd1) = (y[3] + y[2], y[3] - y[2]);
+        let (even2, odd2) = (y[4] + y[1], y[4] - y[1]);
+        let (even3, odd3) = (y[5] + y[0], y[5] - y[0]);
+        let c0 = even1 * 0.405_133_960_071_457_1
+            + even2 * 0.092_517_944_384_243_93
+            + even3 * 0.002_348_066_035_706_7;
+        let c1 = odd1 * 0.283_428_063_389_066_9
+            + odd2 * 0.217_032_770_240_549
+            + odd3 * 0.013_092_947_487_315_15;
+        let c2 = even1 * -0.191_337_682_540_351_94
+            + even2 * 0.161_878_444_879_435_92
+            + even3 * 0.029_460_171_431_119_12;
+        let c3 = odd1 * -0.164_716_261_905_545_42
+            + odd2 * -0.001_545_472_035_424_99
+            + odd3 * 0.033_992_714_448_519_09;
+        let c4 = even1 * 0.038_457_987_295_881_49
+            + even2 * -0.057_129_361_042_426_44
+            + even3 * 0.018_667_509_299_210_7;
+        let c5 = odd1 * 0.043_179_501_852_256_09
+            + odd2 * -0.018_028_142_559_264_17
+            + odd3 * 0.001_521_700_215_582_04;
+        ((((c5 * z + c4) * z + c3) * z + c2) * z + c1) * z + c0
+    };
+    let buf = [0.0, 1.0, 0.0, -1.0, 0.5, 0.25, -0.75, 0.0, 0.3, -0.2];
+    let interp = Interpolator::new(Interpolation::Optimal);
+    // Integer positions and exact table rows
+    for base in 2..7 {
+        let y: Vec<f64> = buf[base - 2..base + 4].iter().map(|&v| v as f64).collect();
+        for x in [0.0, 0.25, 0.5, 0.75] {
+            let expected = reference(&y, x) as f32;
+            approx(interp.read(&buf, base as f32 + x as f32), expected);
+        }
+    }
+}
+
+#[test]
+fn interpolator_quality_on_oversampled_sine() {
+    // 0.2 cycles per sample sits inside the 2x oversampled band
+    let freq = 0.2f64;
+    let buf: Vec<f32> = (0..1000)
+        .map(|i| (std::f64::consts::TAU * freq * i as f64).sin() as f32)
+        .collect();
+    let error = |kind| {
+        let interp = Interpolator::new(kind);
+        (0..900)
+            .map(|k| {
+                let pos = 50.0 + k as f64 * 0.7919;
+                let expected = (std::f64::consts::TAU * freq * pos).sin() as f32;
+                (interp.read(&buf, pos as f32) - expected).abs()
+            })
+            .fold(0.0f32, f32::max)
+    };
+    // The optimal kernel rolls off the passband, so it is left out
+    let [linear, cubic, lagrange, _, sinc] = KERNELS.map(error);
+    assert!(cubic < linear * 0.5, "{} {}", cubic, linear);
+    assert!(lagrange < linear * 0.5, "{} {}", lagrange, linear);
+    assert!(sinc < cubic * 0.1, "{} {}", sinc, cubic);
+}
+
+#[test]
+fn read_delay_counts_back_from_write_index() {
+    let buf: Vec<f32> = (0..16).map(|i| i as f32).collect();
+    for kind in [
+        Interpolation::Linear,
+        Interpolation::Cubic,
+        Interpolation::Lagrange,
+    ] {
+        let interp = Interpolator::new(kind);
+        approx(interp.read_delay(&buf, 10, 2.5), 7.5);
+        approx(interp.read_delay(&buf, 10, 4.0), 6.0);
+    }
+    // Wraps behind index 0
+    let linear = Interpolator::new(Interpolation::Linear);
+    approx(linear.read_delay(&buf, 1, 2.0), 15.0);
+}
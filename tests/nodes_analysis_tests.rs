@@ -0,0 +1,102 @@
+use auxide::node::NodeDef;
+use auxide_dsp::nodes::analysis::{
+    AutocorrPitchDetector, FrequencyAnalyzer, OnsetDetector, Peak, Rms, SpectralCentroid,
+};
+
+const SR: f32 = 44100.0;
+
+fn sine(freq: f32, len: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| (i as f32 * freq * 2.0 * std::f32::consts::PI / SR).sin())
+        .collect()
+}
+
+/// Run a single-output node over `input` in 64-sample blocks.
+fn run<T: NodeDef>(node: &T, input: &[f32]) -> Vec<f32> {
+    let mut state = node.init_state(SR, 64);
+    let mut out = vec![vec![0.0; 64]; node.output_ports().len()];
+    let mut result = Vec::with_capacity(input.len());
+    for block in input.chunks(64) {
+        node.process_block(&mut state, &[block], &mut out, SR);
+        result.extend_from_slice(&out[0][..block.len()]);
+    }
+    result
+}
+
+#[test]
+fn rms_of_constant() {
+    let out = run(&Rms { window_size: 128 }, &[0.5; 256]);
+    assert!((out[255] - 0.5).abs() < 1e-4);
+}
+
+#[test]
+fn peak_holds_then_releases() {
+    let node = Peak {
+        hold_ms: 1.0,
+        release_ms: 10.0,
+    };
+    let mut input = vec![0.0; 2048];
+    input[0] = 1.0;
+    let out = run(&node, &input);
+    assert_eq!(out[20], 1.0);
+    assert!(out[2047] < 0.1);
+}
+
+#[test]
+fn frequency_analyzer_tracks_sine() {
+    let out = run(&FrequencyAnalyzer { window_size: 4410 }, &sine(440.0, 8820));
+    assert!((out[8819] - 440.0).abs() < 15.0, "{}", out[8819]);
+}
+
+#[test]
+fn spectral_centroid_follows_brightness() {
+    let node = SpectralCentroid { fft_size: 1024 };
+    let low = run(&node, &sine(500.0, 4096))[4095];
+    let high = run(&node, &sine(5000.0, 4096))[4095];
+    assert!((low - 500.0).abs() < 100.0, "{}", low);
+    assert!((high - 5000.0).abs() < 300.0, "{}", high);
+}
+
+#[test]
+fn onset_detector_fires_on_attack() {
+    let node = OnsetDetector {
+        threshold: 0.1,
+        sensitivity: 1.0,
+        fft_size: 512,
+    };
+    let mut input = vec![0.0; 4096];
+    input[2048..].copy_from_slice(&sine(1000.0, 2048));
+    let out = run(&node, &input);
+    let onsets: Vec<usize> = (0..out.len()).filter(|&i| out[i] == 1.0).collect();
+    assert_eq!(onsets.len(), 1, "{:?}", onsets);
+    assert!(onsets[0] >= 2048 && onsets[0] < 2048 + 512);
+}
+
+#[test]
+fn autocorr_pitch_detects_sine() {
+    let node = AutocorrPitchDetector {
+        min_freq: 80.0,
+        max_freq: 1000.0,
+        window_size: 1024,
+    };
+    let out = run(&node, &sine(220.0, 4096));
+    assert!((out[4095] - 220.0).abs() < 2.0, "{}", out[4095]);
+
+    let silent = run(&node, &[0.0; 2048]);
+    assert_eq!(silent[2047], 0.0);
+}
+
+#[cfg(test)]
+mod property_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn onset_detector_no_panic(threshold in 0.0..1.0f32, sensitivity in 0.0..10.0f32) {
+            let node = OnsetDetector { threshold, sensitivity, fft_size: 64 };
+            let out = run(&node, &sine(440.0, 256));
+            prop_assert!(out.iter().all(|x| x.is_finite()));
+        }
+    }
+}
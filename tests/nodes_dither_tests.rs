@@ -0,0 +1,201 @@
+use auxide::node::NodeDef;
+use auxide_dsp::nodes::dither::{Dither, DitherType, NoiseShaping};
+
+const SR: f32 = 44_100.0;
+
+fn render(node: &Dither, input: &[f32]) -> Vec<f32> {
+    let mut state = node.init_state(SR, input.len());
+    let mut out = vec![vec![0.0; input.len()]];
+    node.process_block(&mut state, &[input], &mut out, SR);
+    out.pop().unwrap()
+}
+
+fn sine(freq: f32, amp: f32, len: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| amp * (std::f32::consts::TAU * freq * i as f32 / SR).sin())
+        .collect()
+}
+
+fn error(node: &Dither, input: &[f32]) -> Vec<f32> {
+    render(node, input)
+        .iter()
+        .zip(input)
+        .map(|(y, x)| y - x)
+        .collect()
+}
+
+fn variance(buf: &[f32]) -> f32 {
+    let mean = buf.iter().sum::<f32>() / buf.len() as f32;
+    buf.iter().map(|v| (v - mean) * (v - mean)).sum::<f32>() / buf.len() as f32
+}
+
+/// Mean power spectral density over `lo..hi` Hz, sampled every 50 Hz.
+fn band_power(buf: &[f32], lo: f32, hi: f32) -> f32 {
+    let mut total = 0.0;
+    let mut count = 0;
+    let mut freq = lo;
+    while freq < hi {
+        let w = std::f32::consts::TAU * freq / SR;
+        let (re, im) = buf
+            .iter()
+            .enumerate()
+            .fold((0.0f32, 0.0f32), |(re, im), (i, v)| {
+                (re + v * (w * i as f32).cos(), im + v * (w * i as f32).sin())
+            });
+        total += (re * re + im * im) / buf.len() as f32;
+        count += 1;
+        freq += 50.0;
+    }
+    total / count as f32
+}
+
+const DITHERS: [DitherType; 4] = [
+    DitherType::None,
+    DitherType::Rpdf,
+    DitherType::Tpdf,
+    DitherType::HighPassTpdf,
+];
+
+const SHAPINGS: [NoiseShaping; 6] = [
+    NoiseShaping::None,
+    NoiseShaping::FirstOrder,
+    NoiseShaping::SecondOrder,
+    NoiseShaping::FWeighted,
+    NoiseShaping::ModifiedEWeighted,
+    NoiseShaping::ImprovedEWeighted,
+];
+
+#[test]
+fn output_lies_on_the_target_grid() {
+    let input = sine(997.0, 1.0, 4410);
+    for bits in [4.0, 8.0, 16.0] {
+        let step = 2.0f32.powf(1.0 - bits);
+        for dither in DITHERS {
+            for shaping in SHAPINGS {
+                let node = Dither {
+                    dither,
+                    shaping,
+                    ..Dither::new(bits)
+                };
+                for v in render(&node, &input) {
+                    let level = v / step;
+                    assert!(
+                        (level - level.round()).abs() < 1e-3,
+                        "{:?} {:?}",
+                        dither,
+                        shaping
+                    );
+                    assert!((-1.0..=1.0 - step).contains(&v));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn seed_makes_renders_repeatable() {
+    let input = sine(440.0, 0.5, 1024);
+    let node = Dither {
+        shaping: NoiseShaping::FWeighted,
+        ..Dither::new(8.0)
+    };
+    assert_eq!(render(&node, &input), render(&node, &input));
+    let other = Dither {
+        seed: 7,
+        ..node.clone()
+    };
+    assert_ne!(render(&node, &input), render(&other, &input));
+    // A zero seed still dithers
+    let zero = Dither { seed: 0, ..node };
+    assert!(variance(&render(&zero, &vec![0.0; 1024])) > 0.0);
+}
+
+#[test]
+fn tpdf_error_does_not_follow_the_signal() {
+    let step = 2.0f32.powi(-7);
+    let silence = vec![0.0; 44_100];
+    let quiet = sine(440.0, 0.3 * step, 44_100);
+    let loud = sine(440.0, 0.5, 44_100);
+
+    // Undithered, silence truncates to silence and the error tracks the input
+    let plain = Dither {
+        dither: DitherType::None,
+        ..Dither::new(8.0)
+    };
+    assert_eq!(variance(&error(&plain, &silence)), 0.0);
+    assert!(render(&plain, &quiet).iter().all(|v| *v == 0.0));
+
+    // TPDF: error variance is step^2 / 4 whatever the input
+    for kind in [DitherType::Tpdf, DitherType::HighPassTpdf] {
+        let node = Dither {
+            dither: kind,
+            ..Dither::new(8.0)
+        };
+        for input in [&silence, &quiet, &loud] {
+            let err = error(&node, input);
+            let ratio = variance(&err) / (step * step / 4.0);
+            assert!((ratio - 1.0).abs() < 0.05, "{:?}: {}", kind, ratio);
+            let mean = err.iter().sum::<f32>() / err.len() as f32;
+            assert!(mean.abs() < 0.01 * step);
+        }
+    }
+
+    // RPDF adds step^2 / 12 to the step^2 / 12 of rounding
+    let rpdf = Dither {
+        dither: DitherType::Rpdf,
+        ..Dither::new(8.0)
+    };
+    let ratio = variance(&error(&rpdf, &loud)) / (step * step / 6.0);
+    assert!((ratio - 1.0).abs() < 0.05, "{}", ratio);
+}
+
+#[test]
+fn high_pass_tpdf_tilts_the_dither_up() {
+    let silence = vec![0.0; 8820];
+    let spectrum = |dither| {
+        let node = Dither {
+            dither,
+            ..Dither::new(8.0)
+        };
+        let err = error(&node, &silence);
+        band_power(&err, 100.0, 2000.0) / band_power(&err, 18_000.0, 20_000.0)
+    };
+    // Only the dither is tilted; the rounding error itself stays white, so
+    // the low band keeps step^2 / 12 against step^2 * 5 / 12 near Nyquist
+    let ratio = spectrum(DitherType::HighPassTpdf) / spectrum(DitherType::Tpdf);
+    assert!(ratio < 0.3, "{}", ratio);
+}
+
+#[test]
+fn noise_shaping_moves_error_out_of_the_sensitive_band() {
+    let input = sine(440.0, 0.3, 8820);
+    let flat = error(&Dither::new(12.0), &input);
+    let shaped = |shaping| {
+        let node = Dither {
+            shaping,
+            ..Dither::new(12.0)
+        };
+        error(&node, &input)
+    };
+
+    for shaping in [NoiseShaping::FirstOrder, NoiseShaping::SecondOrder] {
+        let err = shaped(shaping);
+        let low = band_power(&err, 100.0, 1000.0) / band_power(&flat, 100.0, 1000.0);
+        let high = band_power(&err, 16_000.0, 20_000.0) / band_power(&flat, 16_000.0, 20_000.0);
+        assert!(low < 0.1, "{:?}: {}", shaping, low);
+        assert!(high > 2.0, "{:?}: {}", shaping, high);
+    }
+
+    // The psychoacoustic curves dip deepest where hearing is most sensitive
+    for shaping in [
+        NoiseShaping::FWeighted,
+        NoiseShaping::ModifiedEWeighted,
+        NoiseShaping::ImprovedEWeighted,
+    ] {
+        let err = shaped(shaping);
+        let mid = band_power(&err, 2500.0, 4500.0) / band_power(&flat, 2500.0, 4500.0);
+        let high = band_power(&err, 16_000.0, 20_000.0) / band_power(&flat, 16_000.0, 20_000.0);
+        assert!(mid < 0.05, "{:?}: {}", shaping, mid);
+        assert!(high > 4.0, "{:?}: {}", shaping, high);
+    }
+}
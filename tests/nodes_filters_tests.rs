@@ -1,7 +1,11 @@
 use auxide::node::NodeDef;
 use auxide_dsp::{
-    AllpassFilter, BiquadFilter, CombFilter, FormantFilter, LadderFilter, SvfFilter, SvfMode,
+    AllpassFilter, BandSplitter, Bandwidth, BiquadCoefficients, BiquadFilter, BiquadResponse,
+    CascadeFilter, CoefficientUpdate, CombFilter, CrossoverOrder, DiodeLadderFilter, FilterBand,
+    FilterDesign, FilterFamily, FormantFilter, LadderFilter, LadderSlope, ParametricBiquad,
+    SvfFilter, SvfMode, ZdfLadderFilter, ZdfSvfFilter,
 };
+use num_complex::Complex64;
 
 fn non_silent(output: &[f32]) -> bool {
     output.iter().any(|&x| x.abs() > 1e-6)
@@ -117,6 +121,448 @@ fn biquad_runs() {
     assert!(non_silent(&out[0]));
 }
 
+fn render<T: NodeDef>(node: &T, inputs: &[&[f32]]) -> Vec<f32> {
+    let mut state = node.init_state(48_000.0, inputs[0].len());
+    let mut out = vec![vec![0.0; inputs[0].len()]];
+    node.process_block(&mut state, inputs, &mut out, 48_000.0);
+    out.pop().unwrap()
+}
+
+fn noise(len: usize) -> Vec<f32> {
+    let mut rng = 1u64;
+    (0..len)
+        .map(|_| auxide_dsp::xorshift(&mut rng) * 2.0 - 1.0)
+        .collect()
+}
+
+#[test]
+fn parametric_biquad_matches_designed_coefficients() {
+    let input = noise(256);
+    let node = ParametricBiquad {
+        gain: 6.0,
+        ..ParametricBiquad::new(BiquadResponse::HighShelf, 3000.0, 0.9)
+    };
+    let fixed = BiquadFilter::new(BiquadCoefficients::design(
+        BiquadResponse::HighShelf,
+        3000.0,
+        Bandwidth::Q(0.9),
+        6.0,
+        48_000.0,
+    ));
+    assert_eq!(render(&node, &[&input]), render(&fixed, &[&input]));
+
+    // A constant offset on every modulation input lands on the same design
+    let offset = ParametricBiquad {
+        cutoff: 2000.0,
+        q: 0.4,
+        gain: 0.0,
+        ..node.clone()
+    };
+    let out = render(&offset, &[&input, &[1000.0; 256], &[0.5; 256], &[6.0; 256]]);
+    assert_eq!(out, render(&node, &[&input]));
+}
+
+#[test]
+fn parametric_biquad_update_rate() {
+    let input = noise(256);
+    let sweep: Vec<f32> = (0..256).map(|i| i as f32 * 20.0).collect();
+    let node = ParametricBiquad::new(BiquadResponse::Lowpass, 500.0, 0.707);
+    let per_sample = render(&node, &[&input, &sweep]);
+    let per_block = render(
+        &ParametricBiquad {
+            update: CoefficientUpdate::PerBlock,
+            ..node.clone()
+        },
+        &[&input, &sweep],
+    );
+    // Per block holds the design from the first sample
+    assert_eq!(per_block, render(&node, &[&input]));
+    assert_ne!(per_sample, per_block);
+}
+
+#[test]
+fn parametric_biquad_survives_audio_rate_sweeps() {
+    let input = noise(4800);
+    let sweep: Vec<f32> = (0..4800)
+        .map(|i| 19_000.0 * (0.5 + 0.5 * (i as f32 * 0.3).sin()))
+        .collect();
+    for response in [
+        BiquadResponse::Lowpass,
+        BiquadResponse::BandpassPeak,
+        BiquadResponse::Peaking,
+    ] {
+        let node = ParametricBiquad {
+            gain: 12.0,
+            ..ParametricBiquad::new(response, 100.0, 8.0)
+        };
+        let out = render(&node, &[&input, &sweep]);
+        assert!(out.iter().all(|v| v.is_finite() && v.abs() < 100.0));
+    }
+}
+
+#[test]
+fn cascade_filter_follows_its_design() {
+    let design = FilterDesign::new(
+        FilterFamily::Elliptic {
+            ripple_db: 0.1,
+            attenuation_db: 80.0,
+        },
+        FilterBand::Lowpass,
+        8,
+        4000.0,
+    );
+    let node = CascadeFilter::new(design);
+    let sections = design.sections(48_000.0);
+    for freq in [1000.0, 3900.0, 6000.0, 12_000.0] {
+        let input: Vec<f32> = (0..9600)
+            .map(|i| (std::f32::consts::TAU * freq * i as f32 / 48_000.0).sin())
+            .collect();
+        let out = render(&node, &[&input]);
+        let peak = out[4800..].iter().fold(0.0f32, |m, v| m.max(v.abs()));
+        let expected: f32 = sections
+            .iter()
+            .map(|s| s.magnitude(freq, 48_000.0))
+            .product();
+        assert!(
+            (peak - expected).abs() < 1e-3 * expected.max(1.0),
+            "{}: {} vs {}",
+            freq,
+            peak,
+            expected
+        );
+    }
+}
+
+/// Complex response of an impulse response at `freq`.
+fn complex_response(ir: &[f32], freq: f64, sample_rate: f64) -> Complex64 {
+    let w = std::f64::consts::TAU * freq / sample_rate;
+    ir.iter()
+        .enumerate()
+        .map(|(i, &v)| Complex64::from_polar(v as f64, -w * i as f64))
+        .sum()
+}
+
+#[test]
+fn zdf_svf_matches_prewarped_analog_prototype() {
+    let sample_rate = 48_000.0;
+    let node = ZdfSvfFilter {
+        cutoff: 3000.0,
+        resonance: 0.6,
+        gain: 9.0,
+    };
+    let mut impulse = vec![0.0; 8192];
+    impulse[0] = 1.0;
+    let mut state = node.init_state(sample_rate as f32, impulse.len());
+    let mut out = vec![vec![0.0; impulse.len()]; node.output_ports().len()];
+    node.process_block(&mut state, &[&impulse], &mut out, sample_rate as f32);
+
+    let g = (std::f64::consts::PI * 3000.0 / sample_rate).tan();
+    let k = 2.0 - 2.0 * 0.6;
+    let a = 10f64.powf(9.0 / 40.0);
+    for freq in [50.0, 1000.0, 2900.0, 3000.0, 8000.0, 20_000.0] {
+        let z = Complex64::from_polar(1.0, std::f64::consts::TAU * freq / sample_rate);
+        let s = (z - 1.0) / ((z + 1.0) * g);
+        let d = s * s + k * s + 1.0;
+        let numerators = [
+            Complex64::new(1.0, 0.0),
+            s * s,
+            k * s,
+            s * s + 1.0,
+            1.0 - s * s,
+            s * s - k * s + 1.0,
+            s * s + a * k * s + a * a,
+            a * a * s * s + a * k * s + 1.0,
+            s * s + a * a * k * s + 1.0,
+        ];
+        for (port, numerator) in numerators.iter().enumerate() {
+            let expected = numerator / d;
+            let measured = complex_response(&out[port], freq, sample_rate);
+            assert!(
+                (measured - expected).norm() < 1e-4 * expected.norm().max(1.0),
+                "port {} at {}: {} vs {}",
+                port,
+                freq,
+                measured,
+                expected
+            );
+        }
+    }
+
+    // The cutoff lands exactly where it was asked for
+    let bandpass = complex_response(&out[2], 3000.0, sample_rate);
+    assert!((bandpass.norm() - 1.0).abs() < 1e-4);
+    assert!(bandpass.im.abs() < 1e-4);
+}
+
+#[test]
+fn zdf_svf_survives_audio_rate_modulation() {
+    let input = noise(9600);
+    let cutoff_sweep: Vec<f32> = (0..9600)
+        .map(|i| 30_000.0 * (i as f32 * 0.7).sin())
+        .collect();
+    let resonance_sweep: Vec<f32> = (0..9600).map(|i| (i as f32 * 1.3).sin()).collect();
+    for sample_rate in [8000.0, 44_100.0, 192_000.0] {
+        let node = ZdfSvfFilter {
+            cutoff: 5000.0,
+            resonance: 0.9,
+            gain: 24.0,
+        };
+        let mut state = node.init_state(sample_rate, input.len());
+        let mut out = vec![vec![0.0; input.len()]; 9];
+        node.process_block(
+            &mut state,
+            &[&input, &cutoff_sweep, &resonance_sweep],
+            &mut out,
+            sample_rate,
+        );
+        for port in &out {
+            assert!(port.iter().all(|v| v.is_finite() && v.abs() < 1000.0));
+        }
+    }
+
+    // Fully resonant and above Nyquist it still rings down instead of blowing up
+    let node = ZdfSvfFilter {
+        cutoff: 40_000.0,
+        resonance: 1.0,
+        gain: 0.0,
+    };
+    let mut impulse = vec![0.0; 48_000];
+    impulse[0] = 1.0;
+    let mut state = node.init_state(48_000.0, impulse.len());
+    let mut out = vec![vec![0.0; impulse.len()]; 9];
+    node.process_block(&mut state, &[&impulse], &mut out, 48_000.0);
+    let head = out[0][..4800].iter().fold(0.0f32, |m, v| m.max(v.abs()));
+    let tail = out[0][43_200..].iter().fold(0.0f32, |m, v| m.max(v.abs()));
+    assert!(tail < head);
+}
+
+/// Impulse responses of every output of a splitter.
+fn split_impulse(node: &BandSplitter, len: usize) -> Vec<Vec<f32>> {
+    let mut impulse = vec![0.0; len];
+    impulse[0] = 1.0;
+    let mut state = node.init_state(48_000.0, len);
+    let mut out = vec![vec![0.0; len]; node.output_ports().len()];
+    node.process_block(&mut state, &[&impulse], &mut out, 48_000.0);
+    out
+}
+
+/// Magnitude of an impulse response at `freq`.
+fn response(ir: &[f32], freq: f32) -> f32 {
+    let w = std::f64::consts::TAU * freq as f64 / 48_000.0;
+    let (re, im) = ir.iter().enumerate().fold((0.0, 0.0), |(re, im), (i, &v)| {
+        let phase = w * i as f64;
+        (re + v as f64 * phase.cos(), im - v as f64 * phase.sin())
+    });
+    (re * re + im * im).sqrt() as f32
+}
+
+/// Frequency and peak level over the second half of a signal.
+fn settled_pitch(signal: &[f32], sample_rate: f32) -> (f32, f32) {
+    let tail = &signal[signal.len() / 2..];
+    let crossings: Vec<f32> = (1..tail.len())
+        .filter(|&i| tail[i - 1] <= 0.0 && tail[i] > 0.0)
+        .map(|i| i as f32 - 1.0 + tail[i - 1] / (tail[i - 1] - tail[i]))
+        .collect();
+    let peak = tail.iter().fold(0.0f32, |m, v| m.max(v.abs()));
+    match crossings.len() {
+        0..=2 => (0.0, peak),
+        n => (
+            sample_rate * (n - 1) as f32 / (crossings[n - 1] - crossings[0]),
+            peak,
+        ),
+    }
+}
+
+#[test]
+fn zdf_ladder_small_signal_matches_prototype() {
+    // A quiet impulse keeps the tanh stages linear: H = (1 + s)^(4 - n) / ((1 + s)^4 + k)
+    let mut impulse = vec![0.0; 8192];
+    impulse[0] = 1e-3;
+    let g = (std::f64::consts::PI * 1000.0 / 48_000.0).tan();
+    let slopes = [
+        LadderSlope::Db6,
+        LadderSlope::Db12,
+        LadderSlope::Db18,
+        LadderSlope::Db24,
+    ];
+    for (poles, slope) in slopes.into_iter().enumerate() {
+        let node = ZdfLadderFilter {
+            resonance: 0.7,
+            slope,
+            ..ZdfLadderFilter::default()
+        };
+        let out = render(&node, &[&impulse]);
+        for freq in [100.0, 1000.0, 4000.0, 16_000.0] {
+            let z = Complex64::from_polar(1.0, std::f64::consts::TAU * freq as f64 / 48_000.0);
+            let stage = (z - 1.0) / ((z + 1.0) * g) + 1.0;
+            let expected = (stage.powi(3 - poles as i32) / (stage.powi(4) + 2.8)).norm() as f32;
+            let measured = response(&out, freq) * 1e3;
+            assert!(
+                (measured - expected).abs() < 1e-3 * expected.max(1e-3),
+                "{:?} at {}: {} vs {}",
+                slope,
+                freq,
+                measured,
+                expected
+            );
+        }
+    }
+}
+
+#[test]
+fn ladders_compensate_passband_gain() {
+    let dc = vec![1e-3; 48_000];
+    let level = |out: Vec<f32>| out[47_999] / 1e-3;
+    for compensation in [0.0, 1.0] {
+        let moog = ZdfLadderFilter {
+            resonance: 0.9,
+            compensation,
+            ..ZdfLadderFilter::default()
+        };
+        let diode = DiodeLadderFilter {
+            resonance: 0.9,
+            compensation,
+            ..DiodeLadderFilter::default()
+        };
+        let (moog, diode) = (level(render(&moog, &[&dc])), level(render(&diode, &[&dc])));
+        if compensation == 0.0 {
+            assert!((moog - 1.0 / (1.0 + 3.6)).abs() < 1e-3);
+            assert!((diode - 1.0 / (1.0 + 17.0 * 0.9)).abs() < 1e-3);
+        } else {
+            assert!((moog - 1.0).abs() < 1e-3);
+            assert!((diode - 1.0).abs() < 1e-3);
+        }
+    }
+}
+
+#[test]
+fn ladders_self_oscillate_at_cutoff() {
+    let mut kick = vec![0.0; 96_000];
+    kick[0] = 0.01;
+    let render_both = |cutoff, resonance| {
+        [
+            render(
+                &ZdfLadderFilter {
+                    cutoff,
+                    resonance,
+                    ..ZdfLadderFilter::default()
+                },
+                &[&kick],
+            ),
+            render(
+                &DiodeLadderFilter {
+                    cutoff,
+                    resonance,
+                    ..DiodeLadderFilter::default()
+                },
+                &[&kick],
+            ),
+        ]
+    };
+
+    let mut ratios = [Vec::new(), Vec::new()];
+    for cutoff in [110.0, 880.0, 3520.0] {
+        for (ratios, out) in ratios.iter_mut().zip(render_both(cutoff, 1.1)) {
+            let (pitch, peak) = settled_pitch(&out, 48_000.0);
+            assert!(peak > 0.01, "{}: {}", cutoff, peak);
+            ratios.push(pitch / cutoff);
+        }
+        for out in render_both(cutoff, 0.95) {
+            assert!(settled_pitch(&out, 48_000.0).1 < 1e-6);
+        }
+    }
+    // The tanh stages flatten a full-scale oscillation a little, by the same
+    // ratio at every pitch
+    for ratios in ratios {
+        assert!(ratios.iter().all(|&r| r > 0.9 && r <= 1.0), "{:?}", ratios);
+        let spread = ratios
+            .iter()
+            .fold(0.0f32, |m, &r| m.max((r - ratios[0]).abs()));
+        assert!(spread < 5e-3, "{:?}", ratios);
+    }
+}
+
+#[test]
+fn ladders_survive_audio_rate_modulation() {
+    let input: Vec<f32> = noise(9600).iter().map(|v| v * 4.0).collect();
+    let cutoff_sweep: Vec<f32> = (0..9600)
+        .map(|i| 30_000.0 * (i as f32 * 0.7).sin())
+        .collect();
+    let resonance_sweep: Vec<f32> = (0..9600).map(|i| 0.6 * (i as f32 * 1.3).sin()).collect();
+    let inputs: [&[f32]; 3] = [&input, &cutoff_sweep, &resonance_sweep];
+    for sample_rate in [8000.0, 44_100.0, 192_000.0] {
+        let moog = ZdfLadderFilter {
+            cutoff: 5000.0,
+            resonance: 1.2,
+            drive: 10.0,
+            compensation: 1.0,
+            ..ZdfLadderFilter::default()
+        };
+        let diode = DiodeLadderFilter {
+            cutoff: 5000.0,
+            resonance: 1.2,
+            drive: 10.0,
+            compensation: 1.0,
+        };
+        let mut moog_state = moog.init_state(sample_rate, 9600);
+        let mut diode_state = diode.init_state(sample_rate, 9600);
+        let mut out = vec![vec![0.0; 9600]];
+        moog.process_block(&mut moog_state, &inputs, &mut out, sample_rate);
+        assert!(out[0].iter().all(|v| v.is_finite() && v.abs() < 1000.0));
+        diode.process_block(&mut diode_state, &inputs, &mut out, sample_rate);
+        assert!(out[0].iter().all(|v| v.is_finite() && v.abs() < 1000.0));
+    }
+}
+
+const CROSSOVERS: [CrossoverOrder; 3] = [
+    CrossoverOrder::Lr2,
+    CrossoverOrder::Lr4,
+    CrossoverOrder::Lr8,
+];
+
+#[test]
+fn band_splitter_sums_flat() {
+    for order in CROSSOVERS {
+        let node = BandSplitter::new(vec![200.0, 1000.0, 5000.0], order);
+        let bands = split_impulse(&node, 16_384);
+        assert_eq!(bands.len(), 4);
+        let sum: Vec<f32> = (0..16_384)
+            .map(|i| bands.iter().map(|b| b[i]).sum())
+            .collect();
+        for freq in [20.0, 150.0, 200.0, 640.0, 1000.0, 3000.0, 5000.0, 15_000.0] {
+            let gain = response(&sum, freq);
+            assert!((gain - 1.0).abs() < 1e-3, "{:?} {}: {}", order, freq, gain);
+        }
+        // The allpasses leave the low band's crossover 6 dB down
+        assert!((response(&bands[0], 200.0) - 0.5).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn band_splitter_slopes_and_ordering() {
+    let attenuation = |order| {
+        let node = BandSplitter::new(vec![1000.0], order);
+        let bands = split_impulse(&node, 8192);
+        assert!((response(&bands[0], 1000.0) - 0.5).abs() < 1e-3);
+        assert!((response(&bands[1], 1000.0) - 0.5).abs() < 1e-3);
+        response(&bands[0], 4000.0)
+    };
+    // Two octaves up: about 24, 48 and 96 dB down
+    let db = |order| 20.0 * attenuation(order).log10();
+    assert!((db(CrossoverOrder::Lr2) + 24.0).abs() < 2.0);
+    assert!((db(CrossoverOrder::Lr4) + 48.0).abs() < 2.0);
+    assert!((db(CrossoverOrder::Lr8) + 96.0).abs() < 3.0);
+
+    let sorted = BandSplitter::new(vec![300.0, 3000.0], CrossoverOrder::Lr4);
+    let shuffled = BandSplitter::new(vec![3000.0, 300.0], CrossoverOrder::Lr4);
+    assert_eq!(split_impulse(&sorted, 512), split_impulse(&shuffled, 512));
+
+    let single = BandSplitter::new(Vec::new(), CrossoverOrder::Lr4);
+    assert_eq!(single.output_ports().len(), 1);
+    let through = split_impulse(&single, 4);
+    assert_eq!(through[0], vec![1.0, 0.0, 0.0, 0.0]);
+}
+
 #[test]
 fn allpass_runs() {
     let node = AllpassFilter {
@@ -183,6 +629,50 @@ mod property_tests {
             // Should not panic
         }
 
+        #[test]
+        fn parametric_biquad_no_panic(cutoff in -100.0..30000.0f32, q in -1.0..40.0f32, gain in -48.0..48.0f32) {
+            let responses = [
+                BiquadResponse::Lowpass,
+                BiquadResponse::Highpass,
+                BiquadResponse::BandpassSkirt,
+                BiquadResponse::BandpassPeak,
+                BiquadResponse::Notch,
+                BiquadResponse::Allpass,
+                BiquadResponse::Peaking,
+                BiquadResponse::LowShelf,
+                BiquadResponse::HighShelf,
+            ];
+            for response in responses {
+                let node = ParametricBiquad { gain, ..ParametricBiquad::new(response, cutoff, q) };
+                let mut state = node.init_state(44100.0, 64);
+                let mut out = vec![vec![0.0; 64]];
+                node.process_block(&mut state, &[&[1.0; 64]], &mut out, 44100.0);
+                prop_assert!(out[0].iter().all(|v| v.is_finite()));
+            }
+        }
+
+        #[test]
+        fn zdf_svf_filter_stays_finite(cutoff in -100.0..60000.0f32, resonance in -1.0..2.0f32, gain in -48.0..48.0f32) {
+            let node = ZdfSvfFilter { cutoff, resonance, gain };
+            let mut state = node.init_state(44100.0, 64);
+            let mut out = vec![vec![0.0; 64]; 9];
+            node.process_block(&mut state, &[&[1.0; 64]], &mut out, 44100.0);
+            prop_assert!(out.iter().flatten().all(|v| v.is_finite()));
+        }
+
+        #[test]
+        fn zdf_ladders_stay_finite(cutoff in -100.0..60000.0f32, resonance in -1.0..2.0f32, drive in 0.0..20.0f32, compensation in 0.0..1.0f32) {
+            let moog = ZdfLadderFilter { cutoff, resonance, drive, compensation, ..ZdfLadderFilter::default() };
+            let diode = DiodeLadderFilter { cutoff, resonance, drive, compensation };
+            let mut moog_state = moog.init_state(44100.0, 64);
+            let mut diode_state = diode.init_state(44100.0, 64);
+            let mut out = vec![vec![0.0; 64]];
+            moog.process_block(&mut moog_state, &[&[1.0; 64]], &mut out, 44100.0);
+            prop_assert!(out[0].iter().all(|v| v.is_finite()));
+            diode.process_block(&mut diode_state, &[&[1.0; 64]], &mut out, 44100.0);
+            prop_assert!(out[0].iter().all(|v| v.is_finite()));
+        }
+
         #[test]
         fn allpass_filter_no_panic(delay_samples in 1..1000usize, gain in 0.0..0.99f32) {
             let node = AllpassFilter { delay_samples, gain };
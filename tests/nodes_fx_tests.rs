@@ -1,6 +1,7 @@
 use auxide::node::NodeDef;
 use auxide_dsp::{
-    Chorus, ConvolutionReverb, Delay, Flanger, MultitapDelay, Phaser, SimpleReverb, Tremolo,
+    Chorus, ConvolutionReverb, Delay, Flanger, FractionalDelay, Interpolation, MultitapDelay,
+    Phaser, SimpleReverb, Tremolo,
 };
 
 fn non_silent(output: &[f32]) -> bool {
@@ -49,6 +50,32 @@ fn flanger_runs() {
     assert!(non_silent(&out[0]));
 }
 
+#[test]
+fn chorus_interpolation_keeps_highs() {
+    // Half-sample delay: linear interpolation averages neighbours and
+    // dulls a 12 kHz tone, the sinc kernel passes it
+    let input: Vec<f32> = (0..4096)
+        .map(|i| (std::f32::consts::TAU * 12_000.0 * i as f32 / 44100.0).sin())
+        .collect();
+    let rms = |interpolation| {
+        let node = Chorus {
+            delay_ms: 10.5 / 44.1,
+            depth_ms: 0.0,
+            rate: 0.0,
+            mix: 1.0,
+        }
+        .with_interpolation(interpolation);
+        let mut state = node.init_state(44100.0, 4096);
+        let mut out = vec![vec![0.0; 4096]];
+        node.process_block(&mut state, &[&input], &mut out, 44100.0);
+        let tail = &out[0][1024..];
+        (tail.iter().map(|v| v * v).sum::<f32>() / tail.len() as f32).sqrt()
+    };
+    let full = std::f32::consts::FRAC_1_SQRT_2;
+    assert!(rms(Interpolation::Linear) < 0.7 * full);
+    assert!(rms(Interpolation::Sinc) > 0.95 * full);
+}
+
 #[test]
 fn phaser_runs() {
     let node = Phaser {
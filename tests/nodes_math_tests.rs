@@ -0,0 +1,119 @@
+use auxide::node::NodeDef;
+use auxide_dsp::nodes::math::{
+    Abs, Add, Clamp, Cos, Div, Exp, Ln, Multiply, Offset, Pow, Scale, Sin, Sub,
+};
+
+/// Run a node over one 4-sample block.
+fn run<T: NodeDef>(node: &T, inputs: &[&[f32]]) -> Vec<f32> {
+    let mut state = node.init_state(44100.0, 4);
+    let mut out = vec![vec![0.0; 4]];
+    node.process_block(&mut state, inputs, &mut out, 44100.0);
+    out.remove(0)
+}
+
+#[test]
+fn nary_nodes_fold_operands() {
+    let a = [1.0, 2.0, 3.0, 4.0];
+    let b = [0.5; 4];
+    let c = [2.0; 4];
+    assert_eq!(run(&Add { inputs: 3 }, &[&a, &b, &c]), [3.5, 4.5, 5.5, 6.5]);
+    assert_eq!(
+        run(&Sub { inputs: 3 }, &[&a, &b, &c]),
+        [-1.5, -0.5, 0.5, 1.5]
+    );
+    assert_eq!(run(&Multiply { inputs: 3 }, &[&a, &b, &c]), a);
+    assert_eq!(run(&Div { inputs: 2 }, &[&a, &c]), [0.5, 1.0, 1.5, 2.0]);
+}
+
+#[test]
+fn nary_port_count_is_clamped() {
+    assert_eq!(Add { inputs: 0 }.input_ports().len(), 2);
+    assert_eq!(Add { inputs: 5 }.input_ports().len(), 5);
+    assert_eq!(Multiply { inputs: 100 }.input_ports().len(), 8);
+}
+
+#[test]
+fn unconnected_operands_are_skipped() {
+    let a = [1.0, 2.0, 3.0, 4.0];
+    assert_eq!(
+        run(&Multiply { inputs: 4 }, &[&a, &[], &[2.0; 4]]),
+        [2.0, 4.0, 6.0, 8.0]
+    );
+}
+
+#[test]
+fn div_and_ln_stay_finite() {
+    let num = [1.0, -1.0, f32::INFINITY, f32::NAN];
+    let den = [0.0, -0.0, 1.0, 1.0];
+    let out = run(&Div { inputs: 2 }, &[&num, &den]);
+    assert!(out.iter().all(|x| x.is_finite()));
+    assert!(out[0] > 0.0 && out[1] > 0.0);
+    assert_eq!(&out[2..], [0.0, 0.0]);
+
+    // With only the numerator connected the result is still sanitized
+    let lone = [f32::NAN, f32::INFINITY, f32::NEG_INFINITY, 2.0];
+    assert_eq!(run(&Div { inputs: 2 }, &[&lone]), [0.0, 0.0, 0.0, 2.0]);
+
+    let out = run(&Ln, &[&[0.0, -1.0, f32::NAN, std::f32::consts::E]]);
+    assert!(out.iter().all(|x| x.is_finite()));
+    assert!((out[3] - 1.0).abs() < 1e-6);
+
+    let out = run(&Ln, &[&[f32::INFINITY, f32::NAN, f32::NEG_INFINITY, 1.0]]);
+    assert!(out.iter().all(|x| x.is_finite()));
+    assert_eq!(out[0], 0.0);
+    assert_eq!(out[1], out[2]);
+    assert_eq!(out[3], 0.0);
+}
+
+#[test]
+fn unary_nodes_compute() {
+    let x = [-2.0, -0.5, 0.25, 1.0];
+    assert_eq!(run(&Scale { factor: 2.0 }, &[&x]), [-4.0, -1.0, 0.5, 2.0]);
+    assert_eq!(run(&Offset { amount: 1.0 }, &[&x]), [-1.0, 0.5, 1.25, 2.0]);
+    assert_eq!(run(&Abs, &[&x]), [2.0, 0.5, 0.25, 1.0]);
+    assert_eq!(
+        run(
+            &Clamp {
+                min: -1.0,
+                max: 0.5
+            },
+            &[&x]
+        ),
+        [-1.0, -0.5, 0.25, 0.5]
+    );
+    assert_eq!(run(&Pow { exponent: 2.0 }, &[&x]), [4.0, 0.25, 0.0625, 1.0]);
+    assert!(run(&Pow { exponent: 0.5 }, &[&x])
+        .iter()
+        .all(|v| v.is_finite()));
+    assert_eq!(run(&Exp, &[&[0.0, 1000.0, 0.0, 0.0]])[..2], [1.0, 0.0]);
+    let s = run(&Sin, &[&x]);
+    let c = run(&Cos, &[&x]);
+    assert!((s[2] - 1.0).abs() < 1e-6);
+    assert!((c[3] - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn scale_factor_is_modulated() {
+    let out = run(&Scale { factor: 1.0 }, &[&[1.0; 4], &[0.0, 1.0, 2.0, 3.0]]);
+    assert_eq!(out, [1.0, 2.0, 3.0, 4.0]);
+}
+
+#[cfg(test)]
+mod property_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn div_never_outputs_non_finite(a in proptest::num::f32::ANY, b in proptest::num::f32::ANY) {
+            let out = run(&Div { inputs: 2 }, &[&[a; 4], &[b; 4]]);
+            prop_assert!(out.iter().all(|x| x.is_finite()));
+        }
+
+        #[test]
+        fn ln_never_outputs_non_finite(a in -1.0e6..1.0e6f32) {
+            let out = run(&Ln, &[&[a; 4]]);
+            prop_assert!(out.iter().all(|x| x.is_finite()));
+        }
+    }
+}
@@ -3,8 +3,8 @@
 use std::sync::Arc;
 
 use auxide::node::NodeDef;
-use auxide_dsp::generate_sine_table;
 use auxide_dsp::nodes::oscillators::*;
+use auxide_dsp::{generate_saw_table, generate_sine_table, WavetableBank};
 
 fn non_silent(buf: &[f32]) -> bool {
     buf.iter().any(|&v| v.abs() > 1e-6)
@@ -31,6 +31,92 @@ fn square_runs() {
     assert!(non_silent(&out[0]));
 }
 
+fn render<T: NodeDef>(node: &T, inputs: &[&[f32]], len: usize) -> Vec<f32> {
+    let mut state = node.init_state(48_000.0, len);
+    let mut out = vec![vec![0.0; len]];
+    node.process_block(&mut state, inputs, &mut out, 48_000.0);
+    out.remove(0)
+}
+
+fn upward_crossings(buf: &[f32]) -> usize {
+    buf.windows(2).filter(|w| w[0] < 0.0 && w[1] >= 0.0).count()
+}
+
+#[test]
+fn saw_voct_raises_octave() {
+    let node = SawOsc::new(100.0);
+    let zeros = [0.0; 4800];
+    let base = render(&node, &[], 4800);
+    let octave = render(&node, &[&zeros, &[1.0; 4800]], 4800);
+    assert_eq!(upward_crossings(&base), 10);
+    assert_eq!(upward_crossings(&octave), 20);
+}
+
+#[test]
+fn saw_through_zero_fm_runs_backwards() {
+    let node = SawOsc::new(100.0);
+    let zeros = [0.0; 4800];
+    let out = render(&node, &[&zeros, &zeros, &[-2.0; 4800]], 4800);
+    let falling = out.windows(2).filter(|w| w[1] < w[0]).count();
+    assert!(falling > 4700, "falling steps {}", falling);
+    assert!(out.iter().all(|v| v.abs() < 1.1));
+}
+
+#[test]
+fn saw_phase_modulation_offsets_phase() {
+    let node = SawOsc::new(0.0);
+    let zeros = [0.0; 8];
+    let out = render(&node, &[&zeros, &zeros, &zeros, &[0.25; 8]], 8);
+    // Phase modulation lags by one sample
+    assert!((out[0] + 1.0).abs() < 1e-6);
+    assert!(out[1..].iter().all(|v| (v + 0.5).abs() < 1e-6));
+}
+
+#[test]
+fn saw_sync_restarts_cycle() {
+    let node = SawOsc::new(100.0);
+    let zeros = [0.0; 2000];
+    let mut sync = [0.0; 2000];
+    sync[1000..1010].fill(1.0);
+    let out = render(&node, &[&zeros, &zeros, &zeros, &zeros, &sync], 2000);
+    // The reset lands half a sample after sample 1000
+    let inc = 100.0 / 48_000.0;
+    let expected = 2.0 * 9.5 * inc - 1.0;
+    assert!((out[1010] - expected).abs() < 1e-4);
+}
+
+#[test]
+fn sync_at_natural_wrap_matches_free_running() {
+    // Period of 100.5 samples wraps halfway between samples 100 and 101,
+    // exactly where a sync edge crossing between 99 and 100 resets it
+    let node = SawOsc::new(48_000.0 / 100.5);
+    let zeros = [0.0; 150];
+    let mut sync = [0.0; 150];
+    sync[99] = 0.25;
+    sync[100] = 0.75;
+    let free = render(&node, &[], 150);
+    let synced = render(&node, &[&zeros, &zeros, &zeros, &zeros, &sync], 150);
+    for (a, b) in free.iter().zip(&synced) {
+        assert!((a - b).abs() < 1e-3, "{} vs {}", a, b);
+    }
+}
+
+#[test]
+fn pulse_width_modulation_input() {
+    let node = PulseOsc {
+        freq: 100.0,
+        pulse_width: 0.5,
+    };
+    let zeros = [0.0; 480];
+    let out = render(
+        &node,
+        &[&zeros, &zeros, &zeros, &zeros, &zeros, &[-0.25; 480]],
+        480,
+    );
+    let high = out.iter().filter(|&&v| v > 0.0).count();
+    assert!((118..=122).contains(&high), "high samples {}", high);
+}
+
 #[test]
 fn wavetable_runs() {
     let table = Arc::new(generate_sine_table(64));
@@ -41,6 +127,113 @@ fn wavetable_runs() {
     assert!(non_silent(&out[0]));
 }
 
+#[test]
+fn wavetable_does_not_alias() {
+    // Saws at multiples of 10 Hz: over 4410 samples every harmonic lands on
+    // a bin, aliases of a level with partials above Nyquist fall in between.
+    // The sweep covers several octaves of non-power-of-two table increments.
+    let sr = 44_100.0;
+    let table = Arc::new(generate_saw_table(2048));
+    for freq in [
+        110.0, 350.0, 1230.0, 2750.0, 3900.0, 6170.0, 9800.0, 15_000.0,
+    ] {
+        let node = WavetableOsc {
+            freq,
+            table: table.clone(),
+        };
+        let mut state = node.init_state(sr, 4410);
+        let mut out = vec![vec![0.0; 4410]];
+        node.process_block(&mut state, &[], &mut out, sr);
+        let x = &out[0];
+        let n = x.len() as f64;
+
+        let total: f64 = x.iter().map(|&v| v as f64 * v as f64).sum::<f64>() / n;
+        let mut harmonic = 0.0;
+        let bin = (freq / 10.0) as usize;
+        for h in 1..=(2204 / bin) {
+            let w = std::f64::consts::TAU * (bin * h) as f64 / n;
+            let (re, im) = x
+                .iter()
+                .enumerate()
+                .fold((0.0f64, 0.0f64), |(re, im), (i, &v)| {
+                    let phase = w * i as f64;
+                    (re + v as f64 * phase.cos(), im - v as f64 * phase.sin())
+                });
+            harmonic += 2.0 * (re * re + im * im) / (n * n);
+        }
+        let residual = (total - harmonic) / total;
+        assert!(
+            residual < 1e-4,
+            "{} Hz: alias energy ratio {}",
+            freq,
+            residual
+        );
+    }
+}
+
+#[test]
+fn wavetable_morph_scans_frames() {
+    let sine = generate_sine_table(256);
+    let bank = Arc::new(WavetableBank::from_frames(&[sine.clone(), vec![0.0; 256]]));
+    let render = |position: f32| {
+        let node = WavetableMorphOsc {
+            freq: 100.0,
+            position,
+            phase: 0.0,
+            bank: bank.clone(),
+        };
+        let mut state = node.init_state(48_000.0, 64);
+        let mut out = vec![vec![0.0; 64]];
+        node.process_block(&mut state, &[], &mut out, 48_000.0);
+        out.remove(0)
+    };
+    let first = render(0.0);
+    let half = render(0.5);
+    let last = render(1.0);
+    assert!(non_silent(&first));
+    assert!(!non_silent(&last));
+    for (a, b) in first.iter().zip(&half) {
+        assert!((a * 0.5 - b).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn wavetable_morph_position_and_phase_mod() {
+    let bank = Arc::new(WavetableBank::from_generators(
+        &[generate_sine_table, generate_saw_table],
+        256,
+    ));
+    let node = WavetableMorphOsc {
+        freq: 0.0,
+        position: 0.0,
+        phase: 0.0,
+        bank,
+    };
+    let mut state = node.init_state(48_000.0, 4);
+    let mut out = vec![vec![0.0; 4]];
+    let zeros = [0.0; 4];
+    // Quarter-cycle phase offset at a frozen phase reads the sine peak
+    node.process_block(
+        &mut state,
+        &[&zeros, &zeros, &[0.25; 4]],
+        &mut out,
+        48_000.0,
+    );
+    assert!(out[0].iter().all(|&x| (x - 1.0).abs() < 1e-3));
+    // Full position modulation switches to the saw frame
+    node.process_block(
+        &mut state,
+        &[&zeros, &[1.0; 4], &[0.25; 4]],
+        &mut out,
+        48_000.0,
+    );
+    assert!(
+        out[0].iter().all(|&x| (x + 0.5).abs() < 0.05),
+        "{:?}",
+        out[0]
+    );
+}
+
 #[test]
 fn supersaw_runs() {
     let node = SuperSaw {
@@ -54,6 +247,98 @@ fn supersaw_runs() {
     assert!(non_silent(&out[0]));
 }
 
+fn fm_voice(freq: f32, algorithm: FmAlgorithm) -> FmVoice {
+    let mut voice = FmVoice::new(freq, algorithm);
+    for op in voice.operators.iter_mut() {
+        op.envelope.attack_ms = 0.0;
+        op.envelope.decay_ms = 0.0;
+    }
+    voice
+}
+
+#[test]
+fn fm_single_carrier_is_sine() {
+    let node = fm_voice(440.0, FmAlgorithm::new([0; 6], 0b1));
+    let out = render(&node, &[], 256);
+    // The envelope leaves idle on the first sample
+    for (i, v) in out.iter().enumerate().skip(1) {
+        let expected = (2.0 * std::f32::consts::PI * 440.0 * i as f32 / 48_000.0).sin();
+        assert!(
+            (v - expected).abs() < 1e-3,
+            "sample {}: {} vs {}",
+            i,
+            v,
+            expected
+        );
+    }
+}
+
+#[test]
+fn fm_modulator_follows_algorithm() {
+    let voice = |algorithm: FmAlgorithm| {
+        let mut voice = fm_voice(220.0, algorithm);
+        voice.operators[1].ratio = 2.0;
+        voice.operators[1].level = 3.0;
+        voice
+    };
+    let parallel = render(&voice(FmAlgorithm::new([0; 6], 0b11)), &[], 512);
+    let stacked = render(&voice(FmAlgorithm::stack(2)), &[], 512);
+    // In parallel the loud second operator dominates the average; stacked
+    // it only bends the carrier, which stays within its own level
+    assert!(parallel.iter().any(|v| v.abs() > 1.1));
+    assert!(stacked.iter().all(|v| v.abs() <= 1.0 + 1e-6));
+    let diff: f32 = parallel
+        .iter()
+        .zip(&stacked)
+        .map(|(a, b)| (a - b).abs())
+        .sum();
+    assert!(diff > 1.0);
+}
+
+#[test]
+fn fm_algorithm_drops_upward_routes() {
+    let algorithm = FmAlgorithm::new([0b0001, 0b0001, 0b0001, 0, 0, 0], 0b1);
+    assert_eq!(algorithm.modulators, [0; 6]);
+    assert_eq!(FmAlgorithm::stack(3).modulators, [0b010, 0b100, 0, 0, 0, 0]);
+}
+
+#[test]
+fn fm_six_operator_algorithms() {
+    for (number, algorithm) in FmAlgorithm::SIX_OP.iter().enumerate() {
+        // new() keeps every route, so none point upwards
+        assert_eq!(
+            FmAlgorithm::new(algorithm.modulators, algorithm.carriers),
+            *algorithm
+        );
+        // Every operator is either a carrier or modulates something
+        let modulating = algorithm.modulators.iter().fold(0, |acc, m| acc | m);
+        assert_eq!(algorithm.carriers | modulating, 0b11_1111, "{}", number + 1);
+        assert_eq!(FmAlgorithm::six_op(number + 1), *algorithm);
+    }
+    assert_eq!(FmAlgorithm::SIX_OP[31].carriers, 0b11_1111);
+    assert_eq!(FmAlgorithm::SIX_OP_FEEDBACK[0], 5);
+
+    // Operators 5 and 6 are heard through the DX7 routings
+    let mut voice = fm_voice(220.0, FmAlgorithm::six_op(1));
+    let reference = render(&voice, &[], 512);
+    voice.operators[5].level = 0.0;
+    assert_ne!(render(&voice, &[], 512), reference);
+}
+
+#[test]
+fn fm_gate_and_fixed_frequency() {
+    let mut node = fm_voice(100.0, FmAlgorithm::new([0; 6], 0b1));
+    node.operators[0].fixed_freq = Some(1000.0);
+    node.operators[0].envelope.release_ms = 1.0;
+    let zeros = [0.0; 4800];
+    let mut gate = [0.0; 4800];
+    gate[..2400].fill(1.0);
+    let out = render(&node, &[&zeros, &zeros, &gate], 4800);
+    // 1 kHz for 50 ms regardless of the voice frequency, then silence
+    assert_eq!(upward_crossings(&out[..2400]), 49);
+    assert!(out[2600..].iter().all(|v| v.abs() < 1e-6));
+}
+
 #[test]
 fn noise_runs() {
     let mut out = vec![vec![0.0; 64]];
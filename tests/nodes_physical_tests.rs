@@ -0,0 +1,252 @@
+use auxide::node::NodeDef;
+use auxide_dsp::nodes::physical::{
+    KarplusStrong, ModalPreset, ModalResonator, Mode, Waveguide, WaveguideModel,
+};
+
+const SR: f32 = 48_000.0;
+
+/// Run a node over one block of `len` samples.
+fn render<T: NodeDef>(node: &T, inputs: &[&[f32]], len: usize) -> Vec<f32> {
+    let mut state = node.init_state(SR, len);
+    let mut out = vec![vec![0.0; len]];
+    node.process_block(&mut state, inputs, &mut out, SR);
+    out.remove(0)
+}
+
+/// Frequency near `expected` from the phase drift of its DFT bin between
+/// two windows 12000 samples apart.
+fn measured_freq(buf: &[f32], expected: f32) -> f32 {
+    let w = std::f64::consts::TAU * expected as f64 / SR as f64;
+    let phase = |start: usize| {
+        let (re, im) = buf[start..start + 4800].iter().enumerate().fold(
+            (0.0f64, 0.0f64),
+            |(re, im), (i, &v)| {
+                let t = w * (start + i) as f64;
+                (re + v as f64 * t.cos(), im - v as f64 * t.sin())
+            },
+        );
+        im.atan2(re)
+    };
+    let drift = phase(12_000) - phase(0);
+    let drift =
+        (drift + std::f64::consts::PI).rem_euclid(std::f64::consts::TAU) - std::f64::consts::PI;
+    expected + (drift / w / 12_000.0) as f32 * expected
+}
+
+fn cents(measured: f32, expected: f32) -> f32 {
+    1200.0 * (measured / expected).log2().abs()
+}
+
+fn pluck(freq: f32, stretch: f32) -> KarplusStrong {
+    KarplusStrong {
+        freq,
+        damping: 0.5,
+        decay_ms: 10_000.0,
+        stretch,
+        pick_position: 0.0,
+    }
+}
+
+fn trigger(len: usize) -> Vec<f32> {
+    let mut trigger = vec![0.0; len];
+    trigger[0] = 1.0;
+    trigger
+}
+
+#[test]
+fn pluck_tuning_is_accurate() {
+    let len = 48_000;
+    let trig = trigger(len);
+    for &(freq, stretch) in &[(110.0, 0.0), (440.0, 0.0), (1234.5, 0.0), (329.6, 1.0)] {
+        let out = render(&pluck(freq, stretch), &[&[], &trig], len);
+        let measured = measured_freq(&out[2400..], freq);
+        assert!(
+            cents(measured, freq) < 1.0,
+            "{} Hz measured {} Hz",
+            freq,
+            measured
+        );
+    }
+}
+
+#[test]
+fn pluck_decays_and_needs_trigger() {
+    let len = 48_000;
+    let mut node = pluck(220.0, 0.0);
+    node.decay_ms = 200.0;
+    node.damping = 0.3;
+    let trig = trigger(len);
+    let out = render(&node, &[&[], &trig], len);
+    let peak = |buf: &[f32]| buf.iter().fold(0.0f32, |m, v| m.max(v.abs()));
+    assert!(peak(&out[..2400]) > 0.1);
+    assert!(peak(&out[24_000..]) < peak(&out[..2400]) * 1e-3);
+
+    // Without a trigger or excitation the string stays silent
+    let silent = render(&node, &[], 4800);
+    assert!(silent.iter().all(|&v| v == 0.0));
+}
+
+#[test]
+fn pluck_uses_excitation_input() {
+    let len = 4800;
+    let trig = trigger(len);
+    let mut impulse = vec![0.0; len];
+    impulse[0] = 1.0;
+    let mut node = pluck(480.0, 0.0);
+    node.damping = 0.0;
+    let out = render(&node, &[&impulse, &trig], len);
+    // A single impulse returns once per period
+    let period = 100;
+    assert!(out[0] == 1.0);
+    assert!(out[1..period - 2].iter().all(|v| v.abs() < 0.05));
+    assert!(out[period - 2..period + 2].iter().any(|v| v.abs() > 0.1));
+}
+
+#[test]
+fn pick_position_notches_harmonics() {
+    // Picking at half the string cancels the even harmonics
+    let len = 48_000;
+    let trig = trigger(len);
+    let mut node = pluck(200.0, 0.0);
+    node.damping = 0.0;
+    node.pick_position = 0.5;
+    let out = render(&node, &[&[], &trig], len);
+    let window = &out[4800..4800 + 4800];
+    let energy = |bin: usize| {
+        let w = std::f32::consts::TAU * bin as f32 / window.len() as f32;
+        let (re, im) = window
+            .iter()
+            .enumerate()
+            .fold((0.0f32, 0.0f32), |(re, im), (i, v)| {
+                (re + v * (w * i as f32).cos(), im + v * (w * i as f32).sin())
+            });
+        re * re + im * im
+    };
+    // 10 Hz bins: fundamental at 20, second harmonic at 40
+    assert!(energy(40) < energy(20) * 1e-2);
+}
+
+#[test]
+fn waveguides_sustain_at_pitch() {
+    let len = 48_000;
+    for model in [WaveguideModel::Bowed, WaveguideModel::Blown] {
+        let node = Waveguide {
+            model,
+            freq: 240.0,
+            pressure: 0.6,
+            position: 0.127,
+            damping: 0.5,
+        };
+        let out = render(&node, &[], len);
+        let tail = &out[24_000..];
+        assert!(tail.iter().all(|v| v.is_finite() && v.abs() < 4.0));
+        // Periodic at 200 samples, and not at half the period
+        let corr = |lag: usize| -> f32 {
+            tail[..20_000]
+                .iter()
+                .zip(&tail[lag..])
+                .map(|(a, b)| a * b)
+                .sum()
+        };
+        assert!(corr(0) / 20_000.0 > 1e-4, "{:?} is silent", model);
+        assert!(corr(200) > 0.95 * corr(0), "{:?}", model);
+        assert!(corr(100) < 0.5 * corr(0), "{:?}", model);
+    }
+}
+
+#[test]
+fn waveguide_gate_releases() {
+    let len = 48_000;
+    let node = Waveguide {
+        model: WaveguideModel::Blown,
+        freq: 330.0,
+        pressure: 0.5,
+        position: 0.127,
+        damping: 0.5,
+    };
+    let mut gate = vec![0.0; len];
+    gate[..24_000].fill(1.0);
+    let out = render(&node, &[&[], &gate], len);
+    let peak = |buf: &[f32]| buf.iter().fold(0.0f32, |m, v| m.max(v.abs()));
+    assert!(peak(&out[12_000..24_000]) > 0.05);
+    assert!(peak(&out[40_000..]) < 1e-3);
+}
+
+fn impulse(len: usize) -> Vec<f32> {
+    let mut x = vec![0.0; len];
+    x[0] = 1.0;
+    x
+}
+
+#[test]
+fn modal_mode_impulse_response() {
+    let node = ModalResonator {
+        freq: 1000.0,
+        damping: 0.0,
+        modes: vec![Mode {
+            ratio: 1.0,
+            decay_ms: 1000.0,
+            gain: 0.5,
+        }],
+    };
+    let out = render(&node, &[&impulse(4800)], 4800);
+    let theta = std::f32::consts::TAU * 1000.0 / SR;
+    let r = 10f32.powf(-3.0 / SR);
+    for (n, v) in out.iter().enumerate().take(200) {
+        let expected = 0.5 * r.powi(n as i32) * ((n + 1) as f32 * theta).sin();
+        assert!((v - expected).abs() < 1e-4, "sample {}", n);
+    }
+}
+
+#[test]
+fn modal_presets_ring_and_damping_shortens() {
+    let len = 24_000;
+    let energy = |buf: &[f32]| buf.iter().map(|v| v * v).sum::<f32>();
+    for preset in [
+        ModalPreset::Bar,
+        ModalPreset::Bell,
+        ModalPreset::Membrane,
+        ModalPreset::Glass,
+    ] {
+        let mut node = ModalResonator::new(220.0, preset);
+        let free = render(&node, &[&impulse(len)], len);
+        assert!(free.iter().all(|v| v.is_finite()));
+        assert!(energy(&free[12_000..]) > 0.0, "{:?}", preset);
+        node.damping = 0.8;
+        let damped = render(&node, &[&impulse(len)], len);
+        assert!(
+            energy(&damped[4800..]) < energy(&free[4800..]) * 0.1,
+            "{:?}",
+            preset
+        );
+    }
+}
+
+#[test]
+fn modal_freq_mod_and_nyquist() {
+    let len = 4800;
+    let node = ModalResonator::new(440.0, ModalPreset::Bar);
+    // 18.64 x 2000 Hz is above Nyquist and must stay silent, not blow up
+    let out = render(&node, &[&impulse(len), &[1560.0; 4800]], len);
+    assert!(out.iter().all(|v| v.is_finite() && v.abs() < 4.0));
+    let w = std::f32::consts::TAU * 2000.0 / SR;
+    let (re, im) = out
+        .iter()
+        .enumerate()
+        .fold((0.0f32, 0.0f32), |(re, im), (i, v)| {
+            (re + v * (w * i as f32).cos(), im + v * (w * i as f32).sin())
+        });
+    assert!((re * re + im * im).sqrt() > 10.0);
+}
+
+#[test]
+fn modal_modulation_is_read_at_control_rate() {
+    let len = 4800;
+    let node = ModalResonator::new(440.0, ModalPreset::Bell);
+    let sweep: Vec<f32> = (0..len).map(|i| 200.0 * (i as f32 * 0.01).sin()).collect();
+    // Only the value at the start of each 32-sample frame takes effect
+    let held: Vec<f32> = (0..len).map(|i| sweep[i - i % 32]).collect();
+    let swept = render(&node, &[&impulse(len), &sweep], len);
+    assert_eq!(swept, render(&node, &[&impulse(len), &held], len));
+    assert_ne!(swept, render(&node, &[&impulse(len)], len));
+}
@@ -0,0 +1,200 @@
+use std::sync::Arc;
+
+use auxide::node::NodeDef;
+use auxide_dsp::nodes::sampler::{Interpolation, LoopMode, SamplePlayer};
+use auxide_dsp::wav::WavData;
+
+const SR: f32 = 48_000.0;
+
+/// Run a node over one block of `len` samples, returning left and right.
+fn render(node: &SamplePlayer, inputs: &[&[f32]], len: usize) -> (Vec<f32>, Vec<f32>) {
+    let mut state = node.init_state(SR, len);
+    let mut out = vec![vec![0.0; len]; 2];
+    node.process_block(&mut state, inputs, &mut out, SR);
+    let right = out.pop().unwrap();
+    (out.pop().unwrap(), right)
+}
+
+fn ramp(len: usize) -> Arc<[f32]> {
+    (0..len).map(|i| i as f32).collect()
+}
+
+fn looped(mode: LoopMode) -> SamplePlayer {
+    SamplePlayer {
+        loop_mode: mode,
+        loop_start: 100,
+        loop_end: 200,
+        ..SamplePlayer::new(ramp(1000), 1, SR)
+    }
+}
+
+#[test]
+fn one_shot_plays_sample_then_stops() {
+    for interpolation in [
+        Interpolation::Linear,
+        Interpolation::Cubic,
+        Interpolation::Sinc,
+    ] {
+        let node = SamplePlayer {
+            interpolation,
+            ..SamplePlayer::new(ramp(100), 1, SR)
+        };
+        let (left, right) = render(&node, &[], 200);
+        for (i, v) in left[..100].iter().enumerate() {
+            assert!((v - i as f32).abs() < 1e-3, "{:?} at {}", interpolation, i);
+        }
+        assert!(left[100..].iter().all(|&v| v == 0.0));
+        assert_eq!(left, right);
+    }
+}
+
+#[test]
+fn start_offset_and_sample_rate() {
+    let node = SamplePlayer {
+        start: 0.5,
+        ..SamplePlayer::new(ramp(1001), 1, SR * 2.0)
+    };
+    let (left, _) = render(&node, &[], 8);
+    assert_eq!(left[..4], [500.0, 502.0, 504.0, 506.0]);
+}
+
+#[test]
+fn freq_input_tracks_pitch() {
+    let node = SamplePlayer {
+        root_freq: 220.0,
+        interpolation: Interpolation::Linear,
+        ..SamplePlayer::new(ramp(1000), 1, SR)
+    };
+    let (left, _) = render(&node, &[&[], &[330.0; 64]], 64);
+    assert!((left[10] - 15.0).abs() < 1e-3);
+    // The ratio port scales on top of the tracked frequency
+    let (left, _) = render(&node, &[&[], &[330.0; 64], &[1.0; 64]], 64);
+    assert!((left[10] - 30.0).abs() < 1e-3);
+}
+
+#[test]
+fn interpolation_quality_ordering() {
+    let freq = 6000.0;
+    let sample: Arc<[f32]> = (0..4800)
+        .map(|i| (std::f32::consts::TAU * freq * i as f32 / SR).sin())
+        .collect();
+    let ratio = 0.7319;
+    let error = |interpolation| {
+        let node = SamplePlayer {
+            ratio,
+            interpolation,
+            ..SamplePlayer::new(sample.clone(), 1, SR)
+        };
+        let (left, _) = render(&node, &[], 4000);
+        left[100..]
+            .iter()
+            .enumerate()
+            .map(|(n, v)| {
+                let pos = (n + 100) as f64 * ratio as f64;
+                let expected = (std::f64::consts::TAU * freq as f64 * pos / SR as f64).sin();
+                (*v as f64 - expected).abs()
+            })
+            .fold(0.0, f64::max)
+    };
+    let linear = error(Interpolation::Linear);
+    let cubic = error(Interpolation::Cubic);
+    let sinc = error(Interpolation::Sinc);
+    assert!(cubic < linear, "{} {}", cubic, linear);
+    assert!(sinc < cubic * 0.5, "{} {}", sinc, cubic);
+    assert!(sinc < 0.01);
+}
+
+#[test]
+fn forward_loop_wraps() {
+    let (left, _) = render(&looped(LoopMode::Forward), &[], 400);
+    assert_eq!(left[199], 199.0);
+    assert_eq!(left[200], 100.0);
+    assert_eq!(left[300], 100.0);
+}
+
+#[test]
+fn forward_loop_crossfades_seam() {
+    let mut node = looped(LoopMode::Forward);
+    node.crossfade = 50;
+    let (left, _) = render(&node, &[], 400);
+    // Halfway through the fade the loop end meets the audio before the start
+    assert!((left[175] - (0.5 * 175.0 + 0.5 * 75.0)).abs() < 1e-3);
+    assert!((left[199] - (0.02 * 199.0 + 0.98 * 99.0)).abs() < 1e-3);
+    assert_eq!(left[200], 100.0);
+    // The jump at the seam is no bigger than one step of the faded ramp
+    assert!(left[199..202].windows(2).all(|w| (w[1] - w[0]).abs() < 3.0));
+}
+
+#[test]
+fn ping_pong_and_reverse_loops() {
+    let (left, _) = render(&looped(LoopMode::PingPong), &[], 400);
+    assert_eq!(left[199..202], [199.0, 200.0, 199.0]);
+    assert_eq!(left[299..302], [101.0, 100.0, 101.0]);
+
+    let (left, _) = render(&looped(LoopMode::Reverse), &[], 400);
+    assert_eq!(left[199..202], [199.0, 200.0, 199.0]);
+    assert_eq!(left[299..302], [101.0, 100.0, 199.0]);
+}
+
+#[test]
+fn reverse_loop_crossfades_seam() {
+    let mut node = looped(LoopMode::Reverse);
+    node.crossfade = 50;
+    let (left, _) = render(&node, &[], 400);
+    // Heading down, position 125 fades halfway toward 225
+    assert!((left[275] - 175.0).abs() < 1e-3);
+    assert_eq!(left[300], 200.0);
+    assert_eq!(left[301], 199.0);
+}
+
+#[test]
+fn gate_triggers_and_releases_loop() {
+    let len = 2000;
+    let mut gate = vec![0.0; len];
+    gate[50..600].fill(1.0);
+    let (left, _) = render(&looped(LoopMode::Forward), &[&gate], len);
+    assert!(left[..50].iter().all(|&v| v == 0.0));
+    assert_eq!(left[50], 0.0);
+    assert_eq!(left[51], 1.0);
+    // Looping while held
+    assert!(left[300..600].iter().all(|&v| (100.0..200.0).contains(&v)));
+    // Released at loop position 150, it plays on through the sample end
+    assert_eq!(left[600], 150.0);
+    assert_eq!(left[601], 151.0);
+    assert_eq!(left[1449], 999.0);
+    assert!(left[1450..].iter().all(|&v| v == 0.0));
+}
+
+#[test]
+fn stereo_and_wav_channels() {
+    let interleaved: Arc<[f32]> = (0..200)
+        .map(|i| if i % 2 == 0 { 1.0 } else { -1.0 })
+        .collect();
+    let node = SamplePlayer::new(interleaved, 2, SR);
+    assert_eq!(node.frames(), 100);
+    let (left, right) = render(&node, &[], 50);
+    assert!(left.iter().all(|&v| (v - 1.0).abs() < 1e-6));
+    assert!(right.iter().all(|&v| (v + 1.0).abs() < 1e-6));
+
+    let wav = WavData {
+        sample_rate: 44_100,
+        channels: 4,
+        samples: vec![1.0, 0.0, 0.0, 1.0, 0.5, 0.5, 0.5, 0.5],
+    };
+    let node = SamplePlayer::from_wav(&wav);
+    assert_eq!((node.channels, node.frames()), (1, 2));
+    assert_eq!(node.sample_rate, 44_100.0);
+    assert_eq!(&node.sample[..], &[0.5, 0.5]);
+}
+
+#[test]
+fn channel_count_is_clamped_like_new() {
+    let node = SamplePlayer {
+        channels: 0,
+        ..SamplePlayer::new(ramp(100), 1, SR)
+    };
+    assert_eq!(node.frames(), 100);
+    let (left, right) = render(&node, &[], 8);
+    assert_eq!(left, right);
+    assert!((left[3] - 3.0).abs() < 1e-3);
+}
@@ -1,6 +1,7 @@
 use auxide::node::NodeDef;
 use auxide_dsp::{
-    BitCrusher, DcBlocker, HardClip, Overdrive, SoftClip, TubeSaturation, WaveShaper,
+    AdaaShaper, AntiAlias, BitCrusher, ChebyshevShaper, DcBlocker, HardClip, Interpolation,
+    Overdrive, SoftClip, TableShaper, TubeSaturation, WaveShaper, Wavefolder,
 };
 
 fn non_silent(output: &[f32]) -> bool {
@@ -92,6 +93,324 @@ fn overdrive_runs() {
     assert!(non_silent(&out[0]));
 }
 
+const SR: f32 = 48_000.0;
+
+fn render<T: NodeDef>(node: &T, input: &[f32]) -> Vec<f32> {
+    let mut state = node.init_state(SR, input.len());
+    let mut out = vec![vec![0.0; input.len()]];
+    node.process_block(&mut state, &[input], &mut out, SR);
+    out.pop().unwrap()
+}
+
+/// Sine starting `delay` samples late.
+fn sine(freq: f32, len: usize, delay: f32) -> Vec<f32> {
+    (0..len)
+        .map(|i| (std::f32::consts::TAU * freq * (i as f32 - delay) / SR).sin())
+        .collect()
+}
+
+/// Magnitude of the component at `freq`, normalized so a unit sine reads 1.
+fn magnitude(buf: &[f32], freq: f32) -> f32 {
+    let w = std::f32::consts::TAU * freq / SR;
+    let (re, im) = buf
+        .iter()
+        .enumerate()
+        .fold((0.0f32, 0.0f32), |(re, im), (i, v)| {
+            (re + v * (w * i as f32).cos(), im + v * (w * i as f32).sin())
+        });
+    2.0 * (re * re + im * im).sqrt() / buf.len() as f32
+}
+
+type Shaper = Box<dyn Fn(&[f32]) -> Vec<f32>>;
+
+/// Each shaper with the given anti-aliasing, driven into its curve.
+fn shapers(anti_alias: AntiAlias) -> Vec<(&'static str, Shaper)> {
+    vec![
+        (
+            "WaveShaper",
+            Box::new(move |x| {
+                render(
+                    &WaveShaper {
+                        drive: 4.0,
+                        mix: 1.0,
+                    }
+                    .with_anti_alias(anti_alias),
+                    x,
+                )
+            }),
+        ),
+        (
+            "HardClip",
+            Box::new(move |x| {
+                render(
+                    &HardClip {
+                        threshold: 0.3,
+                        mix: 1.0,
+                    }
+                    .with_anti_alias(anti_alias),
+                    x,
+                )
+            }),
+        ),
+        (
+            "SoftClip",
+            Box::new(move |x| {
+                render(
+                    &SoftClip {
+                        drive: 0.3,
+                        mix: 1.0,
+                    }
+                    .with_anti_alias(anti_alias),
+                    x,
+                )
+            }),
+        ),
+        (
+            "TubeSaturation",
+            Box::new(move |x| {
+                render(
+                    &TubeSaturation {
+                        drive: 3.0,
+                        asymmetry: 0.5,
+                        mix: 1.0,
+                    }
+                    .with_anti_alias(anti_alias),
+                    x,
+                )
+            }),
+        ),
+        (
+            "Overdrive",
+            Box::new(move |x| {
+                render(
+                    &Overdrive {
+                        drive: 2.0,
+                        mix: 1.0,
+                    }
+                    .with_anti_alias(anti_alias),
+                    x,
+                )
+            }),
+        ),
+        (
+            "Wavefolder",
+            Box::new(move |x| {
+                render(
+                    &Wavefolder {
+                        fold: 3.5,
+                        bias: 0.2,
+                        stages: 1,
+                        mix: 1.0,
+                    }
+                    .with_anti_alias(anti_alias),
+                    x,
+                )
+            }),
+        ),
+    ]
+}
+
+#[test]
+fn adaa_follows_curve_with_its_delay() {
+    // On a slow signal the first order acts like the curve half a sample
+    // late and the second order like it one sample late
+    let len = 4800;
+    let input = sine(50.0, len, 0.0);
+    for (mode, delay) in [(AntiAlias::FirstOrder, 0.5), (AntiAlias::SecondOrder, 1.0)] {
+        let plain = shapers(AntiAlias::Off);
+        for ((name, shaper), (_, reference)) in shapers(mode).iter().zip(&plain) {
+            let out = shaper(&input);
+            let expected = reference(&sine(50.0, len, delay));
+            for i in 2..len {
+                assert!(
+                    (out[i] - expected[i]).abs() < 5e-3,
+                    "{} {:?} at {}: {} vs {}",
+                    name,
+                    mode,
+                    i,
+                    out[i],
+                    expected[i]
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn adaa_handles_constant_input() {
+    // Equal consecutive inputs take the ill-conditioned fallback
+    let input = [0.7; 64];
+    for mode in [AntiAlias::FirstOrder, AntiAlias::SecondOrder] {
+        let plain = shapers(AntiAlias::Off);
+        for ((name, shaper), (_, reference)) in shapers(mode).iter().zip(&plain) {
+            let out = shaper(&input);
+            let expected = reference(&input);
+            assert!(out.iter().all(|v| v.is_finite()), "{}", name);
+            for i in 2..64 {
+                assert!((out[i] - expected[i]).abs() < 1e-5, "{} {:?}", name, mode);
+            }
+        }
+    }
+}
+
+#[test]
+fn adaa_reduces_aliasing() {
+    // The 7th harmonic of 7 kHz folds down to 1 kHz
+    let input: Vec<f32> = sine(7000.0, 4800, 0.0).iter().map(|x| x * 2.0).collect();
+    let alias = |anti_alias| {
+        let node = HardClip {
+            threshold: 0.5,
+            mix: 1.0,
+        }
+        .with_anti_alias(anti_alias);
+        magnitude(&render(&node, &input)[480..], 1000.0)
+    };
+    let off = alias(AntiAlias::Off);
+    let first = alias(AntiAlias::FirstOrder);
+    let second = alias(AntiAlias::SecondOrder);
+    assert!(first < off * 0.2, "{} {}", first, off);
+    assert!(second < first * 0.3, "{} {}", second, first);
+}
+
+#[test]
+fn adaa_mode_switch_keeps_history() {
+    // The cached antiderivative terms are refilled on a mode change, so
+    // after one block without anti-aliasing the output matches a node that
+    // ran anti-aliased throughout
+    let input = sine(3000.0, 128, 0.0);
+    let (first, second) = input.split_at(64);
+    for mode in [AntiAlias::FirstOrder, AntiAlias::SecondOrder] {
+        let node = WaveShaper {
+            drive: 4.0,
+            mix: 1.0,
+        };
+        let expected = render(&node.clone().with_anti_alias(mode), &input);
+        let mut state = node.init_state(SR, 64);
+        let mut out = vec![vec![0.0; 64]];
+        node.process_block(&mut state, &[first], &mut out, SR);
+        state.adaa.anti_alias = mode;
+        node.process_block(&mut state, &[second], &mut out, SR);
+        for i in 0..64 {
+            assert!((out[0][i] - expected[64 + i]).abs() < 1e-6, "{:?}", mode);
+        }
+    }
+}
+
+fn folder(stages: usize) -> Wavefolder {
+    Wavefolder {
+        fold: 1.0,
+        bias: 0.0,
+        stages,
+        mix: 1.0,
+    }
+}
+
+#[test]
+fn wavefolder_reflects_and_holds() {
+    let input = [0.5, 1.5, 2.5, 4.0, 6.0, -1.5, -6.0];
+    let out = render(&folder(2), &input);
+    assert_eq!(out, [0.5, 0.5, -0.5, 0.0, 1.0, -0.5, -1.0]);
+    // No stages is a clipper
+    let out = render(&folder(0), &input);
+    assert_eq!(out, [0.5, 1.0, 1.0, 1.0, 1.0, -1.0, -1.0]);
+}
+
+#[test]
+fn wavefolder_fold_mod_and_bias() {
+    let out = render(&folder(2), &[0.5]);
+    let modded = {
+        let node = folder(2);
+        let mut state = node.init_state(SR, 1);
+        let mut out = vec![vec![0.0; 1]];
+        node.process_block(&mut state, &[&[0.5], &[2.0]], &mut out, SR);
+        out.pop().unwrap()
+    };
+    assert_eq!(out, [0.5]);
+    // Fold 3 sends 0.5 to 1.5, which reflects to 0.5
+    assert_eq!(modded, [0.5]);
+
+    // Bias makes the folding asymmetric and brings in even harmonics
+    let input = sine(100.0, 4800, 0.0);
+    let second = |bias| {
+        let node = Wavefolder {
+            fold: 3.0,
+            bias,
+            ..folder(3)
+        };
+        magnitude(&render(&node, &input), 200.0)
+    };
+    assert!(second(0.0) < 1e-3);
+    assert!(second(0.4) > 0.05);
+}
+
+#[test]
+fn chebyshev_builds_requested_harmonics() {
+    let node = ChebyshevShaper::new(vec![0.5, 0.3, 0.0, 0.2]);
+    let out = render(&node, &sine(100.0, 4800, 0.0));
+    for (harmonic, expected) in [(1, 0.5), (2, 0.3), (3, 0.0), (4, 0.2), (5, 0.0)] {
+        let level = magnitude(&out, 100.0 * harmonic as f32);
+        assert!((level - expected).abs() < 1e-3, "{} {}", harmonic, level);
+    }
+    // Full-scale input carries no DC, even with even harmonics
+    assert!(out.iter().sum::<f32>().abs() / 4800.0 < 1e-4);
+    // Overdriven input is clamped before shaping
+    let out = render(&node, &[3.0, -3.0]);
+    assert!((out[0] - 1.0).abs() < 1e-6);
+    assert!((out[1] - (-0.5 + 0.3 - 0.0 + 0.2)).abs() < 1e-6);
+}
+
+#[test]
+fn table_shaper_interpolates_curve() {
+    let identity = TableShaper::new(vec![-1.0, 1.0].into());
+    let input = [-1.0, -0.25, 0.0, 0.6, 1.0];
+    let out = render(&identity, &input);
+    for (x, y) in input.iter().zip(&out) {
+        assert!((x - y).abs() < 1e-6);
+    }
+
+    // Beyond ±1 the curve's ends hold
+    let out = render(&identity, &[1.5, -4.0]);
+    assert_eq!(out, [1.0, -1.0]);
+
+    let cube = |x: f32| x * x * x;
+    let input: Vec<f32> = sine(37.0, 2000, 0.0).iter().map(|x| x * 0.9).collect();
+    // Four-point Lagrange reproduces a cubic exactly
+    for (interpolation, tolerance) in [
+        (Interpolation::Linear, 1e-3),
+        (Interpolation::Lagrange, 1e-5),
+    ] {
+        let node = TableShaper {
+            interpolation,
+            ..TableShaper::from_fn(cube, 65)
+        };
+        let out = render(&node, &input);
+        for (x, y) in input.iter().zip(&out) {
+            assert!((cube(*x) - y).abs() < tolerance, "{:?}", interpolation);
+        }
+    }
+}
+
+#[test]
+fn table_shaper_holds_ends_outside_unit_range() {
+    let curve = |x: f32| (2.0 * x).tanh();
+    let input = [1.0, 1.5, 40.0, -1.0, -2.0, 0.999, -0.999];
+    for interpolation in [
+        Interpolation::Linear,
+        Interpolation::Lagrange,
+        Interpolation::Sinc,
+    ] {
+        let node = TableShaper {
+            interpolation,
+            ..TableShaper::from_fn(curve, 257)
+        };
+        let out = render(&node, &input);
+        for (x, y) in input.iter().zip(&out) {
+            let expected = curve(x.clamp(-1.0, 1.0));
+            assert!((expected - y).abs() < 1e-4, "{:?} {}", interpolation, x);
+        }
+    }
+}
+
 #[cfg(test)]
 mod property_tests {
     use super::*;
@@ -153,6 +472,16 @@ mod property_tests {
             // Should not panic
         }
 
+        #[test]
+        fn wavefolder_no_panic(fold in 0.1..20.0f32, bias in -1.0..1.0f32, stages in 0..8usize) {
+            let node = Wavefolder { fold, bias, stages, mix: 1.0 }.with_anti_alias(AntiAlias::SecondOrder);
+            let mut state = node.init_state(44100.0, 64);
+            let mut out = vec![vec![0.0; 64]];
+            let input = (0..64).map(|i| (i as f32 / 64.0) * 2.0 - 1.0).collect::<Vec<f32>>();
+            node.process_block(&mut state, &[&input], &mut out, 44100.0);
+            prop_assert!(out[0].iter().all(|v| v.abs() <= 1.0 + 1e-4));
+        }
+
         #[test]
         fn overdrive_no_panic(drive in 0.1..10.0f32, mix in 0.0..1.0f32) {
             let node = Overdrive { drive, mix };
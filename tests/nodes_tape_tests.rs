@@ -0,0 +1,147 @@
+use auxide::node::NodeDef;
+use auxide_dsp::nodes::tape::TapeSaturation;
+
+const SR: f32 = 48_000.0;
+
+fn render(node: &TapeSaturation, input: &[f32]) -> Vec<f32> {
+    let mut state = node.init_state(SR, input.len());
+    let mut out = vec![vec![0.0; input.len()]];
+    node.process_block(&mut state, &[input], &mut out, SR);
+    out.pop().unwrap()
+}
+
+fn sine(freq: f32, amp: f32, len: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| amp * (std::f32::consts::TAU * freq * i as f32 / SR).sin())
+        .collect()
+}
+
+/// Magnitude of the component at `freq`, normalized so a unit sine reads 1.
+fn magnitude(buf: &[f32], freq: f32) -> f32 {
+    let w = std::f32::consts::TAU * freq / SR;
+    let (re, im) = buf
+        .iter()
+        .enumerate()
+        .fold((0.0f32, 0.0f32), |(re, im), (i, v)| {
+            (re + v * (w * i as f32).cos(), im + v * (w * i as f32).sin())
+        });
+    2.0 * (re * re + im * im).sqrt() / buf.len() as f32
+}
+
+#[test]
+fn biased_tape_is_clean_at_low_level() {
+    let node = TapeSaturation {
+        bias: 1.0,
+        ..TapeSaturation::new()
+    };
+    let out = render(&node, &sine(1000.0, 0.05, 9600));
+    let fundamental = magnitude(&out[4800..], 1000.0) / 0.05;
+    assert!((0.9..1.3).contains(&fundamental), "{}", fundamental);
+    assert!(magnitude(&out[4800..], 3000.0) < 1e-3 * 0.05);
+}
+
+#[test]
+fn drive_saturates_with_odd_harmonics() {
+    let third = |drive| {
+        let node = TapeSaturation {
+            drive,
+            ..TapeSaturation::new()
+        };
+        let out = render(&node, &sine(200.0, 1.0, 9600));
+        assert!(out.iter().all(|v| v.is_finite() && v.abs() < 4.0));
+        magnitude(&out[4800..], 600.0) / magnitude(&out[4800..], 200.0)
+    };
+    let light = third(0.2);
+    let heavy = third(4.0);
+    assert!(heavy > 5.0 * light, "{} {}", heavy, light);
+    assert!(heavy > 0.05);
+}
+
+#[test]
+fn under_biased_tape_keeps_remanence() {
+    // A burst then silence leaves the tape magnetised unless it is well biased
+    let mut input = vec![1.0; 200];
+    input.extend(vec![0.0; 200]);
+    let remanence = |bias| {
+        let node = TapeSaturation {
+            bias,
+            ..TapeSaturation::new()
+        };
+        render(&node, &input)[399].abs()
+    };
+    assert!(remanence(0.0) > 0.1, "{}", remanence(0.0));
+    assert!(remanence(1.0) < 0.02, "{}", remanence(1.0));
+}
+
+#[test]
+fn saturation_lowers_headroom() {
+    let peak = |saturation| {
+        let node = TapeSaturation {
+            saturation,
+            drive: 4.0,
+            ..TapeSaturation::new()
+        };
+        let out = render(&node, &sine(200.0, 1.0, 4800));
+        // Relative to the small-signal gain of the same tape
+        let small = render(&node, &sine(200.0, 0.01, 4800));
+        let max = |buf: &[f32]| buf[2400..].iter().fold(0.0f32, |m, v| m.max(v.abs()));
+        max(&out) / (max(&small) * 100.0)
+    };
+    assert!(peak(1.0) < peak(0.0), "{} {}", peak(1.0), peak(0.0));
+    assert!(peak(1.0) < 0.5);
+}
+
+#[test]
+fn wow_and_flutter_modulate_the_delay() {
+    let input = sine(1000.0, 0.1, 24_000);
+    let steady = render(&TapeSaturation::new(), &input);
+    for (wow, flutter) in [(1.0, 0.0), (0.0, 1.0)] {
+        let node = TapeSaturation {
+            wow,
+            flutter,
+            ..TapeSaturation::new()
+        };
+        let out = render(&node, &input);
+        let diff = out
+            .iter()
+            .zip(&steady)
+            .fold(0.0f32, |m, (a, b)| m.max((a - b).abs()));
+        assert!(diff > 0.01, "{} {}: {}", wow, flutter, diff);
+        // The level is unchanged, only the timing moves
+        let rms = |buf: &[f32]| (buf.iter().map(|v| v * v).sum::<f32>() / buf.len() as f32).sqrt();
+        let (level, reference) = (rms(&out[4800..]), rms(&steady[4800..]));
+        assert!(
+            (level - reference).abs() < 0.05 * reference,
+            "{} {}",
+            level,
+            reference
+        );
+    }
+}
+
+#[test]
+fn head_bump_and_hf_loss_shape_response() {
+    let gain = |node: &TapeSaturation, freq: f32| {
+        let out = render(node, &sine(freq, 0.01, 48_000));
+        magnitude(&out[24_000..], freq)
+    };
+    let flat = TapeSaturation {
+        bias: 1.0,
+        ..TapeSaturation::new()
+    };
+    let bumped = TapeSaturation {
+        head_bump: 6.0,
+        head_bump_freq: 60.0,
+        ..flat.clone()
+    };
+    let ratio = gain(&bumped, 60.0) / gain(&flat, 60.0);
+    assert!((ratio - 2.0).abs() < 0.05, "{}", ratio);
+    assert!((gain(&bumped, 5000.0) / gain(&flat, 5000.0) - 1.0).abs() < 0.02);
+
+    let dull = TapeSaturation {
+        hf_loss: 1.0,
+        ..flat.clone()
+    };
+    assert!(gain(&dull, 10_000.0) < 0.3 * gain(&flat, 10_000.0));
+    assert!(gain(&dull, 100.0) > 0.95 * gain(&flat, 100.0));
+}
@@ -0,0 +1,132 @@
+use auxide::node::NodeDef;
+use auxide_dsp::oversampling::{OversampleFactor, OversampleFilter, Oversampled};
+use auxide_dsp::{HardClip, Parameterized};
+
+const SR: f32 = 48_000.0;
+
+fn sine(freq: f32, len: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| (std::f32::consts::TAU * freq * i as f32 / SR).sin())
+        .collect()
+}
+
+/// Magnitude of the component at `freq`, normalized so a unit sine reads 1.
+fn magnitude(buf: &[f32], freq: f32) -> f32 {
+    let w = std::f32::consts::TAU * freq / SR;
+    let (re, im) = buf
+        .iter()
+        .enumerate()
+        .fold((0.0f32, 0.0f32), |(re, im), (i, v)| {
+            (re + v * (w * i as f32).cos(), im + v * (w * i as f32).sin())
+        });
+    2.0 * (re * re + im * im).sqrt() / buf.len() as f32
+}
+
+fn render<T: NodeDef>(node: &T, input: &[f32], block_size: usize) -> Vec<f32> {
+    let mut state = node.init_state(SR, block_size);
+    let mut out = vec![vec![0.0; input.len()]];
+    node.process_block(&mut state, &[input], &mut out, SR);
+    out.pop().unwrap()
+}
+
+fn clean() -> HardClip {
+    HardClip {
+        threshold: 10.0,
+        mix: 1.0,
+    }
+}
+
+const FACTORS: [OversampleFactor; 4] = [
+    OversampleFactor::X2,
+    OversampleFactor::X4,
+    OversampleFactor::X8,
+    OversampleFactor::X16,
+];
+
+#[test]
+fn fir_passes_low_band_with_reported_latency() {
+    let freq = 1000.0;
+    let input = sine(freq, 4800);
+    for factor in FACTORS {
+        let node = Oversampled::new(clean(), factor, OversampleFilter::Fir);
+        let latency = node.latency();
+        let out = render(&node, &input, 64);
+        for (i, v) in out.iter().enumerate().skip(200) {
+            let expected = (std::f32::consts::TAU * freq * (i as f32 - latency) / SR).sin();
+            assert!((v - expected).abs() < 1e-3, "{:?} at {}", factor, i);
+        }
+    }
+}
+
+#[test]
+fn iir_passes_low_band_with_small_latency() {
+    let freq = 500.0;
+    let input = sine(freq, 4800);
+    for factor in FACTORS {
+        let node = Oversampled::new(clean(), factor, OversampleFilter::Iir);
+        let latency = node.latency();
+        assert!(latency < 8.0, "{}", latency);
+        let out = render(&node, &input, 64);
+        assert!((magnitude(&out[480..], freq) - 1.0).abs() < 1e-3);
+        for (i, v) in out.iter().enumerate().skip(200) {
+            let expected = (std::f32::consts::TAU * freq * (i as f32 - latency) / SR).sin();
+            assert!((v - expected).abs() < 0.02, "{:?} at {}", factor, i);
+        }
+    }
+}
+
+#[test]
+fn fir_latency_is_longer_than_iir() {
+    for factor in FACTORS {
+        let fir = Oversampled::new(clean(), factor, OversampleFilter::Fir);
+        let iir = Oversampled::new(clean(), factor, OversampleFilter::Iir);
+        assert!(fir.latency() > iir.latency());
+    }
+    let fir = Oversampled::new(clean(), OversampleFactor::X2, OversampleFilter::Fir);
+    assert_eq!(fir.latency(), 31.0);
+}
+
+#[test]
+fn oversampling_reduces_clipping_aliases() {
+    // The 7th harmonic of 7 kHz folds down to 1 kHz at the base rate
+    let input: Vec<f32> = sine(7000.0, 4800);
+    let clip = HardClip {
+        threshold: 0.2,
+        mix: 1.0,
+    };
+    let plain = magnitude(&render(&clip, &input, 64)[480..], 1000.0);
+    assert!(plain > 0.01, "{}", plain);
+    for filter in [OversampleFilter::Fir, OversampleFilter::Iir] {
+        let node = Oversampled::new(clip.clone(), OversampleFactor::X8, filter);
+        let out = render(&node, &input, 64);
+        let alias = magnitude(&out[480..], 1000.0);
+        assert!(alias < plain * 0.05, "{:?}: {} vs {}", filter, alias, plain);
+        // The fundamental survives
+        assert!(magnitude(&out[480..], 7000.0) > 0.2);
+    }
+}
+
+#[test]
+fn long_buffers_are_processed_in_chunks() {
+    let input = sine(3000.0, 1000);
+    let node = Oversampled::new(clean(), OversampleFactor::X4, OversampleFilter::Iir);
+    let whole = render(&node, &input, 1000);
+    let chunked = render(&node, &input, 64);
+    assert_eq!(whole, chunked);
+}
+
+#[test]
+fn zero_block_size_still_processes() {
+    let input = sine(3000.0, 100);
+    let node = Oversampled::new(clean(), OversampleFactor::X2, OversampleFilter::Iir);
+    assert_eq!(render(&node, &input, 0), render(&node, &input, 100));
+}
+
+#[test]
+fn params_forward_to_wrapped_node() {
+    let mut node = Oversampled::new(clean(), OversampleFactor::X2, OversampleFilter::Fir);
+    assert_eq!(node.params().len(), node.node.params().len());
+    node.set_param(0, 0.5);
+    assert_eq!(node.get_param(0), Some(0.5));
+    assert_eq!(node.node.threshold, 0.5);
+}
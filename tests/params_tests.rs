@@ -0,0 +1,129 @@
+use auxide::node::NodeDef;
+use auxide_dsp::nodes::filters::{SvfFilter, SvfMode};
+use auxide_dsp::nodes::fx::ConvolutionReverb;
+use auxide_dsp::nodes::oscillators::Constant;
+use auxide_dsp::params::{
+    ParamNode, ParamUnit, Parameterized, SmoothedValue, DEFAULT_SMOOTHING_MS,
+};
+
+#[test]
+fn svf_declares_params() {
+    let node = SvfFilter {
+        cutoff: 1000.0,
+        resonance: 0.5,
+        mode: SvfMode::Lowpass,
+    };
+    let params = node.params();
+    assert_eq!(params.len(), 2);
+    assert_eq!(params[0].name, "cutoff");
+    assert_eq!(params[0].unit, ParamUnit::Hertz);
+    assert_eq!(node.get_param(1), Some(0.5));
+    assert_eq!(node.get_param(7), None);
+}
+
+#[test]
+fn handle_clamps_and_rejects_unknown() {
+    let (_node, handle) = ParamNode::new(SvfFilter {
+        cutoff: 1000.0,
+        resonance: 0.5,
+        mode: SvfMode::Lowpass,
+    });
+    assert!(handle.set(0, 1.0e9));
+    assert_eq!(handle.get(0), Some(20000.0));
+    assert!(!handle.set(9, 1.0));
+    assert!(!handle.set(0, f32::NAN));
+    assert!(handle.set_by_name("resonance", 0.25));
+    assert_eq!(handle.get(1), Some(0.25));
+}
+
+#[test]
+fn param_node_ramps_to_target() {
+    let (node, handle) = ParamNode::new(Constant { value: 0.0 });
+    let node = node.with_smoothing_ms(1.0);
+    let mut state = node.init_state(44100.0, 64);
+    let mut out = vec![vec![0.0; 64]];
+
+    node.process_block(&mut state, &[], &mut out, 44100.0);
+    assert!(out[0].iter().all(|&x| x == 0.0));
+
+    handle.set(0, 1.0);
+    node.process_block(&mut state, &[], &mut out, 44100.0);
+    // Smoothed: rises monotonically without jumping straight to the target
+    assert!(out[0][0] > 0.0 && out[0][0] < 1.0);
+    assert!(out[0].windows(2).all(|w| w[1] >= w[0]));
+
+    for _ in 0..10 {
+        node.process_block(&mut state, &[], &mut out, 44100.0);
+    }
+    assert!(out[0].iter().all(|&x| x == 1.0));
+}
+
+#[test]
+fn param_node_passes_inputs_while_ramping() {
+    let (node, handle) = ParamNode::new(SvfFilter {
+        cutoff: 500.0,
+        resonance: 0.1,
+        mode: SvfMode::Lowpass,
+    });
+    let mut state = node.init_state(44100.0, 64);
+    let mut out = vec![vec![0.0; 64]];
+    let input = [1.0; 64];
+    handle.set(0, 5000.0);
+    node.process_block(&mut state, &[&input], &mut out, 44100.0);
+    assert!(out[0].iter().all(|x| x.is_finite()));
+    assert!(out[0].iter().any(|&x| x.abs() > 1e-6));
+}
+
+#[test]
+fn param_node_ramps_once_per_block() {
+    let (node, handle) = ParamNode::new(Constant { value: 0.0 });
+    let node = node.with_smoothing_ms(1.0);
+    let mut state = node.init_state(44100.0, 64);
+    let mut out = vec![vec![0.0; 64]];
+
+    handle.set(0, 1.0);
+    node.process_block(&mut state, &[], &mut out, 44100.0);
+    // The ramp holds for the whole block
+    assert!(out[0][0] > 0.0 && out[0][0] < 1.0);
+    assert!(out[0].iter().all(|&x| x == out[0][0]));
+
+    for _ in 0..10 {
+        node.process_block(&mut state, &[], &mut out, 44100.0);
+    }
+    assert!(out[0].iter().all(|&x| x == 1.0));
+}
+
+#[test]
+fn param_node_settles_at_default_smoothing() {
+    for sample_rate in [44100.0, 48000.0, 96000.0] {
+        let (node, handle) = ParamNode::new(Constant { value: 0.0 });
+        assert_eq!(node.smoothing_ms, DEFAULT_SMOOTHING_MS);
+        let mut state = node.init_state(sample_rate, 64);
+        let mut out = vec![vec![0.0; 64]];
+
+        handle.set(0, 0.5);
+        for _ in 0..(sample_rate as usize / 64) {
+            node.process_block(&mut state, &[], &mut out, sample_rate);
+        }
+        assert!(state.smoothed.iter().all(SmoothedValue::is_settled));
+        assert!(out[0].iter().all(|&x| x == 0.5), "{}", sample_rate);
+    }
+}
+
+#[test]
+fn param_node_keeps_host_blocks_whole() {
+    // The convolution reverb buffers by the block size given to init_state
+    let ir: Vec<f32> = (0..300).map(|i| 0.9f32.powi(i)).collect();
+    let (node, handle) = ParamNode::new(ConvolutionReverb { ir, mix: 0.2 });
+    let node = node.with_smoothing_ms(1.0);
+    let mut state = node.init_state(44100.0, 64);
+    let mut out = vec![vec![0.0; 64]];
+    let input = [0.5; 64];
+
+    handle.set(0, 0.9);
+    for _ in 0..50 {
+        node.process_block(&mut state, &[&input], &mut out, 44100.0);
+        assert!(out[0].iter().all(|x| x.is_finite()));
+    }
+    assert!(state.smoothed.iter().all(SmoothedValue::is_settled));
+}
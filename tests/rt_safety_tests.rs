@@ -10,25 +10,71 @@
 //! The coverage here represents the most commonly used RT-critical paths.
 
 use auxide::graph::{Graph, NodeType, PortId, Rate};
+use auxide::node::NodeDef;
 use auxide::plan::Plan;
 use auxide::rt::Runtime;
+use auxide_dsp::filter_design::{
+    BiquadResponse, CrossoverOrder, FilterBand, FilterDesign, FilterFamily,
+};
+use auxide_dsp::nodes::analysis::{
+    AutocorrPitchDetector, FrequencyAnalyzer, OnsetDetector, Peak, Rms, SpectralCentroid,
+};
+use auxide_dsp::nodes::dither::{Dither, DitherType, NoiseShaping};
 use auxide_dsp::nodes::dynamics::Compressor;
 use auxide_dsp::nodes::envelopes::AdsrEnvelope;
-use auxide_dsp::nodes::filters::{LadderFilter, SvfFilter};
-use auxide_dsp::nodes::fx::Delay;
+use auxide_dsp::nodes::filters::{
+    BandSplitter, CascadeFilter, DiodeLadderFilter, LadderFilter, LadderSlope, ParametricBiquad,
+    SvfFilter, ZdfLadderFilter, ZdfSvfFilter,
+};
+use auxide_dsp::nodes::fx::{Delay, FractionalDelay};
+use auxide_dsp::nodes::granular::{GrainSource, GrainWindow, Granulator};
 use auxide_dsp::nodes::lfo::Lfo;
+use auxide_dsp::nodes::math::{Clamp, Div, Ln, Multiply};
 use auxide_dsp::nodes::oscillators::{
-    BrownNoise, Constant, PinkNoise, PulseOsc, SawOsc, SquareOsc, SuperSaw, TriangleOsc,
-    WavetableOsc, WhiteNoise,
+    BrownNoise, Constant, FmAlgorithm, FmVoice, PinkNoise, PulseOsc, SawOsc, SquareOsc, SuperSaw,
+    TriangleOsc, WavetableMorphOsc, WavetableOsc, WhiteNoise,
+};
+use auxide_dsp::nodes::physical::{
+    KarplusStrong, ModalPreset, ModalResonator, Waveguide, WaveguideModel,
 };
 use auxide_dsp::nodes::pitch::PitchShifter;
-use auxide_dsp::nodes::shapers::WaveShaper;
+use auxide_dsp::nodes::sampler::{Interpolation, LoopMode, SamplePlayer};
+use auxide_dsp::nodes::shapers::{
+    AdaaShaper, AntiAlias, ChebyshevShaper, HardClip, SoftClip, TableShaper, WaveShaper, Wavefolder,
+};
+use auxide_dsp::nodes::tape::TapeSaturation;
 use auxide_dsp::nodes::utility::RingMod;
+use auxide_dsp::oversampling::{OversampleFactor, OversampleFilter, Oversampled};
+use auxide_dsp::params::ParamNode;
+use auxide_dsp::wavetables::{generate_saw_table, generate_sine_table, WavetableBank};
 use auxide_dsp::SvfMode;
 
 #[global_allocator]
 static ALLOC: dhat::Alloc = dhat::Alloc;
 
+/// SineOsc -> node -> OutputSink, compiled and run once so lazy setup is done
+fn sine_into<T: NodeDef + 'static>(node: T) -> Runtime {
+    let mut graph = Graph::new();
+    let osc = graph.add_node(NodeType::SineOsc { freq: 440.0 });
+    let id = graph.add_external_node(node);
+    let sink = graph.add_node(NodeType::OutputSink);
+    for (from, to) in [(osc, id), (id, sink)] {
+        graph
+            .add_edge(auxide::graph::Edge {
+                from_node: from,
+                from_port: PortId(0),
+                to_node: to,
+                to_port: PortId(0),
+                rate: Rate::Audio,
+            })
+            .unwrap();
+    }
+    let plan = Plan::compile(&graph, 64).unwrap();
+    let mut runtime = Runtime::new(plan, &graph, 44100.0);
+    runtime.process_block(&mut [0.0; 64]).unwrap();
+    runtime
+}
+
 /// Single comprehensive RT allocation test for ALL DSP node types
 ///
 /// Tests ALL DSP nodes for RT safety by:
@@ -36,7 +82,7 @@ static ALLOC: dhat::Alloc = dhat::Alloc;
 /// 2. Running multiple process_block calls while profiling
 /// 3. Verifying zero allocations occurred during processing
 ///
-/// Nodes tested: ALL NodeDef implementations (oscillators, filters, envelopes, dynamics, lfo, fx, shapers, pitch, utility)
+/// Nodes tested: ALL NodeDef implementations (oscillators, filters, envelopes, dynamics, lfo, fx, shapers, pitch, utility, analysis, math, physical, granular, sampler, oversampled, tape)
 #[test]
 fn test_all_nodes_rt_safe() {
     // ========== SETUP PHASE (allocations allowed) ==========
@@ -136,6 +182,28 @@ fn test_all_nodes_rt_safe() {
     let mut out_wavetable = vec![0.0; 64];
     runtime_wavetable.process_block(&mut out_wavetable).unwrap();
 
+    // SineOsc drives the freq_mod input
+    let mut runtime_morph = sine_into(WavetableMorphOsc {
+        freq: 220.0,
+        position: 0.5,
+        phase: 0.0,
+        bank: std::sync::Arc::new(WavetableBank::from_generators(
+            &[generate_sine_table, generate_saw_table],
+            256,
+        )),
+    });
+    let mut out_morph = vec![0.0; 64];
+
+    // SineOsc drives the oscillators' freq_mod inputs
+    let mut osc_mod_runtimes = [
+        sine_into(SawOsc::new(220.0)),
+        sine_into(PulseOsc {
+            freq: 220.0,
+            pulse_width: 0.3,
+        }),
+        sine_into(FmVoice::new(220.0, FmAlgorithm::four_op(1))),
+    ];
+
     let mut graph_supersaw = Graph::new();
     let supersaw = graph_supersaw.add_external_node(SuperSaw {
         freq: 440.0,
@@ -388,11 +456,14 @@ fn test_all_nodes_rt_safe() {
     // FX
     let mut graph_delay = Graph::new();
     let osc_delay = graph_delay.add_node(NodeType::SineOsc { freq: 440.0 });
-    let delay = graph_delay.add_external_node(Delay {
-        delay_ms: 100.0,
-        feedback: 0.3,
-        mix: 0.5,
-    });
+    let delay = graph_delay.add_external_node(
+        Delay {
+            delay_ms: 100.0,
+            feedback: 0.3,
+            mix: 0.5,
+        }
+        .with_interpolation(Interpolation::Sinc),
+    );
     let sink_delay = graph_delay.add_node(NodeType::OutputSink);
     graph_delay
         .add_edge(auxide::graph::Edge {
@@ -420,10 +491,13 @@ fn test_all_nodes_rt_safe() {
     // Shapers
     let mut graph_waveshaper = Graph::new();
     let osc_waveshaper = graph_waveshaper.add_node(NodeType::SineOsc { freq: 440.0 });
-    let waveshaper = graph_waveshaper.add_external_node(WaveShaper {
-        drive: 1.0,
-        mix: 1.0,
-    });
+    let waveshaper = graph_waveshaper.add_external_node(
+        WaveShaper {
+            drive: 1.0,
+            mix: 1.0,
+        }
+        .with_anti_alias(AntiAlias::SecondOrder),
+    );
     let sink_waveshaper = graph_waveshaper.add_node(NodeType::OutputSink);
     graph_waveshaper
         .add_edge(auxide::graph::Edge {
@@ -453,10 +527,13 @@ fn test_all_nodes_rt_safe() {
     // Pitch
     let mut graph_pitchshifter = Graph::new();
     let osc_pitchshifter = graph_pitchshifter.add_node(NodeType::SineOsc { freq: 440.0 });
-    let pitchshifter = graph_pitchshifter.add_external_node(PitchShifter {
-        shift: 2.0,
-        mix: 1.0,
-    });
+    let pitchshifter = graph_pitchshifter.add_external_node(
+        PitchShifter {
+            shift: 2.0,
+            mix: 1.0,
+        }
+        .with_interpolation(Interpolation::Optimal),
+    );
     let sink_pitchshifter = graph_pitchshifter.add_node(NodeType::OutputSink);
     graph_pitchshifter
         .add_edge(auxide::graph::Edge {
@@ -521,6 +598,230 @@ fn test_all_nodes_rt_safe() {
     let mut out_ringmod = vec![0.0; 64];
     runtime_ringmod.process_block(&mut out_ringmod).unwrap();
 
+    // Parameter control
+    let mut graph_param = Graph::new();
+    let osc_param = graph_param.add_node(NodeType::SineOsc { freq: 440.0 });
+    let (param_svf, param_handle) = ParamNode::new(SvfFilter {
+        cutoff: 1000.0,
+        resonance: 0.5,
+        mode: SvfMode::Lowpass,
+    });
+    let svf_param = graph_param.add_external_node(param_svf);
+    let sink_param = graph_param.add_node(NodeType::OutputSink);
+    graph_param
+        .add_edge(auxide::graph::Edge {
+            from_node: osc_param,
+            from_port: PortId(0),
+            to_node: svf_param,
+            to_port: PortId(0),
+            rate: Rate::Audio,
+        })
+        .unwrap();
+    graph_param
+        .add_edge(auxide::graph::Edge {
+            from_node: svf_param,
+            from_port: PortId(0),
+            to_node: sink_param,
+            to_port: PortId(0),
+            rate: Rate::Audio,
+        })
+        .unwrap();
+    let plan_param = Plan::compile(&graph_param, 64).unwrap();
+    let mut runtime_param = Runtime::new(plan_param, &graph_param, 44100.0);
+    let mut out_param = vec![0.0; 64];
+    runtime_param.process_block(&mut out_param).unwrap();
+
+    // Analysis
+    let mut analysis_runtimes = [
+        sine_into(Rms { window_size: 128 }),
+        sine_into(Peak {
+            hold_ms: 10.0,
+            release_ms: 100.0,
+        }),
+        sine_into(FrequencyAnalyzer { window_size: 128 }),
+        sine_into(SpectralCentroid { fft_size: 256 }),
+        sine_into(OnsetDetector {
+            threshold: 0.1,
+            sensitivity: 1.0,
+            fft_size: 256,
+        }),
+        sine_into(AutocorrPitchDetector {
+            min_freq: 100.0,
+            max_freq: 1000.0,
+            window_size: 256,
+        }),
+    ];
+    let mut out_analysis = vec![0.0; 64];
+
+    // Math
+    let mut math_runtimes = [
+        sine_into(Multiply { inputs: 4 }),
+        sine_into(Div { inputs: 2 }),
+        sine_into(Ln),
+        sine_into(Clamp {
+            min: -0.5,
+            max: 0.5,
+        }),
+    ];
+    let mut out_math = vec![0.0; 64];
+
+    // Physical models, excited by the sine
+    let mut physical_runtimes = [
+        sine_into(KarplusStrong {
+            freq: 220.0,
+            damping: 0.5,
+            decay_ms: 2000.0,
+            stretch: 0.5,
+            pick_position: 0.2,
+        }),
+        sine_into(Waveguide {
+            model: WaveguideModel::Bowed,
+            freq: 220.0,
+            pressure: 0.5,
+            position: 0.127,
+            damping: 0.5,
+        }),
+        sine_into(Waveguide {
+            model: WaveguideModel::Blown,
+            freq: 220.0,
+            pressure: 0.5,
+            position: 0.127,
+            damping: 0.5,
+        }),
+        sine_into(ModalResonator::new(220.0, ModalPreset::Membrane)),
+    ];
+    let mut out_physical = vec![0.0; 64];
+
+    // Granular, recording the sine live and reading a preloaded buffer
+    let mut granular_runtimes = [
+        sine_into(Granulator {
+            window: GrainWindow::Blackman,
+            density: 200.0,
+            jitter: 0.3,
+            pitch: 7.0,
+            spread: 1.0,
+            ..Granulator::new(GrainSource::Live { length_ms: 500.0 })
+        }),
+        sine_into(Granulator {
+            grain_ms: 5.0,
+            density: 500.0,
+            position: 0.5,
+            jitter: 0.5,
+            ..Granulator::new(GrainSource::Buffer(std::sync::Arc::new(vec![0.5; 4410])))
+        }),
+    ];
+    let mut out_granular = vec![0.0; 64];
+
+    // Sample players, gated by the sine
+    let sample: std::sync::Arc<[f32]> = (0..2000).map(|i| (i as f32 * 0.01).sin()).collect();
+    let mut sampler_runtimes = [
+        sine_into(SamplePlayer {
+            loop_mode: LoopMode::Forward,
+            loop_start: 500,
+            loop_end: 1500,
+            crossfade: 200,
+            interpolation: Interpolation::Sinc,
+            ..SamplePlayer::new(sample.clone(), 1, 48_000.0)
+        }),
+        sine_into(SamplePlayer {
+            ratio: 1.5,
+            loop_mode: LoopMode::Reverse,
+            loop_start: 200,
+            loop_end: 800,
+            crossfade: 100,
+            ..SamplePlayer::new(sample, 2, 48_000.0)
+        }),
+    ];
+    let mut out_sampler = vec![0.0; 64];
+
+    // Oversampled shapers, both filter designs, one also anti-aliased, then
+    // the polynomial and table shapers and tape
+    let mut shaper_runtimes = [
+        sine_into(Oversampled::new(
+            HardClip {
+                threshold: 0.3,
+                mix: 1.0,
+            },
+            OversampleFactor::X16,
+            OversampleFilter::Fir,
+        )),
+        sine_into(Oversampled::new(
+            SoftClip {
+                drive: 4.0,
+                mix: 1.0,
+            },
+            OversampleFactor::X4,
+            OversampleFilter::Iir,
+        )),
+        sine_into(Oversampled::new(
+            Wavefolder {
+                fold: 6.0,
+                bias: 0.2,
+                stages: 4,
+                mix: 1.0,
+            }
+            .with_anti_alias(AntiAlias::FirstOrder),
+            OversampleFactor::X2,
+            OversampleFilter::Fir,
+        )),
+        sine_into(ChebyshevShaper::new(vec![0.6, 0.3, 0.2, 0.1])),
+        sine_into(TableShaper {
+            interpolation: Interpolation::Sinc,
+            ..TableShaper::from_fn(|x: f32| (3.0 * x).tanh(), 1024)
+        }),
+        sine_into(TapeSaturation {
+            drive: 3.0,
+            bias: 0.2,
+            wow: 0.5,
+            flutter: 0.5,
+            head_bump: 4.0,
+            hf_loss: 0.3,
+            interpolation: Interpolation::Sinc,
+            ..TapeSaturation::new()
+        }),
+        sine_into(Dither {
+            dither: DitherType::HighPassTpdf,
+            shaping: NoiseShaping::ImprovedEWeighted,
+            ..Dither::new(16.0)
+        }),
+    ];
+    let mut out_shapers = vec![0.0; 64];
+
+    let mut filter_runtimes = [
+        sine_into(ParametricBiquad {
+            gain: 6.0,
+            ..ParametricBiquad::new(BiquadResponse::Peaking, 2000.0, 2.0)
+        }),
+        sine_into(CascadeFilter::new(FilterDesign::new(
+            FilterFamily::Elliptic {
+                ripple_db: 0.5,
+                attenuation_db: 60.0,
+            },
+            FilterBand::Highpass,
+            9,
+            200.0,
+        ))),
+        sine_into(BandSplitter::new(
+            vec![150.0, 800.0, 4000.0],
+            CrossoverOrder::Lr8,
+        )),
+        sine_into(ZdfSvfFilter {
+            resonance: 0.95,
+            ..ZdfSvfFilter::default()
+        }),
+        sine_into(ZdfLadderFilter {
+            resonance: 1.1,
+            slope: LadderSlope::Db12,
+            ..ZdfLadderFilter::default()
+        }),
+        sine_into(DiodeLadderFilter {
+            resonance: 0.9,
+            drive: 4.0,
+            ..DiodeLadderFilter::default()
+        }),
+    ];
+    let mut out_filters = vec![0.0; 64];
+
     // ========== RT PHASE (zero allocations required) ==========
     let _profiler = dhat::Profiler::new_heap();
 
@@ -531,6 +832,10 @@ fn test_all_nodes_rt_safe() {
         runtime_triangle.process_block(&mut out_triangle).unwrap();
         runtime_pulse.process_block(&mut out_pulse).unwrap();
         runtime_wavetable.process_block(&mut out_wavetable).unwrap();
+        runtime_morph.process_block(&mut out_morph).unwrap();
+        for runtime in osc_mod_runtimes.iter_mut() {
+            runtime.process_block(&mut out_morph).unwrap();
+        }
         runtime_supersaw.process_block(&mut out_supersaw).unwrap();
         runtime_whitenoise
             .process_block(&mut out_whitenoise)
@@ -555,6 +860,29 @@ fn test_all_nodes_rt_safe() {
             .process_block(&mut out_pitchshifter)
             .unwrap();
         runtime_ringmod.process_block(&mut out_ringmod).unwrap();
+        param_handle.set(0, 500.0 + (out_param[0].abs() * 4000.0));
+        runtime_param.process_block(&mut out_param).unwrap();
+        for runtime in analysis_runtimes.iter_mut() {
+            runtime.process_block(&mut out_analysis).unwrap();
+        }
+        for runtime in math_runtimes.iter_mut() {
+            runtime.process_block(&mut out_math).unwrap();
+        }
+        for runtime in physical_runtimes.iter_mut() {
+            runtime.process_block(&mut out_physical).unwrap();
+        }
+        for runtime in granular_runtimes.iter_mut() {
+            runtime.process_block(&mut out_granular).unwrap();
+        }
+        for runtime in sampler_runtimes.iter_mut() {
+            runtime.process_block(&mut out_sampler).unwrap();
+        }
+        for runtime in shaper_runtimes.iter_mut() {
+            runtime.process_block(&mut out_shapers).unwrap();
+        }
+        for runtime in filter_runtimes.iter_mut() {
+            runtime.process_block(&mut out_filters).unwrap();
+        }
     }
 
     let stats = dhat::HeapStats::get();
@@ -0,0 +1,59 @@
+#![forbid(unsafe_code)]
+
+use auxide_dsp::*;
+
+/// Encode interleaved 16-bit PCM as a WAV file.
+fn wav_16(samples: &[i16], channels: u16, sample_rate: u32) -> Vec<u8> {
+    let data_len = (samples.len() * 2) as u32;
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+    bytes.extend_from_slice(b"WAVEfmt ");
+    bytes.extend_from_slice(&16u32.to_le_bytes());
+    bytes.extend_from_slice(&1u16.to_le_bytes());
+    bytes.extend_from_slice(&channels.to_le_bytes());
+    bytes.extend_from_slice(&sample_rate.to_le_bytes());
+    bytes.extend_from_slice(&(sample_rate * channels as u32 * 2).to_le_bytes());
+    bytes.extend_from_slice(&(channels * 2).to_le_bytes());
+    bytes.extend_from_slice(&16u16.to_le_bytes());
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_len.to_le_bytes());
+    for s in samples {
+        bytes.extend_from_slice(&s.to_le_bytes());
+    }
+    bytes
+}
+
+#[test]
+fn parses_pcm16_stereo() {
+    let wav = parse_wav(&wav_16(&[16384, -16384, 0, 32767], 2, 48000)).unwrap();
+    assert_eq!(wav.sample_rate, 48000);
+    assert_eq!(wav.channels, 2);
+    assert_eq!(wav.frames(), 2);
+    assert_eq!(wav.channel(1), vec![-0.5, 32767.0 / 32768.0]);
+    assert_eq!(wav.to_mono()[0], 0.0);
+}
+
+#[test]
+fn rejects_non_wav() {
+    assert!(matches!(
+        parse_wav(b"not a wav file"),
+        Err(WavError::Format(_))
+    ));
+    assert!(matches!(
+        read_wav("/nonexistent/table.wav"),
+        Err(WavError::Io(_))
+    ));
+}
+
+#[test]
+fn bank_loads_frames_from_wav() {
+    // Three 64-sample frames plus a partial one that is dropped
+    let samples: Vec<i16> = (0..(3 * 64 + 10)).map(|i| (i * 100) as i16).collect();
+    let path = std::env::temp_dir().join(format!("auxide_dsp_bank_{}.wav", std::process::id()));
+    std::fs::write(&path, wav_16(&samples, 1, 44100)).unwrap();
+    let bank = WavetableBank::from_wav(&path, 64).unwrap();
+    std::fs::remove_file(&path).ok();
+    assert_eq!(bank.num_frames(), 3);
+    assert_eq!(bank.frame(0).table_size(), 64);
+}
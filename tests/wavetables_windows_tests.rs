@@ -25,6 +25,43 @@ fn wavetable_golden() {
     approx(tri[3], 0.0);
 }
 
+#[test]
+fn mipmaps_are_band_limited() {
+    let mip = MipmappedWavetable::new(&generate_saw_table(256));
+    // Harmonic limits 128, 64, ..., 2
+    assert_eq!(mip.num_levels(), 7);
+    assert_eq!(mip.table_size(), 256);
+
+    // The last level keeps only the fundamental: a sine of the saw's
+    // fundamental amplitude 2/pi
+    let top = mip.level(mip.num_levels() - 1);
+    let peak = top.iter().fold(0.0f32, |m, x| m.max(x.abs()));
+    assert!((peak - 2.0 / std::f32::consts::PI).abs() < 1e-2, "{}", peak);
+
+    // Level 0 reproduces the source minus its Nyquist bin
+    let sine = MipmappedWavetable::new(&generate_sine_table(64));
+    for (a, b) in sine.level(0).iter().zip(generate_sine_table(64)) {
+        approx(*a, b);
+    }
+}
+
+#[test]
+fn mipmap_selects_level_by_frequency() {
+    let mip = MipmappedWavetable::new(&generate_saw_table(256));
+    let sr = 44100.0;
+    // Below one table sample per output sample the full table is used
+    approx(
+        mip.sample(0.25, 50.0, sr),
+        cubic_interpolate(mip.level(0), 64.0),
+    );
+    // Far above the table range the last level is used
+    let top = mip.num_levels() - 1;
+    approx(
+        mip.sample(0.25, 20000.0, sr),
+        cubic_interpolate(mip.level(top), 64.0),
+    );
+}
+
 #[test]
 fn windows_sum_correctly() {
     let n = 8;